@@ -0,0 +1,3 @@
+-- Add migration script here
+-- NOTE: 既存ユーザーはパスワード未設定(ログイン不可)として扱う
+ALTER TABLE users ADD COLUMN password_hash VARCHAR;
@@ -0,0 +1,7 @@
+-- Add migration script here
+-- NOTE: ローテーションで発行されたトークンは同じfamily_idを引き継ぐ
+ALTER TABLE sessions ADD COLUMN family_id UUID;
+UPDATE sessions SET family_id = session_id;
+ALTER TABLE sessions ALTER COLUMN family_id SET NOT NULL;
+ALTER TABLE sessions ADD COLUMN revoked_at TIMESTAMPTZ;
+CREATE INDEX sessions_family_id_idx ON sessions (family_id);
@@ -0,0 +1,2 @@
+-- Add migration script here
+ALTER TABLE users ADD COLUMN role VARCHAR NOT NULL DEFAULT 'user' CHECK (role IN ('user', 'admin'));
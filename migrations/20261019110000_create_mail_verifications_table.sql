@@ -0,0 +1,13 @@
+-- Add migration script here
+ALTER TABLE users ADD COLUMN mail_verified_at TIMESTAMPTZ;
+-- NOTE: 確認フロー導入前に登録されたユーザーは確認済みとして扱う
+UPDATE users SET mail_verified_at = now();
+
+CREATE TABLE mail_verifications (
+    token_hash BYTEA PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    mail_address VARCHAR NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL,
+    used_at TIMESTAMPTZ
+);
@@ -0,0 +1,3 @@
+-- Add migration script here
+ALTER TABLE users ADD COLUMN pending_mail_address VARCHAR;
+ALTER TABLE mail_verifications ADD COLUMN purpose VARCHAR NOT NULL DEFAULT 'verify' CHECK (purpose IN ('verify', 'revert'));
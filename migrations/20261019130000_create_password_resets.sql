@@ -0,0 +1,18 @@
+-- Add migration script here
+CREATE TABLE password_resets (
+    token_hash BYTEA PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL,
+    used_at TIMESTAMPTZ
+);
+
+-- NOTE: 発行数の制限に使用する。存在しないアドレスへの申請も記録する
+CREATE TABLE password_reset_requests (
+    id BIGSERIAL PRIMARY KEY,
+    mail_address VARCHAR NOT NULL,
+    ip_address VARCHAR NOT NULL,
+    requested_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX password_reset_requests_mail_address_idx ON password_reset_requests (mail_address, requested_at);
+CREATE INDEX password_reset_requests_ip_address_idx ON password_reset_requests (ip_address, requested_at);
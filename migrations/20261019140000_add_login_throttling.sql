@@ -0,0 +1,20 @@
+-- Add migration script here
+ALTER TABLE users ADD COLUMN failed_login_count INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE users ADD COLUMN locked_until TIMESTAMPTZ;
+
+CREATE TABLE login_throttles (
+    ip_address VARCHAR PRIMARY KEY,
+    failed_count INTEGER NOT NULL,
+    last_failed_at TIMESTAMPTZ NOT NULL
+);
+
+-- NOTE: 監査用の記録のため、ユーザーの削除後も残す
+CREATE TABLE account_lock_events (
+    id BIGSERIAL PRIMARY KEY,
+    user_id UUID NOT NULL,
+    kind VARCHAR NOT NULL CHECK (kind IN ('locked', 'unlocked')),
+    actor_id UUID,
+    locked_until TIMESTAMPTZ,
+    occurred_at TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX account_lock_events_user_id_idx ON account_lock_events (user_id, occurred_at);
@@ -0,0 +1,29 @@
+-- Add migration script here
+-- NOTE: trueの場合、ログイン時にパスワードに加えて二要素目の確認を要求する
+ALTER TABLE users ADD COLUMN two_factor_enabled BOOLEAN NOT NULL DEFAULT false;
+
+-- NOTE: confirmed_atがNULLの間は登録途中(認証アプリでの確認前)
+CREATE TABLE totp_credentials (
+    user_id UUID PRIMARY KEY REFERENCES users (user_id) ON DELETE CASCADE,
+    secret BYTEA NOT NULL,
+    confirmed_at TIMESTAMPTZ,
+    last_used_step BIGINT,
+    created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE recovery_codes (
+    code_hash BYTEA PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL,
+    used_at TIMESTAMPTZ
+);
+CREATE INDEX recovery_codes_user_id_idx ON recovery_codes (user_id);
+
+-- NOTE: パスワード確認済みで二要素目の確認を待っているログイン
+CREATE TABLE login_challenges (
+    token_hash BYTEA PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL,
+    used_at TIMESTAMPTZ
+);
@@ -0,0 +1,13 @@
+-- Add migration script here
+ALTER TABLE users ADD COLUMN status VARCHAR NOT NULL DEFAULT 'pending'
+    CHECK (status IN ('pending', 'active', 'suspended', 'deleted'));
+ALTER TABLE users ADD COLUMN suspension_reason VARCHAR;
+ALTER TABLE users ADD COLUMN suspended_until TIMESTAMPTZ;
+ALTER TABLE users ADD COLUMN deleted_at TIMESTAMPTZ;
+ALTER TABLE users ADD CONSTRAINT users_suspension_check
+    CHECK (status <> 'suspended' OR suspension_reason IS NOT NULL);
+ALTER TABLE users ADD CONSTRAINT users_deletion_check
+    CHECK (status <> 'deleted' OR deleted_at IS NOT NULL);
+
+-- NOTE: 既存のユーザーはメールアドレスの確認状況から状態を決める
+UPDATE users SET status = 'active' WHERE mail_verified_at IS NOT NULL;
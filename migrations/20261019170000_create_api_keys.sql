@@ -0,0 +1,14 @@
+-- Add migration script here
+-- NOTE: サービス間の呼び出しに使用するキー。平文のキーは発行時にのみ返し、ハッシュ値を保存する
+--       prefixはキーの先頭部分で、一覧やログでキーを識別するために平文で保存する
+CREATE TABLE api_keys (
+    api_key_id UUID PRIMARY KEY,
+    name VARCHAR NOT NULL,
+    prefix VARCHAR NOT NULL UNIQUE,
+    key_hash BYTEA NOT NULL UNIQUE,
+    scopes TEXT[] NOT NULL,
+    created_by UUID NOT NULL REFERENCES users (user_id),
+    created_at TIMESTAMPTZ NOT NULL,
+    last_used_at TIMESTAMPTZ,
+    revoked_at TIMESTAMPTZ
+);
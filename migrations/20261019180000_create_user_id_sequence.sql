@@ -0,0 +1,3 @@
+-- Add migration script here
+-- NOTE: user.id_strategy = "sequence"の場合にユーザーIDの採番に使用する
+CREATE SEQUENCE user_id_seq;
@@ -0,0 +1,10 @@
+-- Add migration script here
+-- NOTE: 紛らわしいユーザー名の重複確認に使用する、ユーザー名のskeleton(UTS #39)
+--       skeletonはアプリケーションで計算するため、既存のユーザーの分はマイグレーション後に
+--       `api_server maintenance backfill-user-name-skeletons`を実行して補完すること
+CREATE TABLE user_name_skeletons (
+    user_id UUID PRIMARY KEY REFERENCES users (user_id) ON DELETE CASCADE,
+    skeleton VARCHAR NOT NULL
+);
+
+CREATE INDEX user_name_skeletons_skeleton_idx ON user_name_skeletons (skeleton);
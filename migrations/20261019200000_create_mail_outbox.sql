@@ -0,0 +1,15 @@
+-- Add migration script here
+-- NOTE: 送信するメール。業務処理と同じトランザクションで登録し、コミット後にワーカーが送信する
+CREATE TABLE mail_outbox (
+    id BIGSERIAL PRIMARY KEY,
+    to_address VARCHAR NOT NULL,
+    subject VARCHAR NOT NULL,
+    body TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMPTZ NOT NULL,
+    last_error TEXT,
+    sent_at TIMESTAMPTZ
+);
+
+CREATE INDEX mail_outbox_pending_idx ON mail_outbox (next_attempt_at) WHERE sent_at IS NULL;
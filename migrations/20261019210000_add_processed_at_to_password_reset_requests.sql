@@ -0,0 +1,6 @@
+-- Add migration script here
+-- NOTE: 再設定メールの発行はワーカーが非同期に行う。処理済みの申請はprocessed_atを設定する
+ALTER TABLE password_reset_requests ADD COLUMN processed_at TIMESTAMPTZ;
+UPDATE password_reset_requests SET processed_at = requested_at;
+
+CREATE INDEX password_reset_requests_pending_idx ON password_reset_requests (requested_at) WHERE processed_at IS NULL;
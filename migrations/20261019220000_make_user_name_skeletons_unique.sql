@@ -0,0 +1,10 @@
+-- Add migration script here
+-- NOTE: 紛らわしいユーザー名が同時に登録されないよう、skeletonを一意にする
+--       既に重複している場合はuser_idが最小の1件を残す。残りのユーザーは名前を変更するまでskeletonを持たない
+DELETE FROM user_name_skeletons duplicated
+USING user_name_skeletons kept
+WHERE duplicated.skeleton = kept.skeleton AND duplicated.user_id > kept.user_id;
+
+DROP INDEX user_name_skeletons_skeleton_idx;
+
+ALTER TABLE user_name_skeletons ADD CONSTRAINT user_name_skeletons_skeleton_key UNIQUE (skeleton);
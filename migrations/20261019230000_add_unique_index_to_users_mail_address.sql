@@ -0,0 +1,4 @@
+-- Add migration script here
+-- NOTE: 同じアドレスへの変更が同時に確認された場合も、1人のユーザーのみが使用できるようにする
+--       既に重複している場合はマイグレーションが失敗するため、事前に重複を解消すること
+ALTER TABLE users ADD CONSTRAINT users_mail_address_key UNIQUE (mail_address);
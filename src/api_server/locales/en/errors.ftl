@@ -0,0 +1,89 @@
+internal-error = An internal server error occurred.
+invalid-json-value = The value has an invalid type or a required field is missing.
+
+## Authentication
+
+authentication-required = Authentication is required.
+access-token-encode-error = Failed to issue an access token. { $detail }
+access-token-invalid-key = The access token key is invalid. { $detail }
+access-token-expired = The access token has expired.
+access-token-invalid = The access token is invalid. { $detail }
+refresh-token-invalid = The refresh token is invalid.
+login-invalid-credentials = The email address or password is incorrect.
+login-too-many-attempts = Too many login attempts. Please wait a while and try again.
+login-account-suspended = This account is suspended.
+login-challenge-invalid = Your login has expired. Please log in again.
+
+## Two-factor authentication
+
+two-factor-request-invalid = Specify exactly one of code or recovery_code.
+two-factor-invalid-code = The verification code is incorrect.
+two-factor-too-many-attempts = Too many attempts. Please wait a while and try again.
+two-factor-user-not-found = The user does not exist.
+two-factor-already-enabled = Two-factor authentication is already enabled.
+two-factor-not-enabled = Two-factor authentication is not enabled.
+two-factor-enrollment-not-started = Two-factor authentication enrollment has not been started.
+two-factor-already-confirmed = Two-factor authentication is already registered.
+
+## Users
+
+user-name-empty = The user name is blank.
+user-name-too-short = The user name must be at least { $min_length } characters.
+user-name-too-long = The user name must be at most { $max_length } characters.
+user-name-reserved = This user name is reserved.
+user-name-blocked = The user name contains a word that is not allowed.
+user-name-confusable = The user name is the same as or too similar to an existing one.
+mail-address-empty = The email address is blank.
+mail-address-too-long = The email address must be at most { $max_length } characters.
+mail-address-invalid-format = The email address format is invalid.
+password-too-short = The password must be at least { $min_length } characters.
+password-too-long = The password must be at most { $max_length } characters.
+password-too-weak = The password must combine letters with digits or symbols.
+user-not-found = The user was not found.
+user-already-exists = { $name } already exists.
+user-id-not-exists = { $id } is not a valid user_id.
+user-mail-address-mismatch = The email address to verify does not match the current address.
+user-status-invalid-transition =
+    Cannot { $transition ->
+        [activate] activate
+        [suspend] suspend
+        [reinstate] reinstate
+       *[delete] delete
+    } a user whose status is { $from }.
+user-status-empty-suspension-reason = Enter a reason for the suspension.
+user-status-invalid-suspension-period = The suspension end must be later than the current time.
+user-policy-not-permitted =
+    You do not have permission to { $action ->
+        [read] view user information
+        [update] update user information
+        [delete] delete the user
+        [unlock] unlock the account
+       *[suspend] suspend the account
+    }.
+user-policy-mail-not-verified =
+    You cannot { $action ->
+        [read] view user information
+        [update] update user information
+        [delete] delete the user
+        [unlock] unlock the account
+       *[suspend] suspend the account
+    } until your email address is verified.
+actor-not-found = The user performing the operation does not exist.
+verification-token-invalid = The verification token is invalid.
+
+## Password reset
+
+password-reset-too-many-requests = Too many password reset requests. Please wait a while and try again.
+password-reset-token-invalid = The reset token is invalid.
+
+## API keys
+
+api-key-invalid = The API key is invalid.
+api-key-insufficient-scope = The API key does not have the { $required } scope.
+api-key-not-permitted = Only administrators can manage API keys.
+api-key-not-found = The API key does not exist.
+api-key-empty-name = Enter a name for the API key.
+api-key-name-too-long = The API key name must be at most { $max } characters.
+api-key-no-scopes = An API key needs at least one scope.
+api-key-already-revoked = The API key has already been revoked.
+api-key-unknown-scope = { $scope } is an unknown scope.
@@ -0,0 +1,92 @@
+# NOTE: エラーメッセージのカタログ。キーはエラーの識別子で、文言を変更しても変えない
+#       キーを追加した場合はen/errors.ftlにも追加する
+
+internal-error = サーバー内部でエラーが発生しました。
+invalid-json-value = 値の型が不正か、必須の項目がありません。
+
+## 認証
+
+authentication-required = 認証が必要です。
+access-token-encode-error = アクセストークンの発行に失敗しました。{ $detail }
+access-token-invalid-key = アクセストークンの鍵が不正です。{ $detail }
+access-token-expired = アクセストークンの有効期限が切れています。
+access-token-invalid = アクセストークンが不正です。{ $detail }
+refresh-token-invalid = リフレッシュトークンが無効です。
+login-invalid-credentials = メールアドレスまたはパスワードが正しくありません。
+login-too-many-attempts = ログインの試行回数が多すぎます。しばらく時間をおいてから再度お試しください。
+login-account-suspended = このアカウントは利用停止中です。
+login-challenge-invalid = ログインの有効期限が切れています。再度ログインしてください。
+
+## 二要素認証
+
+two-factor-request-invalid = codeまたはrecovery_codeのいずれか一方を指定してください。
+two-factor-invalid-code = 確認コードが正しくありません。
+two-factor-too-many-attempts = 試行回数が多すぎます。しばらく時間をおいてから再度お試しください。
+two-factor-user-not-found = ユーザーが存在しません。
+two-factor-already-enabled = 二要素認証は有効化済みです。
+two-factor-not-enabled = 二要素認証は有効化されていません。
+two-factor-enrollment-not-started = 二要素認証の登録が開始されていません。
+two-factor-already-confirmed = 二要素認証は登録済みです。
+
+## ユーザー
+
+user-name-empty = ユーザー名が空白です。
+user-name-too-short = ユーザー名は{ $min_length }文字以上で入力してください。
+user-name-too-long = ユーザー名は{ $max_length }文字以下で入力してください。
+user-name-reserved = このユーザー名は予約されているため使用できません。
+user-name-blocked = 使用できない語句が含まれています。
+user-name-confusable = 既存のユーザー名と同じか紛らわしいため使用できません。
+mail-address-empty = メールアドレスが空白です。
+mail-address-too-long = メールアドレスは{ $max_length }文字以下で入力してください。
+mail-address-invalid-format = メールアドレスの形式が不正です。
+password-too-short = パスワードは{ $min_length }文字以上で入力してください。
+password-too-long = パスワードは{ $max_length }文字以下で入力してください。
+password-too-weak = パスワードは英字と数字または記号を組み合わせてください。
+user-not-found = ユーザーが見つかりません。
+user-already-exists = { $name }はすでに存在しています。
+user-id-not-exists = { $id }は不適切なuser_idです
+user-mail-address-mismatch = 確認対象のメールアドレスが現在のアドレスと一致しません。
+user-status-invalid-transition =
+    状態が{ $from }のユーザーに対して{ $transition ->
+        [activate] 有効化
+        [suspend] 利用停止
+        [reinstate] 利用停止の解除
+       *[delete] 退会
+    }は行えません。
+user-status-empty-suspension-reason = 利用停止の理由を入力してください。
+user-status-invalid-suspension-period = 利用停止の期限には現在より後の日時を指定してください。
+user-policy-not-permitted =
+    { $action ->
+        [read] ユーザー情報の参照
+        [update] ユーザー情報の更新
+        [delete] ユーザーの削除
+        [unlock] アカウントのロック解除
+       *[suspend] アカウントの利用停止
+    }を行う権限がありません。
+user-policy-mail-not-verified =
+    メールアドレスの確認が完了するまで{ $action ->
+        [read] ユーザー情報の参照
+        [update] ユーザー情報の更新
+        [delete] ユーザーの削除
+        [unlock] アカウントのロック解除
+       *[suspend] アカウントの利用停止
+    }は行えません。
+actor-not-found = 操作を行うユーザーが存在しません。
+verification-token-invalid = 確認トークンが無効です。
+
+## パスワード再設定
+
+password-reset-too-many-requests = パスワード再設定の申請が多すぎます。しばらく時間をおいてから再度お試しください。
+password-reset-token-invalid = 再設定トークンが無効です。
+
+## APIキー
+
+api-key-invalid = APIキーが無効です。
+api-key-insufficient-scope = APIキーに{ $required }のスコープがありません。
+api-key-not-permitted = APIキーの管理は管理者のみ行えます。
+api-key-not-found = APIキーが存在しません。
+api-key-empty-name = APIキーの名前を入力してください。
+api-key-name-too-long = APIキーの名前は{ $max }文字以内で入力してください。
+api-key-no-scopes = APIキーには1つ以上のスコープが必要です。
+api-key-already-revoked = APIキーはすでに失効しています。
+api-key-unknown-scope = { $scope }は不明なスコープです。
@@ -0,0 +1,328 @@
+mod auth_config;
+mod cors_config;
+mod database_config;
+mod feature_config;
+mod health_config;
+mod limits_config;
+mod log_config;
+mod mail_config;
+mod server_config;
+mod user_config;
+
+pub use auth_config::*;
+pub use cors_config::*;
+pub use database_config::*;
+pub use feature_config::*;
+pub use health_config::*;
+pub use limits_config::*;
+pub use log_config::*;
+pub use mail_config::*;
+pub use server_config::*;
+pub use user_config::*;
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+const REDACTED: &str = "********";
+
+// NOTE: 優先順位は CLI引数 > 環境変数 > 設定ファイル > デフォルト値
+//       CLI引数と環境変数の優先順位はclapが解決する
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AppConfig {
+    pub server: ServerConfig,
+    pub database: DatabaseConfig,
+    pub log: LogConfig,
+    pub cors: CorsConfig,
+    pub limits: LimitsConfig,
+    pub features: FeatureConfig,
+    pub health: HealthConfig,
+    pub auth: AuthConfig,
+    pub mail: MailConfig,
+    pub user: UserConfig,
+}
+
+impl AppConfig {
+    pub fn load(path: Option<&Path>, overrides: &ConfigOverrides) -> Result<Self, ConfigError> {
+        let mut config = match path {
+            Some(path) => Self::from_file(path)?,
+            None => Self::default(),
+        };
+        overrides.apply(&mut config);
+        Ok(config)
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self, ConfigError> {
+        let content = std::fs::read_to_string(path).map_err(|source| ConfigError::ReadError {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::from_toml_str(&content)
+    }
+
+    pub fn from_toml_str(content: &str) -> Result<Self, ConfigError> {
+        Ok(toml::from_str(content)?)
+    }
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let required = [
+            ("database.user", &self.database.user),
+            ("database.password", &self.database.password),
+            ("database.host", &self.database.host),
+            ("database.name", &self.database.name),
+            ("server.bind", &self.server.bind),
+        ];
+        if let Some((key, _)) = required.iter().find(|(_, value)| value.is_empty()) {
+            return Err(ConfigError::MissingValue(key));
+        }
+        if self.server.workers == Some(0) {
+            return Err(ConfigError::InvalidValue {
+                key: "server.workers",
+                reason: "1以上を指定してください。",
+            });
+        }
+        if self.database.max_connections == 0 {
+            return Err(ConfigError::InvalidValue {
+                key: "database.max_connections",
+                reason: "1以上を指定してください。",
+            });
+        }
+        if !self.mail.verification_url.contains("{token}") {
+            return Err(ConfigError::InvalidValue {
+                key: "mail.verification_url",
+                reason: "{token}を含めてください。",
+            });
+        }
+        if !self.mail.revert_url.contains("{token}") {
+            return Err(ConfigError::InvalidValue {
+                key: "mail.revert_url",
+                reason: "{token}を含めてください。",
+            });
+        }
+        if !self.mail.password_reset_url.contains("{token}") {
+            return Err(ConfigError::InvalidValue {
+                key: "mail.password_reset_url",
+                reason: "{token}を含めてください。",
+            });
+        }
+        self.mail.outbox.validate()?;
+        if self.auth.refresh_token_ttl_secs == 0 {
+            return Err(ConfigError::InvalidValue {
+                key: "auth.refresh_token_ttl_secs",
+                reason: "1以上を指定してください。",
+            });
+        }
+        self.auth.password_reset.validate()?;
+        self.auth.login_throttle.validate()?;
+        self.auth.two_factor.validate()?;
+        self.auth.access_token.validate()
+    }
+
+    // NOTE: config printで表示するための秘匿情報をマスクしたコピー
+    pub fn redacted(&self) -> Self {
+        let mut config = self.clone();
+        if !config.database.password.is_empty() {
+            config.database.password = REDACTED.to_string();
+        }
+        if !config.auth.access_token.secret.is_empty() {
+            config.auth.access_token.secret = REDACTED.to_string();
+        }
+        config
+    }
+
+    pub fn to_toml(&self) -> Result<String, ConfigError> {
+        Ok(toml::to_string_pretty(self)?)
+    }
+}
+
+#[derive(Debug, Default, clap::Args)]
+pub struct ConfigOverrides {
+    /// server bind address
+    #[arg(long, env("SERVER_BIND"))]
+    server_bind: Option<String>,
+    /// number of http workers
+    #[arg(long, env("SERVER_WORKERS"))]
+    server_workers: Option<usize>,
+    /// database user
+    #[arg(long, env("DB_USER"))]
+    database_user: Option<String>,
+    /// database password
+    #[arg(long, env("DB_PASSWORD"), hide_env_values = true)]
+    database_password: Option<String>,
+    /// database host
+    #[arg(long, env("DB_HOST"))]
+    database_host: Option<String>,
+    /// database port
+    #[arg(long, env("DB_PORT"))]
+    database_port: Option<u16>,
+    /// database name
+    #[arg(long, env("DB_NAME"))]
+    database_name: Option<String>,
+    /// database max connections
+    #[arg(long, env("DB_MAX_CONNECTIONS"))]
+    database_max_connections: Option<u32>,
+    /// log filter (same syntax as RUST_LOG)
+    #[arg(long, env("RUST_LOG"))]
+    log_level: Option<String>,
+    /// log output format
+    #[arg(long, env("LOG_FORMAT"))]
+    log_format: Option<LogFormat>,
+    /// secret key for HS256 access tokens
+    #[arg(long, env("AUTH_JWT_SECRET"), hide_env_values = true)]
+    auth_jwt_secret: Option<String>,
+}
+
+impl ConfigOverrides {
+    fn apply(&self, config: &mut AppConfig) {
+        fn set<T: Clone>(target: &mut T, value: &Option<T>) {
+            if let Some(value) = value {
+                *target = value.clone();
+            }
+        }
+
+        set(&mut config.server.bind, &self.server_bind);
+        if self.server_workers.is_some() {
+            config.server.workers = self.server_workers;
+        }
+        set(&mut config.database.user, &self.database_user);
+        set(&mut config.database.password, &self.database_password);
+        set(&mut config.database.host, &self.database_host);
+        set(&mut config.database.port, &self.database_port);
+        set(&mut config.database.name, &self.database_name);
+        set(
+            &mut config.database.max_connections,
+            &self.database_max_connections,
+        );
+        set(&mut config.log.level, &self.log_level);
+        set(&mut config.log.format, &self.log_format);
+        set(&mut config.auth.access_token.secret, &self.auth_jwt_secret);
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("設定ファイル{}を読み込めませんでした。", path.display())]
+    ReadError {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("設定ファイルの形式が不正です。{0}")]
+    ParseError(#[from] toml::de::Error),
+    #[error(transparent)]
+    SerializeError(#[from] toml::ser::Error),
+    #[error("{0}が設定されていません。")]
+    MissingValue(&'static str),
+    #[error("{key}の値が不正です。{reason}")]
+    InvalidValue {
+        key: &'static str,
+        reason: &'static str,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const FILE: &str = r#"
+        [server]
+        bind = "127.0.0.1:9000"
+        workers = 2
+
+        [database]
+        user = "file_user"
+        password = "file_password"
+        name = "file_db"
+
+        [auth.access_token]
+        secret = "file_secret_file_secret_file_secret"
+    "#;
+
+    #[rstest]
+    fn file_overrides_defaults() {
+        let config = AppConfig::from_toml_str(FILE).unwrap();
+        assert_eq!(config.server.bind, "127.0.0.1:9000");
+        assert_eq!(config.server.workers, Some(2));
+        assert_eq!(config.database.user, "file_user");
+        assert_eq!(config.database.port, DatabaseConfig::default().port);
+        assert_eq!(config.log, LogConfig::default());
+        assert_eq!(config.user.id_strategy, IdStrategy::UuidV7);
+    }
+
+    #[rstest]
+    #[case("uuid_v7", IdStrategy::UuidV7)]
+    #[case("ulid", IdStrategy::Ulid)]
+    #[case("sequence", IdStrategy::Sequence)]
+    fn id_strategy(#[case] value: &str, #[case] expected: IdStrategy) {
+        let config =
+            AppConfig::from_toml_str(&format!("[user]\nid_strategy = \"{value}\"")).unwrap();
+        assert_eq!(config.user.id_strategy, expected);
+    }
+
+    #[rstest]
+    fn user_name_policy() {
+        let config = AppConfig::from_toml_str(
+            "[user.name_policy]\nreserved_words = [\"operator\"]\nblocklist_path = \"/nonexistent/blocklist.txt\"",
+        )
+        .unwrap();
+        assert_eq!(config.user.name_policy.reserved_words, ["operator"]);
+        assert!(matches!(
+            config.user.name_policy.load(),
+            Err(ConfigError::ReadError { .. })
+        ));
+        assert!(UserNamePolicyConfig::default().load().is_ok());
+    }
+
+    #[rstest]
+    fn arguments_override_file() {
+        let mut config = AppConfig::from_toml_str(FILE).unwrap();
+        ConfigOverrides {
+            server_bind: Some("0.0.0.0:80".to_string()),
+            database_user: Some("cli_user".to_string()),
+            ..Default::default()
+        }
+        .apply(&mut config);
+        assert_eq!(config.server.bind, "0.0.0.0:80");
+        assert_eq!(config.server.workers, Some(2));
+        assert_eq!(config.database.user, "cli_user");
+        assert_eq!(config.database.password, "file_password");
+    }
+
+    #[rstest]
+    #[case("[server]\nbind = 1")]
+    #[case("[unknown]\nkey = true")]
+    #[case("[user]\nid_strategy = \"uuid_v4\"")]
+    fn invalid_file(#[case] content: &str) {
+        assert!(matches!(
+            AppConfig::from_toml_str(content),
+            Err(ConfigError::ParseError(_))
+        ));
+    }
+
+    #[rstest]
+    #[case(AppConfig::default(), Err("database.user"))]
+    #[case(AppConfig::from_toml_str(FILE).unwrap(), Ok(()))]
+    #[case(
+        AppConfig::from_toml_str(&format!("{FILE}\nalgorithm = \"EdDSA\"")).unwrap(),
+        Err("auth.access_token.private_key_path")
+    )]
+    fn validate(#[case] config: AppConfig, #[case] expected: Result<(), &str>) {
+        let result = config.validate().map_err(|e| match e {
+            ConfigError::MissingValue(key) => key,
+            e => panic!("{e}"),
+        });
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    fn redacted_hides_secrets() {
+        let config = AppConfig::from_toml_str(FILE).unwrap().redacted();
+        assert_eq!(config.database.password, REDACTED);
+        assert_eq!(config.auth.access_token.secret, REDACTED);
+        let toml = config.to_toml().unwrap();
+        assert!(!toml.contains("file_password"));
+        assert!(!toml.contains("file_secret"));
+    }
+}
@@ -0,0 +1,311 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{JwtKeys, LoginThrottlePolicy, PasswordResetRateLimit, Totp};
+
+use super::ConfigError;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AuthConfig {
+    pub refresh_token_ttl_secs: u64,
+    pub mail_verification_ttl_secs: u64,
+    // NOTE: メールアドレス変更時に変更前のアドレスへ送る取り消しリンクの有効期間
+    pub mail_change_revert_ttl_secs: u64,
+    pub access_token: AccessTokenConfig,
+    pub password_hash: PasswordHashConfig,
+    pub password_reset: PasswordResetConfig,
+    pub login_throttle: LoginThrottleConfig,
+    pub two_factor: TwoFactorConfig,
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        Self {
+            refresh_token_ttl_secs: 14 * 24 * 60 * 60,
+            mail_verification_ttl_secs: 24 * 60 * 60,
+            mail_change_revert_ttl_secs: 7 * 24 * 60 * 60,
+            access_token: AccessTokenConfig::default(),
+            password_hash: PasswordHashConfig::default(),
+            password_reset: PasswordResetConfig::default(),
+            login_throttle: LoginThrottleConfig::default(),
+            two_factor: TwoFactorConfig::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum JwtAlgorithm {
+    #[default]
+    HS256,
+    EdDSA,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AccessTokenConfig {
+    pub algorithm: JwtAlgorithm,
+    // NOTE: HS256の共通鍵
+    pub secret: String,
+    // NOTE: EdDSAの鍵ペア(PEM形式)
+    pub private_key_path: Option<PathBuf>,
+    pub public_key_path: Option<PathBuf>,
+    pub issuer: String,
+    pub ttl_secs: u64,
+}
+
+impl Default for AccessTokenConfig {
+    fn default() -> Self {
+        Self {
+            algorithm: JwtAlgorithm::default(),
+            secret: String::new(),
+            private_key_path: None,
+            public_key_path: None,
+            issuer: "api_server".to_string(),
+            ttl_secs: 15 * 60,
+        }
+    }
+}
+
+impl AccessTokenConfig {
+    pub const MIN_SECRET_BYTES: usize = 32;
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        match self.algorithm {
+            JwtAlgorithm::HS256 if self.secret.is_empty() => {
+                Err(ConfigError::MissingValue("auth.access_token.secret"))
+            }
+            JwtAlgorithm::HS256 if self.secret.len() < Self::MIN_SECRET_BYTES => {
+                Err(ConfigError::InvalidValue {
+                    key: "auth.access_token.secret",
+                    reason: "32バイト以上を指定してください。",
+                })
+            }
+            JwtAlgorithm::EdDSA if self.private_key_path.is_none() => Err(
+                ConfigError::MissingValue("auth.access_token.private_key_path"),
+            ),
+            JwtAlgorithm::EdDSA if self.public_key_path.is_none() => Err(
+                ConfigError::MissingValue("auth.access_token.public_key_path"),
+            ),
+            _ if self.ttl_secs == 0 => Err(ConfigError::InvalidValue {
+                key: "auth.access_token.ttl_secs",
+                reason: "1以上を指定してください。",
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn load_keys(&self) -> Result<JwtKeys, ConfigError> {
+        match self.algorithm {
+            JwtAlgorithm::HS256 => Ok(JwtKeys::hs256(self.secret.as_bytes())),
+            JwtAlgorithm::EdDSA => {
+                let read = |path: &Option<PathBuf>, key: &'static str| {
+                    let path = path.as_ref().ok_or(ConfigError::MissingValue(key))?;
+                    std::fs::read(path).map_err(|source| ConfigError::ReadError {
+                        path: path.clone(),
+                        source,
+                    })
+                };
+                let private_key =
+                    read(&self.private_key_path, "auth.access_token.private_key_path")?;
+                let public_key = read(&self.public_key_path, "auth.access_token.public_key_path")?;
+                JwtKeys::eddsa_pem(&private_key, &public_key).map_err(|_| {
+                    ConfigError::InvalidValue {
+                        key: "auth.access_token",
+                        reason: "Ed25519の鍵(PEM形式)を指定してください。",
+                    }
+                })
+            }
+        }
+    }
+}
+
+// NOTE: Argon2idのパラメータ。変更後はログイン時に既存のハッシュが再計算される
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PasswordHashConfig {
+    pub memory_kib: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+}
+
+// NOTE: OWASP Password Storage Cheat Sheetの推奨値
+impl Default for PasswordHashConfig {
+    fn default() -> Self {
+        Self {
+            memory_kib: 19 * 1024,
+            iterations: 2,
+            parallelism: 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PasswordResetConfig {
+    pub ttl_secs: u64,
+    // NOTE: 発行数の制限。window_secsの間にアドレス・IPアドレスごとにmax_per_*回まで申請できる
+    pub window_secs: u64,
+    pub max_per_mail_address: u32,
+    pub max_per_ip_address: u32,
+    // NOTE: 受け付けた申請はissue_interval_msごとにissue_batch_size件ずつ処理し、再設定メールを発行する
+    pub issue_interval_ms: u64,
+    pub issue_batch_size: u32,
+}
+
+impl Default for PasswordResetConfig {
+    fn default() -> Self {
+        Self {
+            ttl_secs: 60 * 60,
+            window_secs: 60 * 60,
+            max_per_mail_address: 3,
+            max_per_ip_address: 20,
+            issue_interval_ms: 1000,
+            issue_batch_size: 20,
+        }
+    }
+}
+
+impl PasswordResetConfig {
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.ttl_secs == 0 {
+            return Err(ConfigError::InvalidValue {
+                key: "auth.password_reset.ttl_secs",
+                reason: "1以上を指定してください。",
+            });
+        }
+        if self.max_per_mail_address == 0 || self.max_per_ip_address == 0 {
+            return Err(ConfigError::InvalidValue {
+                key: "auth.password_reset",
+                reason: "max_per_mail_address・max_per_ip_addressは1以上を指定してください。",
+            });
+        }
+        if self.issue_interval_ms == 0 || self.issue_batch_size == 0 {
+            return Err(ConfigError::InvalidValue {
+                key: "auth.password_reset",
+                reason: "issue_interval_ms・issue_batch_sizeは1以上を指定してください。",
+            });
+        }
+        Ok(())
+    }
+
+    pub fn issue_interval(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(self.issue_interval_ms)
+    }
+
+    pub fn rate_limit(&self) -> PasswordResetRateLimit {
+        PasswordResetRateLimit {
+            window: chrono::Duration::seconds(self.window_secs as i64),
+            max_per_mail_address: self.max_per_mail_address,
+            max_per_ip_address: self.max_per_ip_address,
+        }
+    }
+}
+
+// NOTE: 閾値に達した後は失敗の度に待機時間が倍になる(base_delay_secs * 2^(失敗回数 - 閾値))
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LoginThrottleConfig {
+    // NOTE: アカウントごとの連続失敗回数。超えるとアカウントを一時的にロックする
+    pub account_threshold: u32,
+    // NOTE: 接続元IPアドレスごとの失敗回数。超えるとそのIPアドレスからのログインを待機させる
+    pub ip_address_threshold: u32,
+    pub ip_address_window_secs: u64,
+    pub base_delay_secs: u64,
+    pub max_delay_secs: u64,
+}
+
+impl Default for LoginThrottleConfig {
+    fn default() -> Self {
+        Self {
+            account_threshold: 5,
+            ip_address_threshold: 20,
+            ip_address_window_secs: 60 * 60,
+            base_delay_secs: 30,
+            max_delay_secs: 60 * 60,
+        }
+    }
+}
+
+impl LoginThrottleConfig {
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.account_threshold == 0 || self.ip_address_threshold == 0 {
+            return Err(ConfigError::InvalidValue {
+                key: "auth.login_throttle",
+                reason: "account_threshold・ip_address_thresholdは1以上を指定してください。",
+            });
+        }
+        if self.base_delay_secs > self.max_delay_secs {
+            return Err(ConfigError::InvalidValue {
+                key: "auth.login_throttle.max_delay_secs",
+                reason: "base_delay_secs以上を指定してください。",
+            });
+        }
+        Ok(())
+    }
+
+    pub fn policy(&self) -> LoginThrottlePolicy {
+        LoginThrottlePolicy {
+            account_threshold: self.account_threshold,
+            ip_address_threshold: self.ip_address_threshold,
+            base_delay: chrono::Duration::seconds(self.base_delay_secs as i64),
+            max_delay: chrono::Duration::seconds(self.max_delay_secs as i64),
+            ip_address_window: chrono::Duration::seconds(self.ip_address_window_secs as i64),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TwoFactorConfig {
+    // NOTE: 認証アプリに表示されるサービス名
+    pub issuer: String,
+    // NOTE: 端末の時計のずれとして許容する前後のステップ数(1ステップ30秒)
+    pub skew_steps: u32,
+    pub recovery_code_count: usize,
+    // NOTE: パスワードの確認後、二要素目を入力するまでの猶予
+    pub login_challenge_ttl_secs: u64,
+}
+
+impl Default for TwoFactorConfig {
+    fn default() -> Self {
+        Self {
+            issuer: "api_server".to_string(),
+            skew_steps: 1,
+            recovery_code_count: 10,
+            login_challenge_ttl_secs: 5 * 60,
+        }
+    }
+}
+
+impl TwoFactorConfig {
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.issuer.is_empty() || self.issuer.contains(':') {
+            return Err(ConfigError::InvalidValue {
+                key: "auth.two_factor.issuer",
+                reason: "空でなく、「:」を含まない値を指定してください。",
+            });
+        }
+        if self.recovery_code_count == 0 {
+            return Err(ConfigError::InvalidValue {
+                key: "auth.two_factor.recovery_code_count",
+                reason: "1以上を指定してください。",
+            });
+        }
+        if self.login_challenge_ttl_secs == 0 {
+            return Err(ConfigError::InvalidValue {
+                key: "auth.two_factor.login_challenge_ttl_secs",
+                reason: "1以上を指定してください。",
+            });
+        }
+        Ok(())
+    }
+
+    pub fn totp(&self) -> Totp {
+        Totp {
+            skew_steps: self.skew_steps.into(),
+            ..Totp::default()
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use actix_cors::Cors;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CorsConfig {
+    // NOTE: "*"を指定すると全てのオリジンを許可する
+    pub allowed_origins: Vec<String>,
+    pub allowed_methods: Vec<String>,
+    pub allowed_headers: Vec<String>,
+    pub max_age_secs: Option<usize>,
+    pub supports_credentials: bool,
+}
+
+impl CorsConfig {
+    pub fn to_cors(&self) -> Cors {
+        let mut cors = Cors::default()
+            .allowed_methods(self.allowed_methods.iter().map(String::as_str))
+            .allowed_headers(self.allowed_headers.iter().map(String::as_str))
+            .max_age(self.max_age_secs);
+        for origin in &self.allowed_origins {
+            cors = if origin == "*" {
+                cors.allow_any_origin()
+            } else {
+                cors.allowed_origin(origin)
+            };
+        }
+        if self.supports_credentials {
+            cors = cors.supports_credentials();
+        }
+        cors
+    }
+}
+
+impl Default for CorsConfig {
+    fn default() -> Self {
+        Self {
+            allowed_origins: Vec::new(),
+            allowed_methods: ["GET", "POST", "PUT", "DELETE"].map(String::from).to_vec(),
+            allowed_headers: ["Content-Type", "Authorization"].map(String::from).to_vec(),
+            max_age_secs: Some(3600),
+            supports_credentials: false,
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+use sqlx::postgres::PgConnectOptions;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DatabaseConfig {
+    pub user: String,
+    pub password: String,
+    pub host: String,
+    pub port: u16,
+    pub name: String,
+    pub max_connections: u32,
+    pub acquire_timeout_secs: u64,
+}
+
+impl DatabaseConfig {
+    // NOTE: URLを組み立てるとパスワードに含まれる@や/等をエスケープする必要があるため、項目ごとに指定する
+    pub fn connect_options(&self) -> PgConnectOptions {
+        PgConnectOptions::new()
+            .username(&self.user)
+            .password(&self.password)
+            .host(&self.host)
+            .port(self.port)
+            .database(&self.name)
+    }
+}
+
+impl Default for DatabaseConfig {
+    fn default() -> Self {
+        Self {
+            user: String::new(),
+            password: String::new(),
+            host: "localhost".to_string(),
+            port: 5432,
+            name: String::new(),
+            max_connections: 10,
+            acquire_timeout_secs: 30,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn connect_options_keep_special_characters() {
+        let config = DatabaseConfig {
+            user: "app@user".to_string(),
+            password: "p@ss/w:rd#1".to_string(),
+            host: "db.internal".to_string(),
+            port: 6432,
+            name: "app".to_string(),
+            ..Default::default()
+        };
+        let options = config.connect_options();
+        assert_eq!(options.get_username(), "app@user");
+        assert_eq!(options.get_host(), "db.internal");
+        assert_eq!(options.get_port(), 6432);
+        assert_eq!(options.get_database(), Some("app"));
+    }
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FeatureConfig {
+    pub access_log: bool,
+    pub user_registration: bool,
+    pub metrics: bool,
+}
+
+impl Default for FeatureConfig {
+    fn default() -> Self {
+        Self {
+            access_log: true,
+            user_registration: true,
+            metrics: true,
+        }
+    }
+}
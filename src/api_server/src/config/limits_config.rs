@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LimitsConfig {
+    pub json_payload_bytes: usize,
+    // NOTE: ワーカー毎の同時接続数の上限
+    pub max_connections: usize,
+    pub client_request_timeout_ms: u64,
+}
+
+impl Default for LimitsConfig {
+    fn default() -> Self {
+        Self {
+            json_payload_bytes: 32 * 1024,
+            max_connections: 25_000,
+            client_request_timeout_ms: 5_000,
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use std::{path::PathBuf, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::mailer::MailOutboxSettings;
+
+use super::ConfigError;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MailTransport {
+    #[default]
+    Stdout,
+    File,
+    Smtp,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct MailConfig {
+    pub transport: MailTransport,
+    pub from: String,
+    // NOTE: transport = "file"の場合の出力先
+    pub file_path: PathBuf,
+    pub smtp_host: String,
+    pub smtp_port: u16,
+    // NOTE: 確認メールに記載するURL。{token}が確認トークンに置き換えられる
+    pub verification_url: String,
+    // NOTE: メールアドレス変更の取り消しURL。{token}が取り消しトークンに置き換えられる
+    pub revert_url: String,
+    // NOTE: パスワード再設定のURL。{token}が再設定トークンに置き換えられる
+    pub password_reset_url: String,
+    pub outbox: MailOutboxConfig,
+}
+
+impl Default for MailConfig {
+    fn default() -> Self {
+        Self {
+            transport: MailTransport::default(),
+            from: "api_server <noreply@localhost>".to_string(),
+            file_path: PathBuf::from("mail.log"),
+            smtp_host: "localhost".to_string(),
+            smtp_port: 1025,
+            verification_url: "http://localhost:3000/verify?token={token}".to_string(),
+            revert_url: "http://localhost:3000/mail-address/revert?token={token}".to_string(),
+            password_reset_url: "http://localhost:3000/password-resets/{token}".to_string(),
+            outbox: MailOutboxConfig::default(),
+        }
+    }
+}
+
+// NOTE: 送信待ちのメールをpoll_interval_msごとにbatch_size件ずつ送信する
+//       失敗した場合はretry_backoff_secsから倍々に待機し、max_attempts回で送信を諦める
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct MailOutboxConfig {
+    pub poll_interval_ms: u64,
+    pub batch_size: u32,
+    pub max_attempts: u32,
+    pub retry_backoff_secs: u64,
+    pub max_retry_backoff_secs: u64,
+}
+
+impl Default for MailOutboxConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval_ms: 1000,
+            batch_size: 20,
+            max_attempts: 8,
+            retry_backoff_secs: 30,
+            max_retry_backoff_secs: 60 * 60,
+        }
+    }
+}
+
+impl MailOutboxConfig {
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.poll_interval_ms == 0 || self.batch_size == 0 || self.max_attempts == 0 {
+            return Err(ConfigError::InvalidValue {
+                key: "mail.outbox",
+                reason: "poll_interval_ms・batch_size・max_attemptsは1以上を指定してください。",
+            });
+        }
+        if self.retry_backoff_secs > self.max_retry_backoff_secs {
+            return Err(ConfigError::InvalidValue {
+                key: "mail.outbox.max_retry_backoff_secs",
+                reason: "retry_backoff_secs以上を指定してください。",
+            });
+        }
+        Ok(())
+    }
+
+    pub fn poll_interval(&self) -> Duration {
+        Duration::from_millis(self.poll_interval_ms)
+    }
+
+    pub fn settings(&self) -> MailOutboxSettings {
+        MailOutboxSettings {
+            max_attempts: self.max_attempts,
+            retry_backoff: chrono::Duration::seconds(self.retry_backoff_secs as i64),
+            max_retry_backoff: chrono::Duration::seconds(self.max_retry_backoff_secs as i64),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServerConfig {
+    pub bind: String,
+    // NOTE: 未指定の場合はactix-webのデフォルト(物理コア数)になる
+    pub workers: Option<usize>,
+    // NOTE: シグナル受信後、readinessを失敗させてから新規受付を止めるまでの猶予
+    pub shutdown_delay_ms: u64,
+    // NOTE: 処理中のリクエストの完了を待つ上限。超えた場合は中断される
+    pub shutdown_timeout_secs: u64,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            bind: "0.0.0.0:8080".to_string(),
+            workers: None,
+            shutdown_delay_ms: 5000,
+            shutdown_timeout_secs: 30,
+        }
+    }
+}
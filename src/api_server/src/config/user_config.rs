@@ -0,0 +1,53 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::UserNamePolicy;
+
+use super::ConfigError;
+
+// NOTE: ユーザーIDの採番方法
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IdStrategy {
+    #[default]
+    UuidV7,
+    Ulid,
+    // NOTE: DBのシーケンスによる連番
+    Sequence,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct UserConfig {
+    pub id_strategy: IdStrategy,
+    pub name_policy: UserNamePolicyConfig,
+}
+
+// NOTE: ユーザー名として使用できない語句
+//       reserved_wordsは組み込みの予約語(admin, root, system等)に追加される
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct UserNamePolicyConfig {
+    pub reserved_words: Vec<String>,
+    // NOTE: 1行に1語の禁止語句のファイル。空行と#で始まる行は無視する
+    pub blocklist_path: Option<PathBuf>,
+}
+
+impl UserNamePolicyConfig {
+    pub fn load(&self) -> Result<UserNamePolicy, ConfigError> {
+        let blocklist = match &self.blocklist_path {
+            Some(path) => {
+                std::fs::read_to_string(path).map_err(|source| ConfigError::ReadError {
+                    path: path.clone(),
+                    source,
+                })?
+            }
+            None => String::new(),
+        };
+        Ok(UserNamePolicy::new(
+            &self.reserved_words,
+            UserNamePolicy::parse_blocklist(&blocklist),
+        ))
+    }
+}
@@ -1 +1,21 @@
+pub mod account_lock_controller;
+pub mod api_key_controller;
+pub mod authentication;
+pub mod health_controller;
+pub mod metrics_controller;
+pub mod middleware;
+pub mod password_reset_controller;
+pub mod session_controller;
+pub mod two_factor_controller;
 pub mod user_controller;
+pub mod validated_json;
+
+use actix_web::HttpRequest;
+
+// NOTE: X-Forwarded-For等は詐称できるため、接続元のアドレスのみを使用する
+//       リバースプロキシの背後に置く場合はすべての接続元が同じアドレスになる点に注意
+pub fn peer_ip_address(req: &HttpRequest) -> String {
+    req.peer_addr()
+        .map(|addr| addr.ip().to_string())
+        .unwrap_or_default()
+}
@@ -0,0 +1,83 @@
+use actix_web::{http::StatusCode, web, HttpResponse};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+mod unlock;
+
+use unlock::*;
+
+use crate::{
+    i18n::{Localize, Message},
+    repository::{database_error::DatabaseError, TransactionManager},
+    use_case::{AccountLockUsecaseError, AccountUnlockUsecase},
+};
+
+pub fn config<TM, Usecase>(cfg: &mut web::ServiceConfig, usecase: Arc<Usecase>, tm: Arc<Mutex<TM>>)
+where
+    TM: TransactionManager + std::marker::Sync + std::marker::Send + 'static,
+    Usecase: AccountUnlockUsecase<TM> + std::marker::Send + std::marker::Sync + 'static,
+{
+    let usecase_data = web::Data::from(usecase);
+    let tm_data = web::Data::from(tm);
+    cfg.app_data(tm_data).app_data(usecase_data).route(
+        "/users/{id}/unlock",
+        web::post().to(handle_unlock_account::<TM, Usecase>),
+    );
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AccountLockControllerError {
+    #[error(transparent)]
+    AccountLockApplicationError(#[from] AccountLockUsecaseError),
+    #[error("DatabaseConnectionError")]
+    DatabaseError(#[from] DatabaseError),
+}
+
+impl AccountLockControllerError {
+    fn log(&self, message: &'static str) {
+        match self {
+            Self::AccountLockApplicationError(AccountLockUsecaseError::Forbidden(_)) => {
+                tracing::info!(error = %self, message)
+            }
+            _ => tracing::error!(error = %self, message),
+        }
+    }
+}
+
+impl Localize for AccountLockControllerError {
+    fn message(&self) -> Message {
+        match self {
+            // NOTE: 不正な形式のIDは存在しない場合と同じく扱う
+            Self::AccountLockApplicationError(AccountLockUsecaseError::UserIdError(_)) => {
+                Message::new("user-not-found")
+            }
+            Self::AccountLockApplicationError(e) => e.message(),
+            Self::DatabaseError(_) => Message::new("internal-error"),
+        }
+    }
+}
+
+impl actix_web::ResponseError for AccountLockControllerError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::AccountLockApplicationError(AccountLockUsecaseError::Forbidden(_)) => {
+                StatusCode::FORBIDDEN
+            }
+            Self::AccountLockApplicationError(
+                AccountLockUsecaseError::UserIdError(_)
+                | AccountLockUsecaseError::UserIdNotExistsError(_),
+            ) => StatusCode::NOT_FOUND,
+            Self::AccountLockApplicationError(_) | Self::DatabaseError(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        match self.status_code() {
+            // NOTE: 内部エラーの詳細はログにのみ出力する
+            StatusCode::INTERNAL_SERVER_ERROR => HttpResponse::InternalServerError().finish(),
+            status => HttpResponse::build(status).body(self.message().to_string()),
+        }
+    }
+}
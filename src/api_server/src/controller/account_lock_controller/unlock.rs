@@ -0,0 +1,48 @@
+use actix_web::web;
+use tokio::sync::Mutex;
+
+use crate::{
+    controller::{authentication::AuthenticatedUser, user_controller::UserPathParams},
+    metrics::metrics,
+    repository::TransactionManager,
+    use_case::AccountUnlockUsecase,
+};
+
+use super::AccountLockControllerError;
+
+pub async fn handle_unlock_account<TM, Usecase>(
+    user: AuthenticatedUser,
+    params: web::Path<UserPathParams>,
+    tx_manager: web::Data<Mutex<TM>>,
+    usecase: web::Data<Usecase>,
+) -> Result<web::Json<()>, actix_web::Error>
+where
+    Usecase: AccountUnlockUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    Ok(unlock_account_controller(
+        tx_manager.as_ref(),
+        usecase.as_ref(),
+        user,
+        params.into_inner(),
+    )
+    .await
+    .inspect_err(|e| e.log("failed to unlock account"))
+    .map(web::Json)?)
+}
+
+async fn unlock_account_controller<Usecase, TM>(
+    tx_manager: &Mutex<TM>,
+    usecase: &Usecase,
+    user: AuthenticatedUser,
+    params: UserPathParams,
+) -> Result<(), AccountLockControllerError>
+where
+    Usecase: AccountUnlockUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    let mut tx = TM::begin(tx_manager).await?;
+    let res = usecase.unlock(&mut tx, user.user_id, params.id).await;
+    metrics().observe_usecase("unlock", &res);
+    TM::execute(tx, res).await
+}
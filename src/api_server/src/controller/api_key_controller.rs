@@ -0,0 +1,137 @@
+use actix_web::{http::StatusCode, web, HttpResponse};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+mod create;
+mod list;
+mod revoke;
+
+use create::*;
+use list::*;
+use revoke::*;
+
+use crate::{
+    domain::ApiKeyError,
+    i18n::{Localize, Message},
+    repository::{database_error::DatabaseError, TransactionManager},
+    use_case::{ApiKeyDto, ApiKeyManageUsecase, ApiKeyUsecaseError},
+};
+
+// NOTE: 管理者向けのAPIキーの管理。APIキー自体での呼び出しは受け付けない
+pub fn config<TM, Usecase>(cfg: &mut web::ServiceConfig, usecase: Arc<Usecase>, tm: Arc<Mutex<TM>>)
+where
+    TM: TransactionManager + std::marker::Sync + std::marker::Send + 'static,
+    Usecase: ApiKeyManageUsecase<TM> + std::marker::Send + std::marker::Sync + 'static,
+{
+    let usecase_data = web::Data::from(usecase);
+    let tm_data = web::Data::from(tm);
+    cfg.app_data(tm_data)
+        .app_data(usecase_data)
+        .service(
+            web::resource("/api-keys")
+                .route(web::get().to(handle_list_api_keys::<TM, Usecase>))
+                .route(web::post().to(handle_create_api_key::<TM, Usecase>)),
+        )
+        .route(
+            "/api-keys/{id}/revoke",
+            web::post().to(handle_revoke_api_key::<TM, Usecase>),
+        );
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ApiKeyPathParams {
+    pub id: Uuid,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ApiKeyJdto {
+    id: Uuid,
+    name: String,
+    prefix: String,
+    scopes: Vec<String>,
+    created_by: Uuid,
+    created_at: DateTime<Utc>,
+    last_used_at: Option<DateTime<Utc>>,
+    revoked_at: Option<DateTime<Utc>>,
+}
+
+impl From<ApiKeyDto> for ApiKeyJdto {
+    fn from(value: ApiKeyDto) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            prefix: value.prefix,
+            scopes: value.scopes,
+            created_by: value.created_by,
+            created_at: value.created_at,
+            last_used_at: value.last_used_at,
+            revoked_at: value.revoked_at,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ApiKeyControllerError {
+    #[error(transparent)]
+    ApiKeyApplicationError(#[from] ApiKeyUsecaseError),
+    #[error("DatabaseConnectionError")]
+    DatabaseError(#[from] DatabaseError),
+}
+
+impl ApiKeyControllerError {
+    fn log(&self, message: &'static str) {
+        match actix_web::ResponseError::status_code(self) {
+            StatusCode::INTERNAL_SERVER_ERROR => tracing::error!(error = %self, message),
+            _ => tracing::info!(error = %self, message),
+        }
+    }
+}
+
+impl Localize for ApiKeyControllerError {
+    fn message(&self) -> Message {
+        match self {
+            // NOTE: 不正な形式のIDは存在しない場合と同じく扱う
+            Self::ApiKeyApplicationError(ApiKeyUsecaseError::ApiKeyIdError(_)) => {
+                Message::new("api-key-not-found")
+            }
+            Self::ApiKeyApplicationError(e) => e.message(),
+            Self::DatabaseError(_) => Message::new("internal-error"),
+        }
+    }
+}
+
+impl actix_web::ResponseError for ApiKeyControllerError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::ApiKeyApplicationError(ApiKeyUsecaseError::Forbidden(_)) => StatusCode::FORBIDDEN,
+            Self::ApiKeyApplicationError(
+                ApiKeyUsecaseError::ApiKeyIdError(_) | ApiKeyUsecaseError::ApiKeyNotFound,
+            ) => StatusCode::NOT_FOUND,
+            Self::ApiKeyApplicationError(ApiKeyUsecaseError::ApiKeyError(
+                ApiKeyError::AlreadyRevoked,
+            )) => StatusCode::CONFLICT,
+            Self::ApiKeyApplicationError(
+                ApiKeyUsecaseError::ApiKeyScopeError(_)
+                | ApiKeyUsecaseError::ApiKeyError(
+                    ApiKeyError::EmptyName
+                    | ApiKeyError::NameTooLong { .. }
+                    | ApiKeyError::NoScopes,
+                ),
+            ) => StatusCode::BAD_REQUEST,
+            Self::ApiKeyApplicationError(_) | Self::DatabaseError(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        match self.status_code() {
+            // NOTE: 内部エラーの詳細はログにのみ出力する
+            StatusCode::INTERNAL_SERVER_ERROR => HttpResponse::InternalServerError().finish(),
+            status => HttpResponse::build(status).body(self.message().to_string()),
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use actix_web::web;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use crate::{
+    controller::authentication::AuthenticatedUser,
+    metrics::metrics,
+    repository::TransactionManager,
+    use_case::{ApiKeyManageUsecase, IssuedApiKeyDto},
+};
+
+use super::{ApiKeyControllerError, ApiKeyJdto};
+
+pub async fn handle_create_api_key<TM, Usecase>(
+    user: AuthenticatedUser,
+    info: web::Json<CreateApiKeyRequestJdto>,
+    tx_manager: web::Data<Mutex<TM>>,
+    usecase: web::Data<Usecase>,
+) -> Result<web::Json<IssuedApiKeyJdto>, actix_web::Error>
+where
+    Usecase: ApiKeyManageUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    Ok(create_api_key_controller(
+        tx_manager.as_ref(),
+        usecase.as_ref(),
+        user,
+        info.into_inner(),
+    )
+    .await
+    .inspect_err(|e| e.log("failed to create api key"))
+    .map(web::Json)?)
+}
+
+async fn create_api_key_controller<Usecase, TM>(
+    tx_manager: &Mutex<TM>,
+    usecase: &Usecase,
+    user: AuthenticatedUser,
+    info: CreateApiKeyRequestJdto,
+) -> Result<IssuedApiKeyJdto, ApiKeyControllerError>
+where
+    Usecase: ApiKeyManageUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    let mut tx = TM::begin(tx_manager).await?;
+    let res = usecase
+        .create(&mut tx, user.user_id, info.name, info.scopes)
+        .await;
+    metrics().observe_usecase("create_api_key", &res);
+    Ok(TM::execute::<_, _, ApiKeyControllerError>(tx, res)
+        .await?
+        .into())
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateApiKeyRequestJdto {
+    name: String,
+    scopes: Vec<String>,
+}
+
+// NOTE: 平文のキーはこのレスポンスでのみ返す
+#[derive(Serialize, Debug)]
+pub struct IssuedApiKeyJdto {
+    #[serde(flatten)]
+    api_key: ApiKeyJdto,
+    key: String,
+}
+
+impl From<IssuedApiKeyDto> for IssuedApiKeyJdto {
+    fn from(value: IssuedApiKeyDto) -> Self {
+        Self {
+            api_key: value.api_key.into(),
+            key: value.key,
+        }
+    }
+}
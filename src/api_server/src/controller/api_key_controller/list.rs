@@ -0,0 +1,45 @@
+use actix_web::web;
+use tokio::sync::Mutex;
+
+use crate::{
+    controller::authentication::AuthenticatedUser, metrics::metrics,
+    repository::TransactionManager, use_case::ApiKeyManageUsecase,
+};
+
+use super::{ApiKeyControllerError, ApiKeyJdto};
+
+pub async fn handle_list_api_keys<TM, Usecase>(
+    user: AuthenticatedUser,
+    tx_manager: web::Data<Mutex<TM>>,
+    usecase: web::Data<Usecase>,
+) -> Result<web::Json<Vec<ApiKeyJdto>>, actix_web::Error>
+where
+    Usecase: ApiKeyManageUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    Ok(
+        list_api_keys_controller(tx_manager.as_ref(), usecase.as_ref(), user)
+            .await
+            .inspect_err(|e| e.log("failed to list api keys"))
+            .map(web::Json)?,
+    )
+}
+
+async fn list_api_keys_controller<Usecase, TM>(
+    tx_manager: &Mutex<TM>,
+    usecase: &Usecase,
+    user: AuthenticatedUser,
+) -> Result<Vec<ApiKeyJdto>, ApiKeyControllerError>
+where
+    Usecase: ApiKeyManageUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    let mut tx = TM::begin(tx_manager).await?;
+    let res = usecase.list(&mut tx, user.user_id).await;
+    metrics().observe_usecase("list_api_keys", &res);
+    Ok(TM::execute::<_, _, ApiKeyControllerError>(tx, res)
+        .await?
+        .into_iter()
+        .map(ApiKeyJdto::from)
+        .collect())
+}
@@ -0,0 +1,46 @@
+use actix_web::web;
+use tokio::sync::Mutex;
+
+use crate::{
+    controller::authentication::AuthenticatedUser, metrics::metrics,
+    repository::TransactionManager, use_case::ApiKeyManageUsecase,
+};
+
+use super::{ApiKeyControllerError, ApiKeyPathParams};
+
+pub async fn handle_revoke_api_key<TM, Usecase>(
+    user: AuthenticatedUser,
+    params: web::Path<ApiKeyPathParams>,
+    tx_manager: web::Data<Mutex<TM>>,
+    usecase: web::Data<Usecase>,
+) -> Result<web::Json<()>, actix_web::Error>
+where
+    Usecase: ApiKeyManageUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    Ok(revoke_api_key_controller(
+        tx_manager.as_ref(),
+        usecase.as_ref(),
+        user,
+        params.into_inner(),
+    )
+    .await
+    .inspect_err(|e| e.log("failed to revoke api key"))
+    .map(web::Json)?)
+}
+
+async fn revoke_api_key_controller<Usecase, TM>(
+    tx_manager: &Mutex<TM>,
+    usecase: &Usecase,
+    user: AuthenticatedUser,
+    params: ApiKeyPathParams,
+) -> Result<(), ApiKeyControllerError>
+where
+    Usecase: ApiKeyManageUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    let mut tx = TM::begin(tx_manager).await?;
+    let res = usecase.revoke(&mut tx, user.user_id, params.id).await;
+    metrics().observe_usecase("revoke_api_key", &res);
+    TM::execute(tx, res).await
+}
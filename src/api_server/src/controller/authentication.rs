@@ -0,0 +1,448 @@
+use std::{
+    future::{ready, Future, Ready},
+    marker::PhantomData,
+    pin::Pin,
+    sync::Arc,
+};
+
+use actix_web::{
+    dev::Payload,
+    http::{
+        header::{self, HeaderValue},
+        StatusCode,
+    },
+    web, FromRequest, HttpRequest, HttpResponse,
+};
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+
+use crate::{
+    domain::{AccessTokenCodec, AccessTokenError, ActorId, ApiKeyScope, UserId},
+    i18n::{Localize, Message},
+    metrics::metrics,
+    repository::{database_error::DatabaseError, TransactionManager},
+    use_case::{ApiKeyAuthenticateUsecase, ApiKeyPrincipalDto, ApiKeyUsecaseError},
+};
+
+const REALM: &str = "api_server";
+
+// NOTE: web::Data<AccessTokenVerifier>としてApp全体に登録しておく
+pub type AccessTokenVerifier = dyn AccessTokenCodec + Send + Sync;
+
+// NOTE: web::Data<ApiKeyVerifier>としてApp全体に登録しておく
+pub type ApiKeyVerifier = dyn ApiKeyAuthenticator + Send + Sync;
+
+#[async_trait]
+pub trait ApiKeyAuthenticator {
+    async fn authenticate(
+        &self,
+        key: &str,
+    ) -> Result<Option<ApiKeyPrincipalDto>, AuthenticationError>;
+}
+
+// NOTE: ハンドラとは別のトランザクションで認証し、最終使用日時の記録をハンドラの成否と切り離す
+pub struct UsecaseApiKeyAuthenticator<TM, Usecase> {
+    tm: Arc<Mutex<TM>>,
+    usecase: Arc<Usecase>,
+}
+
+impl<TM, Usecase> UsecaseApiKeyAuthenticator<TM, Usecase> {
+    pub fn new(tm: Arc<Mutex<TM>>, usecase: Arc<Usecase>) -> Self {
+        Self { tm, usecase }
+    }
+}
+
+#[async_trait]
+impl<TM, Usecase> ApiKeyAuthenticator for UsecaseApiKeyAuthenticator<TM, Usecase>
+where
+    TM: TransactionManager + Send + Sync,
+    Usecase: ApiKeyAuthenticateUsecase<TM> + Send + Sync,
+{
+    async fn authenticate(
+        &self,
+        key: &str,
+    ) -> Result<Option<ApiKeyPrincipalDto>, AuthenticationError> {
+        let mut tx = TM::begin(&self.tm).await?;
+        let res = self.usecase.authenticate(&mut tx, key).await;
+        metrics().observe_usecase("authenticate_api_key", &res);
+        TM::execute(tx, res).await
+    }
+}
+
+// NOTE: ハンドラの引数に指定すると、有効なアクセストークンを持つリクエストのみを受け付ける
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuthenticatedUser {
+    pub user_id: UserId,
+}
+
+impl FromRequest for AuthenticatedUser {
+    type Error = AuthenticationError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(authenticate(req))
+    }
+}
+
+fn authenticate(req: &HttpRequest) -> Result<AuthenticatedUser, AuthenticationError> {
+    let verifier = req
+        .app_data::<web::Data<AccessTokenVerifier>>()
+        .ok_or(AuthenticationError::VerifierNotConfigured)?;
+    let token = bearer_token(req).ok_or(AuthenticationError::MissingToken)?;
+    let claims = verifier.decode(token)?;
+    tracing::Span::current().record("user_id", tracing::field::display(&claims.user_id));
+    Ok(AuthenticatedUser {
+        user_id: claims.user_id,
+    })
+}
+
+fn bearer_token(req: &HttpRequest) -> Option<&str> {
+    credentials(req, "Bearer")
+}
+
+fn credentials<'a>(req: &'a HttpRequest, expected_scheme: &str) -> Option<&'a str> {
+    let value = req.headers().get(header::AUTHORIZATION)?.to_str().ok()?;
+    let (scheme, credentials) = value.split_once(' ')?;
+    scheme
+        .eq_ignore_ascii_case(expected_scheme)
+        .then_some(credentials.trim())
+        .filter(|credentials| !credentials.is_empty())
+}
+
+// NOTE: APIキーで呼び出す場合にルートが要求するスコープ
+pub trait RequiredScope {
+    const SCOPE: ApiKeyScope;
+}
+
+pub struct UsersRead;
+
+impl RequiredScope for UsersRead {
+    const SCOPE: ApiKeyScope = ApiKeyScope::UsersRead;
+}
+
+pub struct UsersWrite;
+
+impl RequiredScope for UsersWrite {
+    const SCOPE: ApiKeyScope = ApiKeyScope::UsersWrite;
+}
+
+// NOTE: ハンドラの引数に指定すると、アクセストークン(Bearer)とAPIキー(ApiKey)の両方を受け付ける
+//       APIキーの場合はユースケースの実行前にSのスコープを検査する
+//       ユーザーの場合の認可はユースケース内のポリシーで行う
+#[derive(Debug, Clone, PartialEq)]
+pub struct Authorized<S> {
+    pub actor_id: ActorId,
+    _scope: PhantomData<fn() -> S>,
+}
+
+impl<S> Authorized<S> {
+    fn new(actor_id: ActorId) -> Self {
+        Self {
+            actor_id,
+            _scope: PhantomData,
+        }
+    }
+}
+
+impl<S> FromRequest for Authorized<S>
+where
+    S: RequiredScope + 'static,
+{
+    type Error = AuthenticationError;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        if bearer_token(req).is_some() {
+            let res = authenticate(req).map(|user| Self::new(ActorId::User(user.user_id)));
+            return Box::pin(ready(res));
+        }
+        let Some(key) = credentials(req, "ApiKey").map(str::to_string) else {
+            return Box::pin(ready(Err(AuthenticationError::MissingCredentials)));
+        };
+        let verifier = req.app_data::<web::Data<ApiKeyVerifier>>().cloned();
+        Box::pin(async move {
+            let verifier = verifier.ok_or(AuthenticationError::VerifierNotConfigured)?;
+            let principal = verifier
+                .authenticate(&key)
+                .await?
+                .ok_or(AuthenticationError::InvalidApiKey)?;
+            tracing::Span::current().record("api_key", principal.prefix.as_str());
+            if !principal.scopes.contains(&S::SCOPE) {
+                return Err(AuthenticationError::InsufficientScope { required: S::SCOPE });
+            }
+            Ok(Self::new(ActorId::ApiKey(principal.id)))
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AuthenticationError {
+    #[error("認証が必要です。")]
+    MissingToken,
+    #[error("認証が必要です。")]
+    MissingCredentials,
+    #[error(transparent)]
+    InvalidToken(#[from] AccessTokenError),
+    #[error("APIキーが無効です。")]
+    InvalidApiKey,
+    #[error("APIキーに{required}のスコープがありません。")]
+    InsufficientScope { required: ApiKeyScope },
+    #[error("認証に使用する検証器が登録されていません。")]
+    VerifierNotConfigured,
+    #[error(transparent)]
+    ApiKeyUsecaseError(#[from] ApiKeyUsecaseError),
+    #[error("DatabaseConnectionError")]
+    DatabaseError(#[from] DatabaseError),
+}
+
+impl Localize for AuthenticationError {
+    fn message(&self) -> Message {
+        match self {
+            Self::MissingToken | Self::MissingCredentials => {
+                Message::new("authentication-required")
+            }
+            Self::InvalidToken(e) => e.message(),
+            Self::InvalidApiKey => Message::new("api-key-invalid"),
+            Self::InsufficientScope { required } => {
+                Message::new("api-key-insufficient-scope").arg("required", required.to_string())
+            }
+            Self::VerifierNotConfigured | Self::ApiKeyUsecaseError(_) | Self::DatabaseError(_) => {
+                Message::new("internal-error")
+            }
+        }
+    }
+}
+
+impl actix_web::ResponseError for AuthenticationError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::MissingToken
+            | Self::MissingCredentials
+            | Self::InvalidToken(_)
+            | Self::InvalidApiKey => StatusCode::UNAUTHORIZED,
+            Self::InsufficientScope { .. } => StatusCode::FORBIDDEN,
+            Self::VerifierNotConfigured | Self::ApiKeyUsecaseError(_) | Self::DatabaseError(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+
+    // NOTE: RFC 6750に従い、401・403にはWWW-Authenticateヘッダを付与する
+    fn error_response(&self) -> HttpResponse {
+        let challenges = match self {
+            Self::MissingToken => vec![format!(r#"Bearer realm="{REALM}""#)],
+            Self::MissingCredentials => vec![
+                format!(r#"Bearer realm="{REALM}""#),
+                format!(r#"ApiKey realm="{REALM}""#),
+            ],
+            Self::InvalidToken(AccessTokenError::Expired) => vec![format!(
+                r#"Bearer realm="{REALM}", error="invalid_token", error_description="token expired""#
+            )],
+            Self::InvalidToken(_) => {
+                vec![format!(r#"Bearer realm="{REALM}", error="invalid_token""#)]
+            }
+            Self::InvalidApiKey => {
+                vec![format!(r#"ApiKey realm="{REALM}", error="invalid_token""#)]
+            }
+            Self::InsufficientScope { required } => vec![format!(
+                r#"ApiKey realm="{REALM}", error="insufficient_scope", scope="{required}""#
+            )],
+            Self::VerifierNotConfigured | Self::ApiKeyUsecaseError(_) | Self::DatabaseError(_) => {
+                tracing::error!(error = %self, "failed to authenticate");
+                return HttpResponse::InternalServerError().finish();
+            }
+        };
+        let mut res = HttpResponse::build(self.status_code());
+        for challenge in challenges {
+            res.append_header((
+                header::WWW_AUTHENTICATE,
+                HeaderValue::from_str(&challenge).expect("valid header value"),
+            ));
+        }
+        res.body(self.message().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::domain::{ApiKeyId, JwtAccessTokenCodec, JwtKeys};
+    use actix_web::{test, App};
+    use chrono::{Duration, Utc};
+    use uuid::Uuid;
+
+    fn codec() -> JwtAccessTokenCodec {
+        JwtAccessTokenCodec::new(
+            JwtKeys::hs256(b"secret"),
+            "test".to_string(),
+            Duration::minutes(15),
+        )
+    }
+
+    async fn whoami(user: AuthenticatedUser) -> String {
+        user.user_id.to_string()
+    }
+
+    async fn call(authorization: Option<String>) -> (StatusCode, Option<String>, String) {
+        let verifier: Arc<AccessTokenVerifier> = Arc::new(codec());
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::from(verifier))
+                .route("/", web::get().to(whoami)),
+        )
+        .await;
+        let mut req = test::TestRequest::get().uri("/");
+        if let Some(value) = authorization {
+            req = req.insert_header((header::AUTHORIZATION, value));
+        }
+        let res = test::call_service(&app, req.to_request()).await;
+        let status = res.status();
+        let challenge = res
+            .headers()
+            .get(header::WWW_AUTHENTICATE)
+            .map(|value| value.to_str().unwrap().to_string());
+        let body = test::read_body(res).await;
+        (status, challenge, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[actix_web::test]
+    async fn accepts_valid_token() {
+        let user_id = UserId::new(Uuid::new_v4()).unwrap();
+        let token = codec().encode(&user_id, Utc::now()).unwrap().token;
+
+        let (status, challenge, body) = call(Some(format!("Bearer {token}"))).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(challenge, None);
+        assert_eq!(body, user_id.to_string());
+    }
+
+    #[actix_web::test]
+    async fn rejects_missing_or_invalid_token() {
+        let (status, challenge, _) = call(None).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(challenge.as_deref(), Some(r#"Bearer realm="api_server""#));
+
+        let (status, challenge, _) = call(Some("Bearer garbage".to_string())).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert!(challenge.unwrap().contains(r#"error="invalid_token""#));
+
+        let (status, _, _) = call(Some("Basic dXNlcjpwYXNz".to_string())).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    const API_KEY: &str = "ak_test";
+
+    struct FakeApiKeyAuthenticator(ApiKeyPrincipalDto);
+
+    #[async_trait]
+    impl ApiKeyAuthenticator for FakeApiKeyAuthenticator {
+        async fn authenticate(
+            &self,
+            key: &str,
+        ) -> Result<Option<ApiKeyPrincipalDto>, AuthenticationError> {
+            Ok((key == API_KEY).then(|| self.0.clone()))
+        }
+    }
+
+    async fn whoami_with_scope(principal: Authorized<UsersWrite>) -> String {
+        principal.actor_id.to_string()
+    }
+
+    async fn call_with_scope(
+        authorization: Option<String>,
+        principal: &ApiKeyPrincipalDto,
+    ) -> (StatusCode, Vec<String>, String) {
+        let verifier: Arc<AccessTokenVerifier> = Arc::new(codec());
+        let api_key_verifier: Arc<ApiKeyVerifier> =
+            Arc::new(FakeApiKeyAuthenticator(principal.clone()));
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::from(verifier))
+                .app_data(web::Data::from(api_key_verifier))
+                .route("/", web::get().to(whoami_with_scope)),
+        )
+        .await;
+        let mut req = test::TestRequest::get().uri("/");
+        if let Some(value) = authorization {
+            req = req.insert_header((header::AUTHORIZATION, value));
+        }
+        let res = test::call_service(&app, req.to_request()).await;
+        let status = res.status();
+        let challenges = res
+            .headers()
+            .get_all(header::WWW_AUTHENTICATE)
+            .map(|value| value.to_str().unwrap().to_string())
+            .collect();
+        let body = test::read_body(res).await;
+        (
+            status,
+            challenges,
+            String::from_utf8(body.to_vec()).unwrap(),
+        )
+    }
+
+    fn principal(scopes: Vec<ApiKeyScope>) -> ApiKeyPrincipalDto {
+        ApiKeyPrincipalDto {
+            id: ApiKeyId::new(Uuid::new_v4()).unwrap(),
+            prefix: "ak_test".to_string(),
+            scopes,
+        }
+    }
+
+    #[actix_web::test]
+    async fn authorized_accepts_user_token_and_api_key() {
+        let principal = principal(vec![ApiKeyScope::UsersRead, ApiKeyScope::UsersWrite]);
+        let user_id = UserId::new(Uuid::new_v4()).unwrap();
+        let token = codec().encode(&user_id, Utc::now()).unwrap().token;
+
+        let (status, _, body) = call_with_scope(Some(format!("Bearer {token}")), &principal).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, ActorId::User(user_id).to_string());
+
+        let (status, _, body) =
+            call_with_scope(Some(format!("ApiKey {API_KEY}")), &principal).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, ActorId::ApiKey(principal.id).to_string());
+    }
+
+    #[actix_web::test]
+    async fn authorized_rejects_api_key_without_scope() {
+        let principal = principal(vec![ApiKeyScope::UsersRead]);
+
+        let (status, challenges, _) =
+            call_with_scope(Some(format!("ApiKey {API_KEY}")), &principal).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(
+            challenges,
+            vec![r#"ApiKey realm="api_server", error="insufficient_scope", scope="users:write""#]
+        );
+    }
+
+    #[actix_web::test]
+    async fn authorized_rejects_missing_or_invalid_credentials() {
+        let principal = principal(vec![ApiKeyScope::UsersWrite]);
+
+        let (status, challenges, _) = call_with_scope(None, &principal).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            challenges,
+            vec![
+                r#"Bearer realm="api_server""#,
+                r#"ApiKey realm="api_server""#
+            ]
+        );
+
+        let (status, challenges, _) =
+            call_with_scope(Some("ApiKey ak_unknown".to_string()), &principal).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            challenges,
+            vec![r#"ApiKey realm="api_server", error="invalid_token""#]
+        );
+
+        let (status, _, _) = call_with_scope(Some("Bearer garbage".to_string()), &principal).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+}
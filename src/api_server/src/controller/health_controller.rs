@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use actix_web::{web, HttpResponse};
+
+use crate::health::{HealthChecker, HealthReport};
+
+pub fn config(cfg: &mut web::ServiceConfig, health: Arc<HealthChecker>) {
+    cfg.app_data(web::Data::from(health))
+        .route("/healthz", web::get().to(handle_liveness))
+        .route("/readyz", web::get().to(handle_readiness));
+}
+
+async fn handle_liveness(health: web::Data<HealthChecker>) -> HttpResponse {
+    respond(health.liveness())
+}
+
+async fn handle_readiness(health: web::Data<HealthChecker>) -> HttpResponse {
+    respond(health.readiness().await)
+}
+
+fn respond(report: HealthReport) -> HttpResponse {
+    if report.is_ok() {
+        HttpResponse::Ok().json(report)
+    } else {
+        HttpResponse::ServiceUnavailable().json(report)
+    }
+}
@@ -0,0 +1,19 @@
+use actix_web::{web, HttpResponse};
+
+use crate::metrics::metrics;
+
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.route("/metrics", web::get().to(handle_metrics));
+}
+
+async fn handle_metrics() -> HttpResponse {
+    match metrics().encode() {
+        Ok(body) => HttpResponse::Ok()
+            .content_type("text/plain; version=0.0.4")
+            .body(body),
+        Err(e) => {
+            tracing::error!(error = %e, "failed to encode metrics");
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
@@ -0,0 +1,9 @@
+mod http_metrics;
+mod locale;
+mod request_id;
+mod request_tracking;
+
+pub use http_metrics::*;
+pub use locale::*;
+pub use request_id::*;
+pub use request_tracking::*;
@@ -0,0 +1,64 @@
+use actix_web::{
+    body::MessageBody,
+    dev::{ServiceRequest, ServiceResponse},
+    http::header::{self, HeaderValue},
+    middleware::Next,
+};
+
+use crate::i18n::{with_locale, Locale};
+
+// NOTE: Accept-Languageからエラーメッセージの言語を決め、ハンドラの処理をその言語のスコープで実行する
+//       ResponseError::error_responseはリクエストを参照できないため、タスクローカルで受け渡す
+pub async fn locale(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+    let locale = req
+        .headers()
+        .get(header::ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+        .map(Locale::negotiate)
+        .unwrap_or_default();
+    let mut res = with_locale(locale, next.call(req)).await?;
+    res.headers_mut().insert(
+        header::CONTENT_LANGUAGE,
+        HeaderValue::from_static(locale.as_str()),
+    );
+    res.headers_mut()
+        .append(header::VARY, HeaderValue::from_static("accept-language"));
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::Message;
+    use actix_web::{middleware::from_fn, test, web, App, HttpResponse};
+
+    async fn handler() -> HttpResponse {
+        HttpResponse::BadRequest().body(Message::new("user-not-found").to_string())
+    }
+
+    #[actix_web::test]
+    async fn localizes_by_accept_language() {
+        let app = test::init_service(
+            App::new()
+                .wrap(from_fn(locale))
+                .route("/", web::get().to(handler)),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/")
+            .insert_header((header::ACCEPT_LANGUAGE, "en-US,en;q=0.9"))
+            .to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.headers().get(header::CONTENT_LANGUAGE).unwrap(), "en");
+        assert_eq!(test::read_body(res).await, "The user was not found.");
+
+        let req = test::TestRequest::get().uri("/").to_request();
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.headers().get(header::CONTENT_LANGUAGE).unwrap(), "ja");
+        assert_eq!(test::read_body(res).await, "ユーザーが見つかりません。");
+    }
+}
@@ -0,0 +1,88 @@
+use actix_web::{
+    body::MessageBody,
+    dev::{ServiceRequest, ServiceResponse},
+    http::header::{HeaderName, HeaderValue},
+    middleware::Next,
+    HttpMessage as _,
+};
+use tracing::Instrument as _;
+use uuid::Uuid;
+
+pub const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+const MAX_REQUEST_ID_LENGTH: usize = 128;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestId(String);
+
+impl RequestId {
+    // NOTE: 上流(ロードバランサ等)から渡されたIDは形式が妥当な場合のみ引き継ぐ
+    fn from_header(value: Option<&HeaderValue>) -> Self {
+        value
+            .and_then(|value| value.to_str().ok())
+            .filter(|value| {
+                !value.is_empty()
+                    && value.len() <= MAX_REQUEST_ID_LENGTH
+                    && value
+                        .bytes()
+                        .all(|b| b.is_ascii_alphanumeric() || b"-_.".contains(&b))
+            })
+            .map(|value| Self(value.to_string()))
+            .unwrap_or_else(|| Self(Uuid::new_v4().to_string()))
+    }
+
+    pub fn get(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for RequestId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// NOTE: リクエスト毎にspanを作成し、配下のユースケースやリポジトリのspanを紐づける
+pub async fn request_id(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+    let request_id = RequestId::from_header(req.headers().get(&REQUEST_ID_HEADER));
+    let span = tracing::info_span!(
+        "http_request",
+        request_id = %request_id,
+        method = %req.method(),
+        path = %req.path(),
+        status = tracing::field::Empty,
+        user_id = tracing::field::Empty,
+        api_key = tracing::field::Empty,
+    );
+    req.extensions_mut().insert(request_id.clone());
+
+    let mut res = next.call(req).instrument(span.clone()).await?;
+    span.record("status", res.status().as_u16());
+    if let Ok(value) = HeaderValue::from_str(request_id.get()) {
+        res.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("abc-123", Some("abc-123"))]
+    #[case("", None)]
+    #[case("contains space", None)]
+    #[case(&"a".repeat(MAX_REQUEST_ID_LENGTH + 1), None)]
+    fn from_header(#[case] header: &str, #[case] expected: Option<&str>) {
+        let value = HeaderValue::from_str(header).unwrap();
+        let request_id = RequestId::from_header(Some(&value));
+        match expected {
+            Some(expected) => assert_eq!(request_id.get(), expected),
+            None => assert!(Uuid::parse_str(request_id.get()).is_ok()),
+        }
+    }
+}
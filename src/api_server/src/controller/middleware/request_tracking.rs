@@ -0,0 +1,23 @@
+use actix_web::{
+    body::MessageBody,
+    dev::{ServiceRequest, ServiceResponse},
+    middleware::Next,
+    web,
+};
+
+use crate::shutdown::RequestTracker;
+
+// NOTE: シャットダウン時に処理中・中断されたリクエスト数を集計するために使用する
+pub async fn request_tracking(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+    let guard = req
+        .app_data::<web::Data<RequestTracker>>()
+        .map(|tracker| tracker.clone().into_inner().start());
+    let res = next.call(req).await;
+    if let Some(guard) = guard {
+        guard.complete();
+    }
+    res
+}
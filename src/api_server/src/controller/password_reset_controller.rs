@@ -0,0 +1,118 @@
+use actix_web::{
+    http::{header, StatusCode},
+    web, HttpResponse,
+};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+mod request;
+mod reset;
+
+use request::*;
+use reset::*;
+
+use crate::{
+    domain::PasswordResetPolicyError,
+    i18n::{Localize, Message},
+    repository::{database_error::DatabaseError, TransactionManager},
+    use_case::{
+        PasswordResetConfirmUsecase, PasswordResetRequestUsecase, PasswordResetUsecaseError,
+    },
+};
+
+pub fn config<TM, Usecase>(cfg: &mut web::ServiceConfig, usecase: Arc<Usecase>, tm: Arc<Mutex<TM>>)
+where
+    TM: TransactionManager + std::marker::Sync + std::marker::Send + 'static,
+    Usecase: PasswordResetRequestUsecase<TM>
+        + PasswordResetConfirmUsecase<TM>
+        + std::marker::Send
+        + std::marker::Sync
+        + 'static,
+{
+    let usecase_data = web::Data::from(usecase);
+    let tm_data = web::Data::from(tm);
+    cfg.app_data(tm_data)
+        .app_data(usecase_data)
+        .route(
+            "/password-resets",
+            web::post().to(handle_request_password_reset::<TM, Usecase>),
+        )
+        .route(
+            "/password-resets/{token}",
+            web::post().to(handle_reset_password::<TM, Usecase>),
+        );
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PasswordResetControllerError {
+    #[error(transparent)]
+    PasswordResetApplicationError(#[from] PasswordResetUsecaseError),
+    #[error("DatabaseConnectionError")]
+    DatabaseError(#[from] DatabaseError),
+}
+
+impl PasswordResetControllerError {
+    fn is_rejection(&self) -> bool {
+        matches!(
+            self,
+            Self::PasswordResetApplicationError(
+                PasswordResetUsecaseError::RateLimited(_)
+                    | PasswordResetUsecaseError::InvalidToken
+                    | PasswordResetUsecaseError::MailAddressError(_)
+                    | PasswordResetUsecaseError::PasswordError(_)
+            )
+        )
+    }
+
+    fn log(&self, action: &'static str) {
+        if self.is_rejection() {
+            tracing::info!(error = %self, action, "password reset request rejected");
+        } else {
+            tracing::error!(error = %self, action, "password reset request failed");
+        }
+    }
+}
+
+impl Localize for PasswordResetControllerError {
+    fn message(&self) -> Message {
+        match self {
+            Self::PasswordResetApplicationError(e) => e.message(),
+            Self::DatabaseError(_) => Message::new("internal-error"),
+        }
+    }
+}
+
+impl actix_web::ResponseError for PasswordResetControllerError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::PasswordResetApplicationError(PasswordResetUsecaseError::RateLimited(_)) => {
+                StatusCode::TOO_MANY_REQUESTS
+            }
+            Self::PasswordResetApplicationError(PasswordResetUsecaseError::InvalidToken) => {
+                StatusCode::BAD_REQUEST
+            }
+            Self::PasswordResetApplicationError(
+                PasswordResetUsecaseError::MailAddressError(_)
+                | PasswordResetUsecaseError::PasswordError(_),
+            ) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::PasswordResetApplicationError(_) | Self::DatabaseError(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        match self {
+            Self::PasswordResetApplicationError(PasswordResetUsecaseError::RateLimited(
+                PasswordResetPolicyError::TooManyRequests { retry_after },
+            )) => HttpResponse::build(self.status_code())
+                .insert_header((header::RETRY_AFTER, retry_after.num_seconds().to_string()))
+                .body(self.message().to_string()),
+            _ if self.is_rejection() => {
+                HttpResponse::build(self.status_code()).body(self.message().to_string())
+            }
+            // NOTE: 内部エラーの詳細はログにのみ出力する
+            _ => HttpResponse::InternalServerError().finish(),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+use crate::{
+    controller::peer_ip_address, metrics::metrics, repository::TransactionManager,
+    use_case::PasswordResetRequestUsecase,
+};
+
+use super::PasswordResetControllerError;
+
+pub async fn handle_request_password_reset<TM, Usecase>(
+    req: HttpRequest,
+    info: web::Json<PasswordResetRequestJdto>,
+    tx_manager: web::Data<Mutex<TM>>,
+    usecase: web::Data<Usecase>,
+) -> Result<HttpResponse, actix_web::Error>
+where
+    Usecase: PasswordResetRequestUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    request_password_reset_controller(
+        tx_manager.as_ref(),
+        usecase.as_ref(),
+        info.into_inner(),
+        peer_ip_address(&req),
+    )
+    .await
+    .inspect_err(|e| e.log("request"))?;
+    // NOTE: アドレスの存在有無に関わらず同じレスポンスを返す
+    Ok(HttpResponse::Accepted().finish())
+}
+
+async fn request_password_reset_controller<Usecase, TM>(
+    tx_manager: &Mutex<TM>,
+    usecase: &Usecase,
+    info: PasswordResetRequestJdto,
+    ip_address: String,
+) -> Result<(), PasswordResetControllerError>
+where
+    Usecase: PasswordResetRequestUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    let mut tx = TM::begin(tx_manager).await?;
+    let res = usecase.request_reset(&mut tx, info.email, ip_address).await;
+    metrics().observe_usecase("request_password_reset", &res);
+    TM::execute(tx, res).await
+}
+
+#[derive(Deserialize)]
+pub struct PasswordResetRequestJdto {
+    email: String,
+}
@@ -0,0 +1,58 @@
+use actix_web::web;
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+use crate::{
+    metrics::metrics, repository::TransactionManager, use_case::PasswordResetConfirmUsecase,
+};
+
+use super::PasswordResetControllerError;
+
+pub async fn handle_reset_password<TM, Usecase>(
+    path: web::Path<PasswordResetPathParams>,
+    info: web::Json<ResetPasswordRequestJdto>,
+    tx_manager: web::Data<Mutex<TM>>,
+    usecase: web::Data<Usecase>,
+) -> Result<web::Json<()>, actix_web::Error>
+where
+    Usecase: PasswordResetConfirmUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    Ok(reset_password_controller(
+        tx_manager.as_ref(),
+        usecase.as_ref(),
+        path.into_inner(),
+        info.into_inner(),
+    )
+    .await
+    .inspect_err(|e| e.log("reset"))
+    .map(web::Json)?)
+}
+
+async fn reset_password_controller<Usecase, TM>(
+    tx_manager: &Mutex<TM>,
+    usecase: &Usecase,
+    path: PasswordResetPathParams,
+    info: ResetPasswordRequestJdto,
+) -> Result<(), PasswordResetControllerError>
+where
+    Usecase: PasswordResetConfirmUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    let mut tx = TM::begin(tx_manager).await?;
+    let res = usecase
+        .reset_password(&mut tx, path.token, info.password)
+        .await;
+    metrics().observe_usecase("reset_password", &res);
+    TM::execute(tx, res).await
+}
+
+#[derive(Deserialize)]
+pub struct PasswordResetPathParams {
+    token: String,
+}
+
+#[derive(Deserialize)]
+pub struct ResetPasswordRequestJdto {
+    password: String,
+}
@@ -0,0 +1,219 @@
+use actix_web::{
+    http::{header, StatusCode},
+    web, HttpResponse,
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+mod login;
+mod refresh;
+mod revoke;
+mod two_factor;
+
+use login::*;
+use refresh::*;
+use revoke::*;
+use two_factor::*;
+
+use crate::{
+    i18n::{Localize, Message},
+    repository::{database_error::DatabaseError, TransactionManager},
+    use_case::{
+        IssuedSessionDto, LoginChallengeDto, LoginOutcome, LoginRejection, SessionCreateUsecase,
+        SessionRefreshUsecase, SessionRevokeUsecase, SessionTwoFactorUsecase, SessionUsecaseError,
+    },
+};
+
+pub fn config<TM, Usecase>(cfg: &mut web::ServiceConfig, usecase: Arc<Usecase>, tm: Arc<Mutex<TM>>)
+where
+    TM: TransactionManager + std::marker::Sync + std::marker::Send + 'static,
+    Usecase: SessionCreateUsecase<TM>
+        + SessionRefreshUsecase<TM>
+        + SessionRevokeUsecase<TM>
+        + SessionTwoFactorUsecase<TM>
+        + std::marker::Send
+        + std::marker::Sync
+        + 'static,
+{
+    let usecase_data = web::Data::from(usecase);
+    let tm_data = web::Data::from(tm);
+    cfg.app_data(tm_data)
+        .app_data(usecase_data)
+        .route("/sessions", web::post().to(handle_login::<TM, Usecase>))
+        .route(
+            "/sessions/two-factor",
+            web::post().to(handle_verify_two_factor::<TM, Usecase>),
+        )
+        .route(
+            "/sessions/refresh",
+            web::post().to(handle_refresh::<TM, Usecase>),
+        )
+        .route(
+            "/sessions/revoke",
+            web::post().to(handle_revoke::<TM, Usecase>),
+        );
+}
+
+#[derive(Serialize, Debug)]
+pub struct TokenResponseJdto {
+    access_token: String,
+    token_type: &'static str,
+    access_token_expires_at: DateTime<Utc>,
+    refresh_token: String,
+    refresh_token_expires_at: DateTime<Utc>,
+}
+
+impl From<IssuedSessionDto> for TokenResponseJdto {
+    fn from(value: IssuedSessionDto) -> Self {
+        Self {
+            access_token: value.access_token,
+            token_type: "Bearer",
+            access_token_expires_at: value.access_token_expires_at,
+            refresh_token: value.refresh_token,
+            refresh_token_expires_at: value.refresh_token_expires_at,
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct TwoFactorChallengeJdto {
+    two_factor_required: bool,
+    challenge_token: String,
+    expires_at: DateTime<Utc>,
+}
+
+impl From<LoginChallengeDto> for TwoFactorChallengeJdto {
+    fn from(value: LoginChallengeDto) -> Self {
+        Self {
+            two_factor_required: true,
+            challenge_token: value.challenge_token,
+            expires_at: value.expires_at,
+        }
+    }
+}
+
+// NOTE: 二要素認証が有効な場合はトークンの代わりに確認用のトークンを返す
+#[derive(Serialize, Debug)]
+#[serde(untagged)]
+pub enum LoginResponseJdto {
+    Issued(TokenResponseJdto),
+    TwoFactorRequired(TwoFactorChallengeJdto),
+}
+
+impl From<LoginOutcome> for LoginResponseJdto {
+    fn from(value: LoginOutcome) -> Self {
+        match value {
+            LoginOutcome::Issued(issued) => Self::Issued(issued.into()),
+            LoginOutcome::TwoFactorRequired(challenge) => Self::TwoFactorRequired(challenge.into()),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SessionControllerError {
+    #[error(transparent)]
+    SessionApplicationError(#[from] SessionUsecaseError),
+    #[error(transparent)]
+    LoginRejected(#[from] LoginRejection),
+    #[error("リフレッシュトークンが無効です。")]
+    InvalidRefreshToken,
+    #[error("codeまたはrecovery_codeのいずれか一方を指定してください。")]
+    InvalidTwoFactorRequest,
+    #[error("DatabaseConnectionError")]
+    DatabaseError(#[from] DatabaseError),
+}
+
+impl SessionControllerError {
+    fn is_rejection(&self) -> bool {
+        matches!(
+            self,
+            Self::LoginRejected(_) | Self::InvalidRefreshToken | Self::InvalidTwoFactorRequest
+        )
+    }
+}
+
+impl Localize for SessionControllerError {
+    fn message(&self) -> Message {
+        match self {
+            Self::LoginRejected(e) => e.message(),
+            Self::InvalidRefreshToken => Message::new("refresh-token-invalid"),
+            Self::InvalidTwoFactorRequest => Message::new("two-factor-request-invalid"),
+            Self::SessionApplicationError(_) | Self::DatabaseError(_) => {
+                Message::new("internal-error")
+            }
+        }
+    }
+}
+
+impl actix_web::ResponseError for SessionControllerError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::LoginRejected(LoginRejection::TooManyAttempts { .. }) => {
+                StatusCode::TOO_MANY_REQUESTS
+            }
+            Self::LoginRejected(
+                LoginRejection::InvalidCredentials
+                | LoginRejection::InvalidTwoFactorCode
+                | LoginRejection::InvalidLoginChallenge,
+            )
+            | Self::InvalidRefreshToken => StatusCode::UNAUTHORIZED,
+            Self::InvalidTwoFactorRequest => StatusCode::BAD_REQUEST,
+            Self::LoginRejected(LoginRejection::AccountSuspended) => StatusCode::FORBIDDEN,
+            Self::SessionApplicationError(_) | Self::DatabaseError(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        match self {
+            Self::LoginRejected(
+                LoginRejection::InvalidCredentials
+                | LoginRejection::InvalidTwoFactorCode
+                | LoginRejection::InvalidLoginChallenge,
+            ) => HttpResponse::Unauthorized().body(self.message().to_string()),
+            Self::InvalidTwoFactorRequest => {
+                HttpResponse::BadRequest().body(self.message().to_string())
+            }
+            Self::LoginRejected(LoginRejection::AccountSuspended) => {
+                HttpResponse::Forbidden().body(self.message().to_string())
+            }
+            Self::LoginRejected(LoginRejection::TooManyAttempts { retry_after }) => {
+                HttpResponse::TooManyRequests()
+                    .insert_header((header::RETRY_AFTER, retry_after_secs(retry_after)))
+                    .body(self.message().to_string())
+            }
+            Self::InvalidRefreshToken => HttpResponse::Unauthorized()
+                .insert_header((
+                    header::WWW_AUTHENTICATE,
+                    r#"Bearer realm="api_server", error="invalid_token""#,
+                ))
+                .body(self.message().to_string()),
+            // NOTE: 内部エラーの詳細はログにのみ出力する
+            Self::SessionApplicationError(_) | Self::DatabaseError(_) => {
+                HttpResponse::InternalServerError().finish()
+            }
+        }
+    }
+}
+
+// NOTE: Retry-Afterは秒単位のため切り上げる
+pub(crate) fn retry_after_secs(retry_after: &chrono::Duration) -> String {
+    let secs = retry_after.num_seconds();
+    let secs = if *retry_after > chrono::Duration::seconds(secs) {
+        secs + 1
+    } else {
+        secs
+    };
+    secs.max(1).to_string()
+}
+
+fn log_error(e: &SessionControllerError, action: &'static str) {
+    if e.is_rejection() {
+        tracing::info!(error = %e, action, "session request rejected");
+    } else {
+        tracing::error!(error = %e, action, "session request failed");
+    }
+}
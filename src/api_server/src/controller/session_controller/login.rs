@@ -0,0 +1,57 @@
+use actix_web::{web, HttpRequest};
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+use crate::{
+    controller::peer_ip_address, metrics::metrics, repository::TransactionManager,
+    use_case::SessionCreateUsecase,
+};
+
+use super::{log_error, LoginResponseJdto, SessionControllerError};
+
+pub async fn handle_login<TM, Usecase>(
+    req: HttpRequest,
+    info: web::Json<LoginRequestJdto>,
+    tx_manager: web::Data<Mutex<TM>>,
+    usecase: web::Data<Usecase>,
+) -> Result<web::Json<LoginResponseJdto>, actix_web::Error>
+where
+    Usecase: SessionCreateUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    Ok(login_controller(
+        tx_manager.as_ref(),
+        usecase.as_ref(),
+        info.into_inner(),
+        peer_ip_address(&req),
+    )
+    .await
+    .inspect_err(|e| log_error(e, "login"))
+    .map(web::Json)?)
+}
+
+async fn login_controller<Usecase, TM>(
+    tx_manager: &Mutex<TM>,
+    usecase: &Usecase,
+    info: LoginRequestJdto,
+    ip_address: String,
+) -> Result<LoginResponseJdto, SessionControllerError>
+where
+    Usecase: SessionCreateUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    let mut tx = TM::begin(tx_manager).await?;
+    let res = usecase
+        .login(&mut tx, info.email, info.password, ip_address)
+        .await;
+    metrics().observe_usecase("login", &res);
+    Ok(TM::execute::<_, _, SessionControllerError>(tx, res)
+        .await?
+        .map(LoginResponseJdto::from)?)
+}
+
+#[derive(Deserialize)]
+pub struct LoginRequestJdto {
+    email: String,
+    password: String,
+}
@@ -0,0 +1,47 @@
+use actix_web::web;
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+use crate::{metrics::metrics, repository::TransactionManager, use_case::SessionRefreshUsecase};
+
+use super::{log_error, SessionControllerError, TokenResponseJdto};
+
+pub async fn handle_refresh<TM, Usecase>(
+    info: web::Json<RefreshRequestJdto>,
+    tx_manager: web::Data<Mutex<TM>>,
+    usecase: web::Data<Usecase>,
+) -> Result<web::Json<TokenResponseJdto>, actix_web::Error>
+where
+    Usecase: SessionRefreshUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    Ok(
+        refresh_controller(tx_manager.as_ref(), usecase.as_ref(), info.into_inner())
+            .await
+            .inspect_err(|e| log_error(e, "refresh"))
+            .map(web::Json)?,
+    )
+}
+
+async fn refresh_controller<Usecase, TM>(
+    tx_manager: &Mutex<TM>,
+    usecase: &Usecase,
+    info: RefreshRequestJdto,
+) -> Result<TokenResponseJdto, SessionControllerError>
+where
+    Usecase: SessionRefreshUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    let mut tx = TM::begin(tx_manager).await?;
+    let res = usecase.refresh(&mut tx, info.refresh_token).await;
+    metrics().observe_usecase("refresh", &res);
+    TM::execute::<_, _, SessionControllerError>(tx, res)
+        .await?
+        .map(TokenResponseJdto::from)
+        .ok_or(SessionControllerError::InvalidRefreshToken)
+}
+
+#[derive(Deserialize)]
+pub struct RefreshRequestJdto {
+    refresh_token: String,
+}
@@ -0,0 +1,44 @@
+use actix_web::web;
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+use crate::{metrics::metrics, repository::TransactionManager, use_case::SessionRevokeUsecase};
+
+use super::{log_error, SessionControllerError};
+
+pub async fn handle_revoke<TM, Usecase>(
+    info: web::Json<RevokeRequestJdto>,
+    tx_manager: web::Data<Mutex<TM>>,
+    usecase: web::Data<Usecase>,
+) -> Result<web::Json<()>, actix_web::Error>
+where
+    Usecase: SessionRevokeUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    Ok(
+        revoke_controller(tx_manager.as_ref(), usecase.as_ref(), info.into_inner())
+            .await
+            .inspect_err(|e| log_error(e, "revoke"))
+            .map(web::Json)?,
+    )
+}
+
+async fn revoke_controller<Usecase, TM>(
+    tx_manager: &Mutex<TM>,
+    usecase: &Usecase,
+    info: RevokeRequestJdto,
+) -> Result<(), SessionControllerError>
+where
+    Usecase: SessionRevokeUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    let mut tx = TM::begin(tx_manager).await?;
+    let res = usecase.revoke(&mut tx, info.refresh_token).await;
+    metrics().observe_usecase("revoke", &res);
+    TM::execute(tx, res).await
+}
+
+#[derive(Deserialize)]
+pub struct RevokeRequestJdto {
+    refresh_token: String,
+}
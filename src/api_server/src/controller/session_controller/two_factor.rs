@@ -0,0 +1,57 @@
+use actix_web::web;
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+use crate::{
+    controller::two_factor_controller::TwoFactorCodeJdto, metrics::metrics,
+    repository::TransactionManager, use_case::SessionTwoFactorUsecase,
+};
+
+use super::{log_error, SessionControllerError, TokenResponseJdto};
+
+pub async fn handle_verify_two_factor<TM, Usecase>(
+    info: web::Json<TwoFactorRequestJdto>,
+    tx_manager: web::Data<Mutex<TM>>,
+    usecase: web::Data<Usecase>,
+) -> Result<web::Json<TokenResponseJdto>, actix_web::Error>
+where
+    Usecase: SessionTwoFactorUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    Ok(
+        verify_two_factor_controller(tx_manager.as_ref(), usecase.as_ref(), info.into_inner())
+            .await
+            .inspect_err(|e| log_error(e, "verify_two_factor"))
+            .map(web::Json)?,
+    )
+}
+
+async fn verify_two_factor_controller<Usecase, TM>(
+    tx_manager: &Mutex<TM>,
+    usecase: &Usecase,
+    info: TwoFactorRequestJdto,
+) -> Result<TokenResponseJdto, SessionControllerError>
+where
+    Usecase: SessionTwoFactorUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    let code = info
+        .code
+        .into_code()
+        .ok_or(SessionControllerError::InvalidTwoFactorRequest)?;
+    let mut tx = TM::begin(tx_manager).await?;
+    let res = usecase
+        .verify_two_factor(&mut tx, info.challenge_token, code)
+        .await;
+    metrics().observe_usecase("verify_two_factor", &res);
+    Ok(TM::execute::<_, _, SessionControllerError>(tx, res)
+        .await?
+        .map(TokenResponseJdto::from)?)
+}
+
+#[derive(Deserialize)]
+pub struct TwoFactorRequestJdto {
+    challenge_token: String,
+    #[serde(flatten)]
+    code: TwoFactorCodeJdto,
+}
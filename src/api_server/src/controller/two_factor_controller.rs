@@ -0,0 +1,162 @@
+use actix_web::{
+    http::{header, StatusCode},
+    web, HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+mod disable;
+mod enroll;
+
+use disable::*;
+use enroll::*;
+
+use crate::{
+    controller::session_controller::retry_after_secs,
+    domain::TotpCredentialError,
+    i18n::{Localize, Message},
+    repository::{database_error::DatabaseError, TransactionManager},
+    use_case::{
+        TotpEnrollmentDto, TwoFactorCode, TwoFactorDisableUsecase, TwoFactorEnrollUsecase,
+        TwoFactorRejection, TwoFactorUsecaseError,
+    },
+};
+
+pub fn config<TM, Usecase>(cfg: &mut web::ServiceConfig, usecase: Arc<Usecase>, tm: Arc<Mutex<TM>>)
+where
+    TM: TransactionManager + std::marker::Sync + std::marker::Send + 'static,
+    Usecase: TwoFactorEnrollUsecase<TM>
+        + TwoFactorDisableUsecase<TM>
+        + std::marker::Send
+        + std::marker::Sync
+        + 'static,
+{
+    let usecase_data = web::Data::from(usecase);
+    let tm_data = web::Data::from(tm);
+    cfg.app_data(tm_data)
+        .app_data(usecase_data)
+        .route(
+            "/two-factor/totp",
+            web::post().to(handle_begin_enrollment::<TM, Usecase>),
+        )
+        .route(
+            "/two-factor/totp/confirm",
+            web::post().to(handle_confirm_enrollment::<TM, Usecase>),
+        )
+        .route(
+            "/two-factor/disable",
+            web::post().to(handle_disable_two_factor::<TM, Usecase>),
+        );
+}
+
+// NOTE: TOTPのコードとリカバリーコードのいずれか一方を受け付ける
+#[derive(Deserialize, Debug)]
+pub struct TwoFactorCodeJdto {
+    code: Option<String>,
+    recovery_code: Option<String>,
+}
+
+impl TwoFactorCodeJdto {
+    pub fn into_code(self) -> Option<TwoFactorCode> {
+        match (self.code, self.recovery_code) {
+            (Some(code), None) => Some(TwoFactorCode::Totp(code)),
+            (None, Some(recovery_code)) => Some(TwoFactorCode::RecoveryCode(recovery_code)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct TotpEnrollmentJdto {
+    secret: String,
+    otpauth_uri: String,
+}
+
+impl From<TotpEnrollmentDto> for TotpEnrollmentJdto {
+    fn from(value: TotpEnrollmentDto) -> Self {
+        Self {
+            secret: value.secret,
+            otpauth_uri: value.otpauth_uri,
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct RecoveryCodesJdto {
+    recovery_codes: Vec<String>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TwoFactorControllerError {
+    #[error(transparent)]
+    TwoFactorApplicationError(#[from] TwoFactorUsecaseError),
+    #[error(transparent)]
+    Rejected(#[from] TwoFactorRejection),
+    #[error("codeまたはrecovery_codeのいずれか一方を指定してください。")]
+    InvalidRequest,
+    #[error("DatabaseConnectionError")]
+    DatabaseError(#[from] DatabaseError),
+}
+
+impl TwoFactorControllerError {
+    fn log(&self, message: &'static str) {
+        match actix_web::ResponseError::status_code(self) {
+            StatusCode::INTERNAL_SERVER_ERROR => tracing::error!(error = %self, message),
+            _ => tracing::info!(error = %self, message),
+        }
+    }
+}
+
+impl Localize for TwoFactorControllerError {
+    fn message(&self) -> Message {
+        match self {
+            Self::TwoFactorApplicationError(e) => e.message(),
+            Self::Rejected(e) => e.message(),
+            Self::InvalidRequest => Message::new("two-factor-request-invalid"),
+            Self::DatabaseError(_) => Message::new("internal-error"),
+        }
+    }
+}
+
+impl actix_web::ResponseError for TwoFactorControllerError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::TwoFactorApplicationError(TwoFactorUsecaseError::UserNotFound) => {
+                StatusCode::UNAUTHORIZED
+            }
+            Self::TwoFactorApplicationError(
+                TwoFactorUsecaseError::AlreadyEnabled
+                | TwoFactorUsecaseError::NotEnabled
+                | TwoFactorUsecaseError::EnrollmentNotStarted
+                | TwoFactorUsecaseError::TotpCredentialError(TotpCredentialError::AlreadyConfirmed),
+            ) => StatusCode::CONFLICT,
+            Self::TwoFactorApplicationError(TwoFactorUsecaseError::TotpCredentialError(
+                TotpCredentialError::InvalidCode,
+            ))
+            | Self::Rejected(TwoFactorRejection::InvalidCode)
+            | Self::InvalidRequest => StatusCode::BAD_REQUEST,
+            Self::Rejected(TwoFactorRejection::TooManyAttempts { .. }) => {
+                StatusCode::TOO_MANY_REQUESTS
+            }
+            Self::TwoFactorApplicationError(_) | Self::DatabaseError(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        match self {
+            Self::Rejected(TwoFactorRejection::TooManyAttempts { retry_after }) => {
+                HttpResponse::TooManyRequests()
+                    .insert_header((header::RETRY_AFTER, retry_after_secs(retry_after)))
+                    .body(self.message().to_string())
+            }
+            _ => match self.status_code() {
+                // NOTE: 内部エラーの詳細はログにのみ出力する
+                StatusCode::INTERNAL_SERVER_ERROR => HttpResponse::InternalServerError().finish(),
+                status => HttpResponse::build(status).body(self.message().to_string()),
+            },
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use actix_web::web;
+use tokio::sync::Mutex;
+
+use crate::{
+    controller::authentication::AuthenticatedUser, metrics::metrics,
+    repository::TransactionManager, use_case::TwoFactorDisableUsecase,
+};
+
+use super::{TwoFactorCodeJdto, TwoFactorControllerError};
+
+pub async fn handle_disable_two_factor<TM, Usecase>(
+    user: AuthenticatedUser,
+    info: web::Json<TwoFactorCodeJdto>,
+    tx_manager: web::Data<Mutex<TM>>,
+    usecase: web::Data<Usecase>,
+) -> Result<web::Json<()>, actix_web::Error>
+where
+    Usecase: TwoFactorDisableUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    Ok(disable_two_factor_controller(
+        tx_manager.as_ref(),
+        usecase.as_ref(),
+        user,
+        info.into_inner(),
+    )
+    .await
+    .inspect_err(|e| e.log("failed to disable two-factor authentication"))
+    .map(web::Json)?)
+}
+
+async fn disable_two_factor_controller<Usecase, TM>(
+    tx_manager: &Mutex<TM>,
+    usecase: &Usecase,
+    user: AuthenticatedUser,
+    info: TwoFactorCodeJdto,
+) -> Result<(), TwoFactorControllerError>
+where
+    Usecase: TwoFactorDisableUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    let code = info
+        .into_code()
+        .ok_or(TwoFactorControllerError::InvalidRequest)?;
+    let mut tx = TM::begin(tx_manager).await?;
+    let res = usecase.disable(&mut tx, user.user_id, code).await;
+    metrics().observe_usecase("disable_two_factor", &res);
+    Ok(TM::execute::<_, _, TwoFactorControllerError>(tx, res).await??)
+}
@@ -0,0 +1,89 @@
+use actix_web::web;
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+use crate::{
+    controller::authentication::AuthenticatedUser, metrics::metrics,
+    repository::TransactionManager, use_case::TwoFactorEnrollUsecase,
+};
+
+use super::{RecoveryCodesJdto, TotpEnrollmentJdto, TwoFactorControllerError};
+
+pub async fn handle_begin_enrollment<TM, Usecase>(
+    user: AuthenticatedUser,
+    tx_manager: web::Data<Mutex<TM>>,
+    usecase: web::Data<Usecase>,
+) -> Result<web::Json<TotpEnrollmentJdto>, actix_web::Error>
+where
+    Usecase: TwoFactorEnrollUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    Ok(
+        begin_enrollment_controller(tx_manager.as_ref(), usecase.as_ref(), user)
+            .await
+            .inspect_err(|e| e.log("failed to begin two-factor enrollment"))
+            .map(web::Json)?,
+    )
+}
+
+async fn begin_enrollment_controller<Usecase, TM>(
+    tx_manager: &Mutex<TM>,
+    usecase: &Usecase,
+    user: AuthenticatedUser,
+) -> Result<TotpEnrollmentJdto, TwoFactorControllerError>
+where
+    Usecase: TwoFactorEnrollUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    let mut tx = TM::begin(tx_manager).await?;
+    let res = usecase.begin_enrollment(&mut tx, user.user_id).await;
+    metrics().observe_usecase("begin_two_factor_enrollment", &res);
+    Ok(TM::execute::<_, _, TwoFactorControllerError>(tx, res)
+        .await?
+        .into())
+}
+
+pub async fn handle_confirm_enrollment<TM, Usecase>(
+    user: AuthenticatedUser,
+    info: web::Json<ConfirmEnrollmentRequestJdto>,
+    tx_manager: web::Data<Mutex<TM>>,
+    usecase: web::Data<Usecase>,
+) -> Result<web::Json<RecoveryCodesJdto>, actix_web::Error>
+where
+    Usecase: TwoFactorEnrollUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    Ok(confirm_enrollment_controller(
+        tx_manager.as_ref(),
+        usecase.as_ref(),
+        user,
+        info.into_inner(),
+    )
+    .await
+    .inspect_err(|e| e.log("failed to confirm two-factor enrollment"))
+    .map(web::Json)?)
+}
+
+async fn confirm_enrollment_controller<Usecase, TM>(
+    tx_manager: &Mutex<TM>,
+    usecase: &Usecase,
+    user: AuthenticatedUser,
+    info: ConfirmEnrollmentRequestJdto,
+) -> Result<RecoveryCodesJdto, TwoFactorControllerError>
+where
+    Usecase: TwoFactorEnrollUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    let mut tx = TM::begin(tx_manager).await?;
+    let res = usecase
+        .confirm_enrollment(&mut tx, user.user_id, info.code)
+        .await;
+    metrics().observe_usecase("confirm_two_factor_enrollment", &res);
+    let recovery_codes = TM::execute::<_, _, TwoFactorControllerError>(tx, res).await?;
+    Ok(RecoveryCodesJdto { recovery_codes })
+}
+
+#[derive(Deserialize)]
+pub struct ConfirmEnrollmentRequestJdto {
+    code: String,
+}
@@ -1,62 +1,169 @@
-use actix_web::{web, HttpResponse};
+use actix_web::{http::StatusCode, web, HttpResponse};
+use serde::Deserialize;
 use std::sync::Arc;
 use tokio::sync::Mutex;
+use uuid::Uuid;
 
+mod delete;
+mod get;
 mod register;
+mod revert_mail_address;
+mod suspend;
 mod update;
+mod verify;
 
+use delete::*;
+use get::*;
 use register::*;
+use revert_mail_address::*;
+use suspend::*;
 use update::*;
+use verify::*;
 
 use crate::{
+    config::FeatureConfig,
+    controller::validated_json::validation_error_response,
+    domain::{UserError, UserStatusError},
+    i18n::{Localize, Message},
     repository::{database_error::DatabaseError, TransactionManager},
-    use_case::{UserRegisterUsecase, UserUpdateUsecase, UserUsecaseError},
+    use_case::{
+        UserDeleteUsecase, UserGetUsecase, UserRegisterUsecase, UserRevertMailAddressUsecase,
+        UserSuspendUsecase, UserUpdateUsecase, UserUsecaseError, UserVerifyUsecase,
+    },
 };
 
-pub fn config<TM, Usecase>(cfg: &mut web::ServiceConfig, usecase: Arc<Usecase>, tm: Arc<Mutex<TM>>)
-where
+pub fn config<TM, Usecase>(
+    cfg: &mut web::ServiceConfig,
+    usecase: Arc<Usecase>,
+    tm: Arc<Mutex<TM>>,
+    features: &FeatureConfig,
+) where
     TM: TransactionManager + std::marker::Sync + std::marker::Send + 'static,
     Usecase: UserRegisterUsecase<TM>
+        + UserGetUsecase<TM>
         + UserUpdateUsecase<TM>
+        + UserDeleteUsecase<TM>
+        + UserVerifyUsecase<TM>
+        + UserRevertMailAddressUsecase<TM>
+        + UserSuspendUsecase<TM>
         + std::marker::Send
         + std::marker::Sync
         + 'static,
 {
     let usecase_data = web::Data::from(usecase);
     let tm_data = web::Data::from(tm);
+    // NOTE: /users/{id}より先に登録しないとidとしてマッチしてしまう
     cfg.app_data(tm_data)
         .app_data(usecase_data)
         .route(
+            "/users/verify",
+            web::post().to(handle_verify_user::<TM, Usecase>),
+        )
+        .route(
+            "/users/mail-address/revert",
+            web::post().to(handle_revert_mail_address::<TM, Usecase>),
+        )
+        .route(
+            "/users/{id}/suspend",
+            web::post().to(handle_suspend_user::<TM, Usecase>),
+        )
+        .route(
+            "/users/{id}/reinstate",
+            web::post().to(handle_reinstate_user::<TM, Usecase>),
+        )
+        .service(
+            web::resource("/users/{id}")
+                .route(web::get().to(get_user::<TM, Usecase>))
+                .route(web::put().to(update_user::<TM, Usecase>))
+                .route(web::delete().to(delete_user::<TM, Usecase>)),
+        );
+    if features.user_registration {
+        cfg.route(
             "/users",
             web::post().to(handle_register_user::<TM, Usecase>),
-        )
-        .route("/users/{id}", web::put().to(update_user::<TM, Usecase>));
+        );
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct UserPathParams {
+    pub id: Uuid,
 }
 
 #[derive(Debug, thiserror::Error)]
 pub enum UserControllerError {
     #[error(transparent)]
     UserApplicationError(#[from] UserUsecaseError),
+    // NOTE: 存在しない場合と退会・利用停止中の場合を区別しない
+    #[error("ユーザーが見つかりません。")]
+    UserNotFound,
     #[error("DatabaseConnectionError")]
     DatabaseError(#[from] DatabaseError),
 }
 
+impl UserControllerError {
+    fn log(&self, message: &'static str) {
+        match self {
+            Self::UserNotFound
+            | Self::UserApplicationError(
+                UserUsecaseError::Forbidden(_)
+                | UserUsecaseError::InvalidVerificationToken
+                | UserUsecaseError::ValidationErrors(_)
+                | UserUsecaseError::UserError(UserError::UserStatusError(_)),
+            ) => tracing::info!(error = %self, message),
+            _ => tracing::error!(error = %self, message),
+        }
+    }
+}
+
+impl Localize for UserControllerError {
+    fn message(&self) -> Message {
+        match self {
+            Self::UserApplicationError(e) => e.message(),
+            Self::UserNotFound => Message::new("user-not-found"),
+            Self::DatabaseError(_) => Message::new("internal-error"),
+        }
+    }
+}
+
 impl actix_web::ResponseError for UserControllerError {
-    fn status_code(&self) -> actix_web::http::StatusCode {
+    fn status_code(&self) -> StatusCode {
         match self {
+            Self::UserApplicationError(UserUsecaseError::Forbidden(_)) => StatusCode::FORBIDDEN,
+            Self::UserApplicationError(
+                UserUsecaseError::InvalidVerificationToken | UserUsecaseError::ValidationErrors(_),
+            ) => StatusCode::BAD_REQUEST,
+            Self::UserApplicationError(UserUsecaseError::UserError(
+                UserError::UserStatusError(UserStatusError::InvalidTransition { .. }),
+            )) => StatusCode::CONFLICT,
+            Self::UserApplicationError(UserUsecaseError::UserError(
+                UserError::UserStatusError(_),
+            )) => StatusCode::BAD_REQUEST,
             // TODO: 適切にハンドリング
-            Self::UserApplicationError(_) => actix_web::http::StatusCode::UNPROCESSABLE_ENTITY,
-            Self::DatabaseError(_) => actix_web::http::StatusCode::INTERNAL_SERVER_ERROR,
+            Self::UserApplicationError(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::UserNotFound => StatusCode::NOT_FOUND,
+            Self::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
 
     fn error_response(&self) -> HttpResponse {
         match self {
+            Self::UserApplicationError(UserUsecaseError::ValidationErrors(errors)) => {
+                validation_error_response(errors)
+            }
+            Self::UserNotFound
+            | Self::UserApplicationError(
+                UserUsecaseError::Forbidden(_)
+                | UserUsecaseError::InvalidVerificationToken
+                | UserUsecaseError::UserError(UserError::UserStatusError(_)),
+            ) => HttpResponse::build(self.status_code()).body(self.message().to_string()),
             // TODO: 適切にハンドリング
             Self::UserApplicationError(_) => {
-                HttpResponse::InternalServerError().body(self.to_string())
+                HttpResponse::InternalServerError().body(self.message().to_string())
+            }
+            Self::DatabaseError(_) => {
+                HttpResponse::InternalServerError().body(self.message().to_string())
             }
-            Self::DatabaseError(_) => HttpResponse::InternalServerError().body(self.to_string()),
         }
     }
 }
@@ -0,0 +1,48 @@
+use actix_web::web;
+use tokio::sync::Mutex;
+
+use crate::{
+    controller::authentication::{Authorized, UsersWrite},
+    metrics::metrics,
+    repository::TransactionManager,
+    use_case::UserDeleteUsecase,
+};
+
+use super::{UserControllerError, UserPathParams};
+
+pub async fn delete_user<TM, Usecase>(
+    principal: Authorized<UsersWrite>,
+    params: web::Path<UserPathParams>,
+    tx_manager: web::Data<Mutex<TM>>,
+    usecase: web::Data<Usecase>,
+) -> Result<web::Json<()>, actix_web::Error>
+where
+    Usecase: UserDeleteUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    Ok(delete_user_controller(
+        tx_manager.as_ref(),
+        usecase.as_ref(),
+        principal,
+        params.into_inner(),
+    )
+    .await
+    .inspect_err(|e| e.log("failed to delete user"))
+    .map(web::Json)?)
+}
+
+async fn delete_user_controller<Usecase, TM>(
+    tx_manager: &Mutex<TM>,
+    usecase: &Usecase,
+    principal: Authorized<UsersWrite>,
+    params: UserPathParams,
+) -> Result<(), UserControllerError>
+where
+    Usecase: UserDeleteUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    let mut tx = TM::begin(tx_manager).await?;
+    let res = usecase.delete(&mut tx, principal.actor_id, params.id).await;
+    metrics().observe_usecase("delete", &res);
+    TM::execute(tx, res).await
+}
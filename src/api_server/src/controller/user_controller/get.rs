@@ -0,0 +1,70 @@
+use actix_web::web;
+use serde::Serialize;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+use crate::{
+    controller::authentication::{Authorized, UsersRead},
+    metrics::metrics,
+    repository::TransactionManager,
+    use_case::{UserDto, UserGetUsecase},
+};
+
+use super::{UserControllerError, UserPathParams};
+
+pub async fn get_user<TM, Usecase>(
+    principal: Authorized<UsersRead>,
+    params: web::Path<UserPathParams>,
+    tx_manager: web::Data<Mutex<TM>>,
+    usecase: web::Data<Usecase>,
+) -> Result<web::Json<UserResponseJdto>, actix_web::Error>
+where
+    Usecase: UserGetUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    Ok(get_user_controller(
+        tx_manager.as_ref(),
+        usecase.as_ref(),
+        principal,
+        params.into_inner(),
+    )
+    .await
+    .inspect_err(|e| e.log("failed to get user"))
+    .map(web::Json)?)
+}
+
+async fn get_user_controller<Usecase, TM>(
+    tx_manager: &Mutex<TM>,
+    usecase: &Usecase,
+    principal: Authorized<UsersRead>,
+    params: UserPathParams,
+) -> Result<UserResponseJdto, UserControllerError>
+where
+    Usecase: UserGetUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    let mut tx = TM::begin(tx_manager).await?;
+    let res = usecase.get(&mut tx, principal.actor_id, &params.id).await;
+    metrics().observe_usecase("get", &res);
+    TM::execute::<_, _, UserControllerError>(tx, res)
+        .await?
+        .map(UserResponseJdto::from)
+        .ok_or(UserControllerError::UserNotFound)
+}
+
+#[derive(Serialize, Debug)]
+pub struct UserResponseJdto {
+    id: Uuid,
+    name: String,
+    email: String,
+}
+
+impl From<UserDto> for UserResponseJdto {
+    fn from(value: UserDto) -> Self {
+        Self {
+            id: value.user_id,
+            name: value.user_name,
+            email: value.mail_address,
+        }
+    }
+}
@@ -1,13 +1,19 @@
 use actix_web::web;
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use tokio::sync::Mutex;
 
-use crate::{repository::TransactionManager, use_case::UserRegisterUsecase};
+use crate::{
+    controller::validated_json::{ValidatedBody, ValidatedJson},
+    domain::{MailAddress, Password, UserName, ValidationErrors, Validator},
+    metrics::metrics,
+    repository::TransactionManager,
+    use_case::UserRegisterUsecase,
+};
 
 use super::UserControllerError;
 
 pub async fn handle_register_user<TM, Usecase>(
-    info: web::Json<RegisterUserRequestJdto>,
+    info: ValidatedJson<RegisterUserRequestJdto>,
     tx_manager: web::Data<Mutex<TM>>,
     usecase: web::Data<Usecase>,
 ) -> Result<web::Json<()>, actix_web::Error>
@@ -18,10 +24,7 @@ where
     Ok(
         register_user_controller(tx_manager.as_ref(), usecase.as_ref(), info.into_inner())
             .await
-            .map_err(|e| {
-                println!("{e}");
-                e
-            })
+            .inspect_err(|e| e.log("failed to register user"))
             .map(web::Json)?,
     )
 }
@@ -36,12 +39,39 @@ where
     TM: TransactionManager + Send,
 {
     let mut tx = TM::begin(tx_manager).await?;
-    let res = usecase.register(&mut tx, info.name, info.email).await;
+    let res = usecase
+        .register(&mut tx, info.name, info.email, info.password)
+        .await;
+    metrics().observe_usecase("register", &res);
     TM::execute(tx, res).await
 }
 
-#[derive(Deserialize, Serialize, Debug)]
 pub struct RegisterUserRequestJdto {
+    name: UserName,
+    email: MailAddress,
+    password: Password,
+}
+
+#[derive(Deserialize)]
+pub struct RawRegisterUserRequestJdto {
     name: String,
     email: String,
+    password: String,
+}
+
+impl ValidatedBody for RegisterUserRequestJdto {
+    type Raw = RawRegisterUserRequestJdto;
+
+    fn validate(raw: Self::Raw) -> Result<Self, ValidationErrors> {
+        let mut validator = Validator::new();
+        let name = validator.check("name", UserName::new(raw.name));
+        let email = validator.check("email", MailAddress::new(raw.email));
+        let password = validator.check("password", Password::new(raw.password));
+        let (name, email, password) = validator.finish((name, email, password))?;
+        Ok(Self {
+            name,
+            email,
+            password,
+        })
+    }
 }
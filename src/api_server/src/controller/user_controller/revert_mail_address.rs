@@ -0,0 +1,46 @@
+use actix_web::web;
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+use crate::{
+    metrics::metrics, repository::TransactionManager, use_case::UserRevertMailAddressUsecase,
+};
+
+use super::UserControllerError;
+
+pub async fn handle_revert_mail_address<TM, Usecase>(
+    info: web::Json<RevertMailAddressRequestJdto>,
+    tx_manager: web::Data<Mutex<TM>>,
+    usecase: web::Data<Usecase>,
+) -> Result<web::Json<()>, actix_web::Error>
+where
+    Usecase: UserRevertMailAddressUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    Ok(
+        revert_mail_address_controller(tx_manager.as_ref(), usecase.as_ref(), info.into_inner())
+            .await
+            .inspect_err(|e| e.log("failed to revert mail address"))
+            .map(web::Json)?,
+    )
+}
+
+async fn revert_mail_address_controller<Usecase, TM>(
+    tx_manager: &Mutex<TM>,
+    usecase: &Usecase,
+    info: RevertMailAddressRequestJdto,
+) -> Result<(), UserControllerError>
+where
+    Usecase: UserRevertMailAddressUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    let mut tx = TM::begin(tx_manager).await?;
+    let res = usecase.revert_mail_address(&mut tx, info.token).await;
+    metrics().observe_usecase("revert_mail_address", &res);
+    TM::execute(tx, res).await
+}
+
+#[derive(Deserialize)]
+pub struct RevertMailAddressRequestJdto {
+    token: String,
+}
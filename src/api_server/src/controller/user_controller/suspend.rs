@@ -0,0 +1,106 @@
+use actix_web::web;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+use crate::{
+    controller::authentication::{Authorized, UsersWrite},
+    metrics::metrics,
+    repository::TransactionManager,
+    use_case::UserSuspendUsecase,
+};
+
+use super::{UserControllerError, UserPathParams};
+
+pub async fn handle_suspend_user<TM, Usecase>(
+    principal: Authorized<UsersWrite>,
+    params: web::Path<UserPathParams>,
+    info: web::Json<SuspendUserRequestJdto>,
+    tx_manager: web::Data<Mutex<TM>>,
+    usecase: web::Data<Usecase>,
+) -> Result<web::Json<()>, actix_web::Error>
+where
+    Usecase: UserSuspendUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    Ok(suspend_user_controller(
+        tx_manager.as_ref(),
+        usecase.as_ref(),
+        principal,
+        params.into_inner(),
+        info.into_inner(),
+    )
+    .await
+    .inspect_err(|e| e.log("failed to suspend user"))
+    .map(web::Json)?)
+}
+
+async fn suspend_user_controller<Usecase, TM>(
+    tx_manager: &Mutex<TM>,
+    usecase: &Usecase,
+    principal: Authorized<UsersWrite>,
+    params: UserPathParams,
+    info: SuspendUserRequestJdto,
+) -> Result<(), UserControllerError>
+where
+    Usecase: UserSuspendUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    let mut tx = TM::begin(tx_manager).await?;
+    let res = usecase
+        .suspend(
+            &mut tx,
+            principal.actor_id,
+            params.id,
+            info.reason,
+            info.until,
+        )
+        .await;
+    metrics().observe_usecase("suspend", &res);
+    TM::execute(tx, res).await
+}
+
+pub async fn handle_reinstate_user<TM, Usecase>(
+    principal: Authorized<UsersWrite>,
+    params: web::Path<UserPathParams>,
+    tx_manager: web::Data<Mutex<TM>>,
+    usecase: web::Data<Usecase>,
+) -> Result<web::Json<()>, actix_web::Error>
+where
+    Usecase: UserSuspendUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    Ok(reinstate_user_controller(
+        tx_manager.as_ref(),
+        usecase.as_ref(),
+        principal,
+        params.into_inner(),
+    )
+    .await
+    .inspect_err(|e| e.log("failed to reinstate user"))
+    .map(web::Json)?)
+}
+
+async fn reinstate_user_controller<Usecase, TM>(
+    tx_manager: &Mutex<TM>,
+    usecase: &Usecase,
+    principal: Authorized<UsersWrite>,
+    params: UserPathParams,
+) -> Result<(), UserControllerError>
+where
+    Usecase: UserSuspendUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    let mut tx = TM::begin(tx_manager).await?;
+    let res = usecase
+        .reinstate(&mut tx, principal.actor_id, params.id)
+        .await;
+    metrics().observe_usecase("reinstate", &res);
+    TM::execute(tx, res).await
+}
+
+#[derive(Deserialize)]
+pub struct SuspendUserRequestJdto {
+    reason: String,
+    until: Option<DateTime<Utc>>,
+}
@@ -1,17 +1,24 @@
 use actix_web::web;
 use serde::Deserialize;
 use tokio::sync::Mutex;
-use uuid::Uuid;
 
 use crate::{
-    domain::UserUpdateCommand, repository::TransactionManager, use_case::UserUpdateUsecase,
+    controller::{
+        authentication::{Authorized, UsersWrite},
+        validated_json::{ValidatedBody, ValidatedJson},
+    },
+    domain::{MailAddress, UserName, UserUpdateCommand, ValidationErrors, Validator},
+    metrics::metrics,
+    repository::TransactionManager,
+    use_case::UserUpdateUsecase,
 };
 
-use super::UserControllerError;
+use super::{UserControllerError, UserPathParams};
 
 pub async fn update_user<TM, Usecase>(
-    params: web::Path<UpdateUserPathParams>,
-    info: web::Json<UpdateUserRequestJdto>,
+    principal: Authorized<UsersWrite>,
+    params: web::Path<UserPathParams>,
+    info: ValidatedJson<UpdateUserRequestJdto>,
     tx_manager: web::Data<Mutex<TM>>,
     usecase: web::Data<Usecase>,
 ) -> Result<web::Json<()>, actix_web::Error>
@@ -22,21 +29,20 @@ where
     Ok(update_user_controller(
         tx_manager.as_ref(),
         usecase.as_ref(),
+        principal,
         params.into_inner(),
         info.into_inner(),
     )
     .await
-    .map_err(|e| {
-        println!("{e}");
-        e
-    })
+    .inspect_err(|e| e.log("failed to update user"))
     .map(web::Json)?)
 }
 
 async fn update_user_controller<Usecase, TM>(
     tx_manager: &Mutex<TM>,
     usecase: &Usecase,
-    params: UpdateUserPathParams,
+    principal: Authorized<UsersWrite>,
+    params: UserPathParams,
     info: UpdateUserRequestJdto,
 ) -> Result<(), UserControllerError>
 where
@@ -47,6 +53,7 @@ where
     let res = usecase
         .update(
             &mut tx,
+            principal.actor_id,
             params.id,
             UserUpdateCommand {
                 name: info.name,
@@ -54,16 +61,30 @@ where
             },
         )
         .await;
+    metrics().observe_usecase("update", &res);
     TM::execute(tx, res).await
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Debug)]
 pub struct UpdateUserRequestJdto {
+    name: Option<UserName>,
+    email: Option<MailAddress>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct RawUpdateUserRequestJdto {
     name: Option<String>,
     email: Option<String>,
 }
 
-#[derive(Deserialize, Debug)]
-pub struct UpdateUserPathParams {
-    pub id: Uuid,
+impl ValidatedBody for UpdateUserRequestJdto {
+    type Raw = RawUpdateUserRequestJdto;
+
+    fn validate(raw: Self::Raw) -> Result<Self, ValidationErrors> {
+        let mut validator = Validator::new();
+        let name = validator.check_optional("name", raw.name.map(UserName::new));
+        let email = validator.check_optional("email", raw.email.map(MailAddress::new));
+        let (name, email) = validator.finish((name, email))?;
+        Ok(Self { name, email })
+    }
 }
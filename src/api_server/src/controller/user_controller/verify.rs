@@ -0,0 +1,44 @@
+use actix_web::web;
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+use crate::{metrics::metrics, repository::TransactionManager, use_case::UserVerifyUsecase};
+
+use super::UserControllerError;
+
+pub async fn handle_verify_user<TM, Usecase>(
+    info: web::Json<VerifyUserRequestJdto>,
+    tx_manager: web::Data<Mutex<TM>>,
+    usecase: web::Data<Usecase>,
+) -> Result<web::Json<()>, actix_web::Error>
+where
+    Usecase: UserVerifyUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    Ok(
+        verify_user_controller(tx_manager.as_ref(), usecase.as_ref(), info.into_inner())
+            .await
+            .inspect_err(|e| e.log("failed to verify user"))
+            .map(web::Json)?,
+    )
+}
+
+async fn verify_user_controller<Usecase, TM>(
+    tx_manager: &Mutex<TM>,
+    usecase: &Usecase,
+    info: VerifyUserRequestJdto,
+) -> Result<(), UserControllerError>
+where
+    Usecase: UserVerifyUsecase<TM>,
+    TM: TransactionManager + Send,
+{
+    let mut tx = TM::begin(tx_manager).await?;
+    let res = usecase.verify(&mut tx, info.token).await;
+    metrics().observe_usecase("verify", &res);
+    TM::execute(tx, res).await
+}
+
+#[derive(Deserialize)]
+pub struct VerifyUserRequestJdto {
+    token: String,
+}
@@ -0,0 +1,187 @@
+use std::{future::Future, pin::Pin};
+
+use actix_web::{dev::Payload, http::StatusCode, web, FromRequest, HttpRequest, HttpResponse};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    domain::{FieldError, ValidationErrors},
+    i18n::Message,
+};
+
+// NOTE: リクエストボディをドメインの型に変換し、不正な値の位置(JSONパス)と検証エラーを返す
+//       サイズの上限やContent-Typeの確認はweb::Jsonに任せ、一度serde_json::Valueとして受け取る
+pub struct ValidatedJson<T>(pub T);
+
+// NOTE: 型や必須項目といった構造はRawのデシリアライズで確認し、値は全てのフィールドをまとめて検証する
+//       serdeのエラーには型付きの検証エラーを載せられないため、値オブジェクトへの変換はvalidateで行う
+pub trait ValidatedBody: Sized {
+    type Raw: DeserializeOwned;
+
+    fn validate(raw: Self::Raw) -> Result<Self, ValidationErrors>;
+}
+
+impl<T> ValidatedJson<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> FromRequest for ValidatedJson<T>
+where
+    T: ValidatedBody + 'static,
+{
+    type Error = actix_web::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let json = web::Json::<serde_json::Value>::from_request(req, payload);
+        Box::pin(async move {
+            let value = json.await?.into_inner();
+            let body = parse_body(value)
+                .inspect_err(|e| tracing::info!(error = %e, "invalid request body"))?;
+            Ok(Self(body))
+        })
+    }
+}
+
+fn parse_body<T>(value: serde_json::Value) -> Result<T, JsonPayloadError>
+where
+    T: ValidatedBody,
+{
+    let raw = serde_path_to_error::deserialize(value)?;
+    Ok(T::validate(raw)?)
+}
+
+// NOTE: 構造の誤りはJSONパスとserdeのエラーを、値の誤りは値オブジェクトの検証エラーをそのまま保持する
+#[derive(Debug, thiserror::Error)]
+pub enum JsonPayloadError {
+    #[error("{path}: {source}")]
+    InvalidStructure {
+        path: String,
+        source: serde_json::Error,
+    },
+    #[error(transparent)]
+    InvalidValues(#[from] ValidationErrors),
+}
+
+impl From<serde_path_to_error::Error<serde_json::Error>> for JsonPayloadError {
+    fn from(value: serde_path_to_error::Error<serde_json::Error>) -> Self {
+        Self::InvalidStructure {
+            path: value.path().to_string(),
+            source: value.into_inner(),
+        }
+    }
+}
+
+impl actix_web::ResponseError for JsonPayloadError {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::BAD_REQUEST
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        match self {
+            // NOTE: serdeのエラーの文言は言語を選べないため、レスポンスには含めずログにのみ出力する
+            Self::InvalidStructure { path, .. } => {
+                validation_error_response(&ValidationErrors::from(FieldError {
+                    field: path.clone(),
+                    code: "invalid_value",
+                    message: Message::new("invalid-json-value"),
+                }))
+            }
+            Self::InvalidValues(errors) => validation_error_response(errors),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ValidationErrorResponseJdto<'a> {
+    errors: &'a ValidationErrors,
+}
+
+// NOTE: 入力値の検証エラーは、フィールドごとに1件ずつ{field, code, message}を返す
+pub fn validation_error_response(errors: &ValidationErrors) -> HttpResponse {
+    HttpResponse::BadRequest().json(ValidationErrorResponseJdto { errors })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{MailAddress, UserName, Validator};
+    use rstest::rstest;
+    use serde::Deserialize;
+
+    #[derive(Debug)]
+    struct Request {
+        name: UserName,
+        emails: Vec<MailAddress>,
+    }
+
+    #[derive(Deserialize)]
+    struct RawRequest {
+        name: String,
+        emails: Vec<String>,
+    }
+
+    impl ValidatedBody for Request {
+        type Raw = RawRequest;
+
+        fn validate(raw: Self::Raw) -> Result<Self, ValidationErrors> {
+            let mut validator = Validator::new();
+            let name = validator.check("name", UserName::new(raw.name));
+            let emails: Vec<_> = raw
+                .emails
+                .into_iter()
+                .enumerate()
+                .map(|(i, email)| validator.check(&format!("emails[{i}]"), MailAddress::new(email)))
+                .collect();
+            let emails = emails.into_iter().collect::<Option<Vec<_>>>();
+            let (name, emails) = validator.finish((name, emails))?;
+            Ok(Self { name, emails })
+        }
+    }
+
+    fn parse(json: &str) -> Result<Request, JsonPayloadError> {
+        parse_body(serde_json::from_str(json).unwrap())
+    }
+
+    #[rstest]
+    fn invalid_values_keep_code_and_message() {
+        let Err(JsonPayloadError::InvalidValues(errors)) =
+            parse(r#"{"name": "ab", "emails": ["hoge@example.com", "hoge"]}"#)
+        else {
+            panic!("expected invalid values");
+        };
+        assert_eq!(
+            errors.errors(),
+            [
+                FieldError {
+                    field: "name".to_string(),
+                    code: "too_short",
+                    message: Message::new("user-name-too-short").arg("min_length", 3usize),
+                },
+                FieldError {
+                    field: "emails[1]".to_string(),
+                    code: "invalid_format",
+                    message: Message::new("mail-address-invalid-format"),
+                },
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case(r#"{"name": "hoge"}"#, ".")]
+    #[case(r#"{"name": "hoge", "emails": [1]}"#, "emails[0]")]
+    fn invalid_structure_has_path(#[case] json: &str, #[case] expected: &str) {
+        let Err(JsonPayloadError::InvalidStructure { path, .. }) = parse(json) else {
+            panic!("expected invalid structure");
+        };
+        assert_eq!(path, expected);
+    }
+
+    #[rstest]
+    fn valid_body_is_converted() {
+        let request = parse(r#"{"name": "hoge", "emails": ["hoge@example.com"]}"#).unwrap();
+        assert_eq!(request.name.get(), "hoge");
+        assert_eq!(request.emails.len(), 1);
+    }
+}
@@ -4,8 +4,14 @@ pub use entity::*;
 mod factory;
 pub use factory::*;
 
+mod policy;
+pub use policy::*;
+
 mod service;
 pub use service::*;
 
+mod validation;
+pub use validation::*;
+
 mod value_object;
 pub use value_object::*;
@@ -1,3 +1,21 @@
+mod account_lock_event;
+mod api_key;
+mod login_challenge;
+mod login_throttle;
+mod mail_verification;
+mod password_reset;
+mod recovery_code_entry;
+mod session;
+mod totp_credential;
 mod user;
 
+pub use account_lock_event::*;
+pub use api_key::*;
+pub use login_challenge::*;
+pub use login_throttle::*;
+pub use mail_verification::*;
+pub use password_reset::*;
+pub use recovery_code_entry::*;
+pub use session::*;
+pub use totp_credential::*;
 pub use user::*;
@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::UserId;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountLockEventKind {
+    Locked,
+    Unlocked,
+}
+
+impl AccountLockEventKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Locked => "locked",
+            Self::Unlocked => "unlocked",
+        }
+    }
+}
+
+// NOTE: アカウントのロック・ロック解除の監査記録
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountLockEvent {
+    pub user_id: UserId,
+    pub kind: AccountLockEventKind,
+    // NOTE: 管理者による解除の場合のみ。ログイン失敗によるロックはNone
+    pub actor_id: Option<UserId>,
+    pub locked_until: Option<DateTime<Utc>>,
+    pub occurred_at: DateTime<Utc>,
+}
@@ -0,0 +1,175 @@
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use crate::domain::{ApiKeyId, ApiKeyIdError, ApiKeyScope, ApiKeyToken, SecretTokenHash, UserId};
+
+// NOTE: 管理者が発行するサービス間呼び出し用のキー
+//       平文のキーは発行時にのみ返し、ハッシュ値とprefixだけを保持する
+pub struct ApiKey {
+    pub id: ApiKeyId,
+    pub name: String,
+    pub prefix: String,
+    pub key_hash: SecretTokenHash,
+    pub scopes: Vec<ApiKeyScope>,
+    pub created_by: UserId,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl ApiKey {
+    const NAME_MAX_LENGTH: usize = 100;
+
+    pub fn issue(
+        name: &str,
+        scopes: Vec<ApiKeyScope>,
+        created_by: UserId,
+        now: DateTime<Utc>,
+    ) -> Result<(Self, ApiKeyToken), ApiKeyError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(ApiKeyError::EmptyName);
+        }
+        if name.chars().count() > Self::NAME_MAX_LENGTH {
+            return Err(ApiKeyError::NameTooLong {
+                max: Self::NAME_MAX_LENGTH,
+            });
+        }
+        let mut scopes = scopes;
+        scopes.sort();
+        scopes.dedup();
+        if scopes.is_empty() {
+            return Err(ApiKeyError::NoScopes);
+        }
+        let token = ApiKeyToken::generate();
+        let api_key = Self {
+            id: ApiKeyId::new(Uuid::new_v4())?,
+            name: name.to_string(),
+            prefix: token.prefix().to_string(),
+            key_hash: token.hash(),
+            scopes,
+            created_by,
+            created_at: now,
+            last_used_at: None,
+            revoked_at: None,
+        };
+        Ok((api_key, token))
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.revoked_at.is_none()
+    }
+
+    pub fn has_scope(&self, scope: ApiKeyScope) -> bool {
+        self.scopes.contains(&scope)
+    }
+
+    // NOTE: リクエストごとの書き込みを避けるため、前回の記録から一定時間経過した場合のみ更新する
+    //       更新した場合はtrueを返す
+    pub fn record_use(&mut self, now: DateTime<Utc>) -> bool {
+        let resolution = Duration::minutes(1);
+        match self.last_used_at {
+            Some(last_used_at) if now - last_used_at < resolution => false,
+            _ => {
+                self.last_used_at = Some(now);
+                true
+            }
+        }
+    }
+
+    pub fn revoke(&mut self, now: DateTime<Utc>) -> Result<(), ApiKeyError> {
+        if self.revoked_at.is_some() {
+            return Err(ApiKeyError::AlreadyRevoked);
+        }
+        self.revoked_at = Some(now);
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ApiKeyError {
+    #[error("APIキーの名前を入力してください。")]
+    EmptyName,
+    #[error("APIキーの名前は{max}文字以内で入力してください。")]
+    NameTooLong { max: usize },
+    #[error("APIキーには1つ以上のスコープが必要です。")]
+    NoScopes,
+    #[error("APIキーはすでに失効しています。")]
+    AlreadyRevoked,
+    #[error(transparent)]
+    ApiKeyIdError(#[from] ApiKeyIdError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn issue(scopes: Vec<ApiKeyScope>) -> Result<(ApiKey, ApiKeyToken), ApiKeyError> {
+        let created_by = UserId::new(Uuid::new_v4()).unwrap();
+        ApiKey::issue(" batch ", scopes, created_by, Utc::now())
+    }
+
+    #[rstest]
+    fn issue_keeps_only_hash_and_prefix() {
+        let (api_key, token) = issue(vec![
+            ApiKeyScope::UsersWrite,
+            ApiKeyScope::UsersRead,
+            ApiKeyScope::UsersWrite,
+        ])
+        .unwrap();
+
+        assert_eq!(api_key.name, "batch");
+        assert_eq!(api_key.prefix, token.prefix());
+        assert_eq!(api_key.key_hash, token.hash());
+        assert_eq!(
+            api_key.scopes,
+            vec![ApiKeyScope::UsersRead, ApiKeyScope::UsersWrite]
+        );
+        assert!(api_key.is_active());
+    }
+
+    #[rstest]
+    #[case("  ", vec![ApiKeyScope::UsersRead], ApiKeyError::EmptyName)]
+    #[case(&"a".repeat(101), vec![ApiKeyScope::UsersRead], ApiKeyError::NameTooLong { max: 100 })]
+    #[case("batch", vec![], ApiKeyError::NoScopes)]
+    fn issue_rejects_invalid_input(
+        #[case] name: &str,
+        #[case] scopes: Vec<ApiKeyScope>,
+        #[case] expected: ApiKeyError,
+    ) {
+        let created_by = UserId::new(Uuid::new_v4()).unwrap();
+        let res = ApiKey::issue(name, scopes, created_by, Utc::now());
+        assert_eq!(res.err(), Some(expected));
+    }
+
+    #[rstest]
+    fn has_scope() {
+        let (api_key, _) = issue(vec![ApiKeyScope::UsersRead]).unwrap();
+        assert!(api_key.has_scope(ApiKeyScope::UsersRead));
+        assert!(!api_key.has_scope(ApiKeyScope::UsersWrite));
+    }
+
+    #[rstest]
+    fn record_use_is_throttled() {
+        let (mut api_key, _) = issue(vec![ApiKeyScope::UsersRead]).unwrap();
+        let now = Utc::now();
+
+        assert!(api_key.record_use(now));
+        assert!(!api_key.record_use(now + Duration::seconds(59)));
+        assert_eq!(api_key.last_used_at, Some(now));
+        assert!(api_key.record_use(now + Duration::seconds(60)));
+        assert_eq!(api_key.last_used_at, Some(now + Duration::seconds(60)));
+    }
+
+    #[rstest]
+    fn revoke_only_once() {
+        let (mut api_key, _) = issue(vec![ApiKeyScope::UsersRead]).unwrap();
+        let now = Utc::now();
+
+        assert_eq!(api_key.revoke(now), Ok(()));
+        assert!(!api_key.is_active());
+        assert_eq!(api_key.revoke(now), Err(ApiKeyError::AlreadyRevoked));
+        assert_eq!(api_key.revoked_at, Some(now));
+    }
+}
@@ -0,0 +1,16 @@
+use chrono::{DateTime, Duration, Utc};
+
+use crate::domain::{SecretToken, SingleUseToken, UserId};
+
+// NOTE: パスワードの確認後、二要素目の確認を待っているログイン
+pub struct LoginChallenge {
+    pub token: SingleUseToken,
+    pub user_id: UserId,
+}
+
+impl LoginChallenge {
+    pub fn issue(user_id: UserId, now: DateTime<Utc>, ttl: Duration) -> (Self, SecretToken) {
+        let (token, secret) = SingleUseToken::issue(now, ttl);
+        (Self { token, user_id }, secret)
+    }
+}
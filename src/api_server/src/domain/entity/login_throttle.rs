@@ -0,0 +1,61 @@
+use chrono::{DateTime, Duration, Utc};
+
+use crate::domain::LoginThrottlePolicy;
+
+// NOTE: 接続元IPアドレスごとのログイン失敗回数
+//       クレデンシャルスタッフィングのように複数アカウントへ分散した試行を抑止する
+//       同時に失敗した場合も数え漏らさないよう、回数の加算はLoginThrottleRepository::record_failureで行う
+//       成功しても回数は戻さない(有効なアカウントを1つ持つだけで制限を回避できてしまうため)
+pub struct LoginThrottle {
+    pub ip_address: String,
+    pub failed_count: u32,
+    pub last_failed_at: DateTime<Utc>,
+}
+
+impl LoginThrottle {
+    pub fn retry_after(
+        &self,
+        now: DateTime<Utc>,
+        policy: &LoginThrottlePolicy,
+    ) -> Option<Duration> {
+        let blocked_until = self.last_failed_at + policy.ip_address_backoff(self.failed_count)?;
+        (blocked_until > now).then(|| blocked_until - now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn backoff_after_threshold() {
+        let policy = LoginThrottlePolicy {
+            account_threshold: 5,
+            ip_address_threshold: 2,
+            base_delay: Duration::seconds(10),
+            max_delay: Duration::hours(1),
+            ip_address_window: Duration::hours(1),
+        };
+        let now = Utc::now();
+        let throttle = |failed_count| LoginThrottle {
+            ip_address: "192.0.2.1".to_string(),
+            failed_count,
+            last_failed_at: now,
+        };
+
+        assert_eq!(throttle(1).retry_after(now, &policy), None);
+        assert_eq!(
+            throttle(2).retry_after(now, &policy),
+            Some(Duration::seconds(10))
+        );
+        assert_eq!(
+            throttle(3).retry_after(now, &policy),
+            Some(Duration::seconds(20))
+        );
+        assert_eq!(
+            throttle(3).retry_after(now + Duration::seconds(20), &policy),
+            None
+        );
+    }
+}
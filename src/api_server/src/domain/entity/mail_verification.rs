@@ -0,0 +1,63 @@
+use chrono::{DateTime, Duration, Utc};
+
+use crate::domain::{MailAddress, SecretToken, SingleUseToken, UserId};
+
+// NOTE: メールアドレスの確認トークン。確認対象のアドレスを保持し、
+//       発行後にアドレスが変更された場合は無効とする
+pub struct MailVerification {
+    pub token: SingleUseToken,
+    pub user_id: UserId,
+    pub purpose: MailVerificationPurpose,
+    // NOTE: Revertの場合は復元先となる変更前のアドレス
+    pub mail_address: MailAddress,
+}
+
+impl MailVerification {
+    pub fn issue(
+        user_id: UserId,
+        purpose: MailVerificationPurpose,
+        mail_address: MailAddress,
+        now: DateTime<Utc>,
+        ttl: Duration,
+    ) -> (Self, SecretToken) {
+        let (token, secret) = SingleUseToken::issue(now, ttl);
+        let verification = Self {
+            token,
+            user_id,
+            purpose,
+            mail_address,
+        };
+        (verification, secret)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MailVerificationPurpose {
+    // NOTE: 登録時・変更時の新しいアドレスの確認
+    Verify,
+    // NOTE: 変更前のアドレスに送る変更の取り消し
+    Revert,
+}
+
+impl MailVerificationPurpose {
+    pub fn new(value: &str) -> Result<Self, MailVerificationError> {
+        match value {
+            "verify" => Ok(Self::Verify),
+            "revert" => Ok(Self::Revert),
+            _ => Err(MailVerificationError::UnknownPurpose(value.to_string())),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Verify => "verify",
+            Self::Revert => "revert",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum MailVerificationError {
+    #[error("{0}は不明なトークンの用途です。")]
+    UnknownPurpose(String),
+}
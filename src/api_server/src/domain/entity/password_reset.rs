@@ -0,0 +1,16 @@
+use chrono::{DateTime, Duration, Utc};
+
+use crate::domain::{SecretToken, SingleUseToken, UserId};
+
+// NOTE: パスワード再設定トークン
+pub struct PasswordReset {
+    pub token: SingleUseToken,
+    pub user_id: UserId,
+}
+
+impl PasswordReset {
+    pub fn issue(user_id: UserId, now: DateTime<Utc>, ttl: Duration) -> (Self, SecretToken) {
+        let (token, secret) = SingleUseToken::issue(now, ttl);
+        (Self { token, user_id }, secret)
+    }
+}
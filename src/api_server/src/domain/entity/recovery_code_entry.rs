@@ -0,0 +1,62 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::{RecoveryCode, SecretTokenHash, UserId};
+
+// NOTE: 発行済みのリカバリーコード。ハッシュのみを保存し、一度だけ使用できる
+pub struct RecoveryCodeEntry {
+    pub code_hash: SecretTokenHash,
+    pub user_id: UserId,
+    pub created_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+}
+
+impl RecoveryCodeEntry {
+    // NOTE: 平文のコードは発行時に一度だけ利用者に表示する
+    pub fn issue(
+        user_id: UserId,
+        count: usize,
+        now: DateTime<Utc>,
+    ) -> (Vec<Self>, Vec<RecoveryCode>) {
+        (0..count)
+            .map(|_| {
+                let code = RecoveryCode::generate();
+                let entry = Self {
+                    code_hash: code.hash(),
+                    user_id,
+                    created_at: now,
+                    used_at: None,
+                };
+                (entry, code)
+            })
+            .unzip()
+    }
+
+    pub fn consume(&mut self, now: DateTime<Utc>) -> bool {
+        if self.used_at.is_some() {
+            return false;
+        }
+        self.used_at = Some(now);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use uuid::Uuid;
+
+    #[rstest]
+    fn issue_unique_codes_usable_once() {
+        let now = Utc::now();
+        let (mut entries, codes) =
+            RecoveryCodeEntry::issue(UserId::new(Uuid::new_v4()).unwrap(), 10, now);
+
+        assert_eq!(entries.len(), 10);
+        assert_eq!(entries[3].code_hash, codes[3].hash());
+        let unique: std::collections::HashSet<_> = codes.iter().map(|code| code.get()).collect();
+        assert_eq!(unique.len(), 10);
+        assert!(entries[0].consume(now));
+        assert!(!entries[0].consume(now));
+    }
+}
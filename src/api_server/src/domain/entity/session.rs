@@ -0,0 +1,139 @@
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use crate::domain::{
+    SecretToken, SecretTokenError, SecretTokenHash, SessionId, SessionIdError, UserId, UserIdError,
+};
+
+// NOTE: リフレッシュトークンのサーバー側の記録
+//       リフレッシュの度に新しいセッションへローテーションし、古いものは失効させる
+pub struct Session {
+    pub id: SessionId,
+    // NOTE: ログイン時のセッションのIDで、ローテーション後のセッションにも引き継がれる
+    pub family_id: SessionId,
+    pub user_id: UserId,
+    pub token_hash: SecretTokenHash,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionState {
+    Active,
+    Expired,
+    Revoked,
+}
+
+impl Session {
+    // NOTE: 平文のトークンは発行時にのみ返し、セッションにはハッシュ値だけを保持する
+    pub fn issue(
+        user_id: UserId,
+        now: DateTime<Utc>,
+        ttl: Duration,
+    ) -> Result<(Self, SecretToken), SessionError> {
+        let id = SessionId::new(Uuid::new_v4())?;
+        Self::issue_in_family(id, user_id, now, ttl)
+    }
+
+    fn issue_in_family(
+        family_id: SessionId,
+        user_id: UserId,
+        now: DateTime<Utc>,
+        ttl: Duration,
+    ) -> Result<(Self, SecretToken), SessionError> {
+        let token = SecretToken::generate();
+        let session = Self {
+            id: SessionId::new(Uuid::new_v4())?,
+            family_id,
+            user_id,
+            token_hash: token.hash(),
+            created_at: now,
+            expires_at: now + ttl,
+            revoked_at: None,
+        };
+        Ok((session, token))
+    }
+
+    pub fn state(&self, now: DateTime<Utc>) -> SessionState {
+        if self.revoked_at.is_some() {
+            SessionState::Revoked
+        } else if self.expires_at <= now {
+            SessionState::Expired
+        } else {
+            SessionState::Active
+        }
+    }
+
+    // NOTE: 自身を失効させ、同じファミリーの新しいセッションを発行する
+    pub fn rotate(
+        &mut self,
+        now: DateTime<Utc>,
+        ttl: Duration,
+    ) -> Result<(Self, SecretToken), SessionError> {
+        if self.state(now) != SessionState::Active {
+            return Err(SessionError::NotActive);
+        }
+        self.revoke(now);
+        Self::issue_in_family(self.family_id, self.user_id, now, ttl)
+    }
+
+    pub fn revoke(&mut self, now: DateTime<Utc>) {
+        self.revoked_at.get_or_insert(now);
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SessionError {
+    #[error("セッションは有効期限切れか失効しています。")]
+    NotActive,
+    #[error(transparent)]
+    SessionIdError(#[from] SessionIdError),
+    #[error(transparent)]
+    UserIdError(#[from] UserIdError),
+    #[error(transparent)]
+    SecretTokenError(#[from] SecretTokenError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn issue(now: DateTime<Utc>) -> Session {
+        let user_id = UserId::new(Uuid::new_v4()).unwrap();
+        Session::issue(user_id, now, Duration::hours(1)).unwrap().0
+    }
+
+    #[rstest]
+    fn rotate_revokes_and_keeps_family() {
+        let now = Utc::now();
+        let mut session = issue(now);
+        let (rotated, token) = session.rotate(now, Duration::hours(1)).unwrap();
+
+        assert_eq!(session.state(now), SessionState::Revoked);
+        assert_eq!(rotated.state(now), SessionState::Active);
+        assert_eq!(rotated.family_id, session.family_id);
+        assert_eq!(rotated.token_hash, token.hash());
+        assert_ne!(rotated.token_hash, session.token_hash);
+    }
+
+    #[rstest]
+    fn inactive_session_cannot_rotate() {
+        let now = Utc::now();
+        let mut session = issue(now);
+        let later = now + Duration::hours(2);
+
+        assert_eq!(session.state(later), SessionState::Expired);
+        assert!(matches!(
+            session.rotate(later, Duration::hours(1)),
+            Err(SessionError::NotActive)
+        ));
+
+        session.revoke(now);
+        assert!(matches!(
+            session.rotate(now, Duration::hours(1)),
+            Err(SessionError::NotActive)
+        ));
+    }
+}
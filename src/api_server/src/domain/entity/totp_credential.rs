@@ -0,0 +1,91 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::{Totp, TotpSecret, UserId};
+
+// NOTE: ユーザーごとのTOTPの共有鍵。認証アプリで生成したコードを確認するまでは登録途中とする
+pub struct TotpCredential {
+    pub user_id: UserId,
+    pub secret: TotpSecret,
+    pub confirmed_at: Option<DateTime<Utc>>,
+    // NOTE: 最後に受け付けたコードの時間ステップ。同じコードの再利用を防ぐ
+    pub last_used_step: Option<i64>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TotpCredential {
+    pub fn new(user_id: UserId, now: DateTime<Utc>) -> Self {
+        Self {
+            user_id,
+            secret: TotpSecret::generate(),
+            confirmed_at: None,
+            last_used_step: None,
+            created_at: now,
+        }
+    }
+
+    pub fn is_confirmed(&self) -> bool {
+        self.confirmed_at.is_some()
+    }
+
+    // NOTE: 受け付けた場合は時間ステップを記録するため、呼び出し後は保存が必要
+    pub fn verify(&mut self, totp: &Totp, code: &str, now: DateTime<Utc>) -> bool {
+        match totp.verify(&self.secret, code, now, self.last_used_step) {
+            Some(step) => {
+                self.last_used_step = Some(step);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn confirm(
+        &mut self,
+        totp: &Totp,
+        code: &str,
+        now: DateTime<Utc>,
+    ) -> Result<(), TotpCredentialError> {
+        if self.is_confirmed() {
+            return Err(TotpCredentialError::AlreadyConfirmed);
+        }
+        if !self.verify(totp, code, now) {
+            return Err(TotpCredentialError::InvalidCode);
+        }
+        self.confirmed_at = Some(now);
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum TotpCredentialError {
+    #[error("二要素認証は登録済みです。")]
+    AlreadyConfirmed,
+    #[error("確認コードが正しくありません。")]
+    InvalidCode,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Clock as _, FixedClock};
+    use chrono::Duration;
+    use rstest::rstest;
+    use uuid::Uuid;
+
+    #[rstest]
+    fn confirm_with_current_code() {
+        let clock = FixedClock(DateTime::from_timestamp(1_700_000_000, 0).unwrap());
+        let totp = Totp::default();
+        let mut credential = TotpCredential::new(UserId::new(Uuid::new_v4()).unwrap(), clock.now());
+        let code = totp.code_at(&credential.secret, totp.step_at(clock.now()));
+
+        let later = clock.now() + Duration::minutes(5);
+        assert_eq!(
+            credential.confirm(&totp, &code, later),
+            Err(TotpCredentialError::InvalidCode)
+        );
+        assert_eq!(credential.confirm(&totp, &code, clock.now()), Ok(()));
+        assert!(credential.is_confirmed());
+        // NOTE: 確認に使用したコードはログインには使えない
+        assert!(!credential.verify(&totp, &code, clock.now()));
+    }
+}
@@ -1,19 +1,48 @@
+use chrono::{DateTime, Duration, Utc};
+
 use crate::domain::{
-    value_object::MailAddress, MailAddressError, UserId, UserIdError, UserName, UserNameError,
+    value_object::MailAddress, AccountLockEvent, AccountLockEventKind, LoginThrottlePolicy,
+    MailAddressError, PasswordHash, PasswordHashError, Role, RoleError, UserId, UserIdError,
+    UserName, UserNameError, UserStatus, UserStatusError,
 };
 
 pub struct User {
     pub id: UserId,
     pub name: UserName,
     pub mail_address: MailAddress,
+    // NOTE: パスワード導入前に登録されたユーザーはNone(ログイン不可)
+    pub password_hash: Option<PasswordHash>,
+    pub role: Role,
+    pub status: UserStatus,
+    pub mail_verified_at: Option<DateTime<Utc>>,
+    // NOTE: 変更後のアドレス。新しいアドレスで確認されるまでmail_addressには反映しない
+    pub pending_mail_address: Option<MailAddress>,
+    // NOTE: 連続したログインの失敗回数。成功または管理者によるロック解除でリセットされる
+    pub failed_login_count: u32,
+    pub locked_until: Option<DateTime<Utc>>,
+    // NOTE: trueの場合、ログイン時に二要素目の確認を要求する
+    pub two_factor_enabled: bool,
 }
 
 impl User {
-    pub fn new(id: UserId, name: UserName, mail_address: MailAddress) -> Self {
+    pub fn new(
+        id: UserId,
+        name: UserName,
+        mail_address: MailAddress,
+        password_hash: PasswordHash,
+    ) -> Self {
         Self {
             id,
             name,
             mail_address,
+            password_hash: Some(password_hash),
+            role: Role::default(),
+            status: UserStatus::default(),
+            mail_verified_at: None,
+            pending_mail_address: None,
+            failed_login_count: 0,
+            locked_until: None,
+            two_factor_enabled: false,
         }
     }
 
@@ -30,8 +59,145 @@ impl User {
         self.name = name;
     }
 
-    pub fn change_mail_address(&mut self, mail_address: MailAddress) {
-        self.mail_address = mail_address;
+    // NOTE: 変更は保留状態となり、新しいアドレスでの確認(verify_mail_address)で反映される
+    //       現在のアドレスを指定した場合は保留中の変更を取り消す
+    //       新たに保留状態となった場合にtrueを返す
+    pub fn request_mail_address_change(&mut self, mail_address: MailAddress) -> bool {
+        if self.mail_address == mail_address {
+            self.pending_mail_address = None;
+            return false;
+        }
+        if self.pending_mail_address.as_ref() == Some(&mail_address) {
+            return false;
+        }
+        self.pending_mail_address = Some(mail_address);
+        true
+    }
+
+    pub fn is_mail_verified(&self) -> bool {
+        self.mail_verified_at.is_some()
+    }
+
+    // NOTE: 現在のアドレスの確認、または保留中の変更の確定を行う
+    //       確認トークンの発行後にアドレスが変更・取り消しされていた場合は確認できない
+    //       確認待ちのユーザーは有効化する
+    pub fn verify_mail_address(
+        &mut self,
+        mail_address: &MailAddress,
+        now: DateTime<Utc>,
+    ) -> Result<(), UserError> {
+        if self.pending_mail_address.as_ref() == Some(mail_address) {
+            self.mail_address = mail_address.clone();
+            self.pending_mail_address = None;
+            self.mail_verified_at = Some(now);
+        } else if self.mail_address == *mail_address {
+            self.mail_verified_at.get_or_insert(now);
+        } else {
+            return Err(UserError::MailAddressMismatch);
+        }
+        if self.status == UserStatus::Pending {
+            self.activate()?;
+        }
+        Ok(())
+    }
+
+    // NOTE: 変更前のアドレスに送った取り消しリンクによる復元
+    //       保留中の変更は破棄し、変更が確定済みの場合も元のアドレスに戻す
+    pub fn revert_mail_address(&mut self, mail_address: MailAddress, now: DateTime<Utc>) {
+        self.pending_mail_address = None;
+        if self.mail_address != mail_address {
+            self.mail_address = mail_address;
+            self.mail_verified_at = Some(now);
+        }
+    }
+
+    pub fn change_password_hash(&mut self, password_hash: PasswordHash) {
+        self.password_hash = Some(password_hash);
+    }
+
+    pub fn change_role(&mut self, role: Role) {
+        self.role = role;
+    }
+
+    pub fn is_available_at(&self, now: DateTime<Utc>) -> bool {
+        self.status.is_available_at(now)
+    }
+
+    pub fn activate(&mut self) -> Result<(), UserStatusError> {
+        self.status = self.status.activate()?;
+        Ok(())
+    }
+
+    pub fn suspend(
+        &mut self,
+        reason: String,
+        until: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> Result<(), UserStatusError> {
+        self.status = self.status.suspend(reason, until, now)?;
+        Ok(())
+    }
+
+    pub fn reinstate(&mut self) -> Result<(), UserStatusError> {
+        self.status = self.status.reinstate(self.is_mail_verified())?;
+        Ok(())
+    }
+
+    // NOTE: 退会後はログインできないよう、パスワードのハッシュも破棄する
+    pub fn delete(&mut self, now: DateTime<Utc>) -> Result<(), UserStatusError> {
+        self.status = self.status.delete(now)?;
+        self.password_hash = None;
+        self.pending_mail_address = None;
+        Ok(())
+    }
+
+    pub fn lock_remaining(&self, now: DateTime<Utc>) -> Option<Duration> {
+        self.locked_until
+            .filter(|locked_until| *locked_until > now)
+            .map(|locked_until| locked_until - now)
+    }
+
+    // NOTE: 閾値を超えた場合はロックし、監査用のイベントを返す
+    //       ロックの期限切れ後に再度失敗した場合は、より長い期間ロックされる
+    pub fn record_login_failure(
+        &mut self,
+        now: DateTime<Utc>,
+        policy: &LoginThrottlePolicy,
+    ) -> Option<AccountLockEvent> {
+        self.failed_login_count = self.failed_login_count.saturating_add(1);
+        let locked_until = now + policy.account_lockout(self.failed_login_count)?;
+        self.locked_until = Some(locked_until);
+        Some(AccountLockEvent {
+            user_id: self.id,
+            kind: AccountLockEventKind::Locked,
+            actor_id: None,
+            locked_until: Some(locked_until),
+            occurred_at: now,
+        })
+    }
+
+    pub fn record_login_success(&mut self) {
+        self.failed_login_count = 0;
+        self.locked_until = None;
+    }
+
+    pub fn enable_two_factor(&mut self) {
+        self.two_factor_enabled = true;
+    }
+
+    pub fn disable_two_factor(&mut self) {
+        self.two_factor_enabled = false;
+    }
+
+    pub fn unlock(&mut self, actor_id: UserId, now: DateTime<Utc>) -> AccountLockEvent {
+        self.record_login_success();
+        AccountLockEvent {
+            user_id: self.id,
+            kind: AccountLockEventKind::Unlocked,
+            actor_id: Some(actor_id),
+            locked_until: None,
+            occurred_at: now,
+        }
     }
 }
 
@@ -42,8 +208,8 @@ impl std::fmt::Display for User {
 }
 
 pub struct UserUpdateCommand {
-    pub name: Option<String>,
-    pub mail_address: Option<String>,
+    pub name: Option<UserName>,
+    pub mail_address: Option<MailAddress>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -54,4 +220,140 @@ pub enum UserError {
     UserNameError(#[from] UserNameError),
     #[error(transparent)]
     MailAddressError(#[from] MailAddressError),
+    #[error(transparent)]
+    PasswordHashError(#[from] PasswordHashError),
+    #[error(transparent)]
+    RoleError(#[from] RoleError),
+    #[error(transparent)]
+    UserStatusError(#[from] UserStatusError),
+    #[error("確認対象のメールアドレスが現在のアドレスと一致しません。")]
+    MailAddressMismatch,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use uuid::Uuid;
+
+    fn mail(value: &str) -> MailAddress {
+        MailAddress::new(value.to_string()).unwrap()
+    }
+
+    fn verified_user() -> User {
+        let hash = PasswordHash::new(
+            "$argon2id$v=19$m=19456,t=2,p=1$c2FsdHNhbHRzYWx0$3nB0X2mK4d2n9JqJ0kq6mF2d3Qx3y8oVn8w0bq7fJ8Q".to_string(),
+        )
+        .unwrap();
+        let mut user = User::new(
+            UserId::new(Uuid::new_v4()).unwrap(),
+            UserName::new("hoge".to_string()).unwrap(),
+            mail("old@example.com"),
+            hash,
+        );
+        user.mail_verified_at = Some(Utc::now());
+        user.status = UserStatus::Active;
+        user
+    }
+
+    #[rstest]
+    fn change_applies_on_confirmation() {
+        let mut user = verified_user();
+        assert!(user.request_mail_address_change(mail("new@example.com")));
+        assert_eq!(user.mail_address, mail("old@example.com"));
+
+        let now = Utc::now();
+        user.verify_mail_address(&mail("new@example.com"), now)
+            .unwrap();
+        assert_eq!(user.mail_address, mail("new@example.com"));
+        assert_eq!(user.pending_mail_address, None);
+        assert_eq!(user.mail_verified_at, Some(now));
+    }
+
+    #[rstest]
+    fn stale_confirmation_is_rejected() {
+        let mut user = verified_user();
+        user.request_mail_address_change(mail("first@example.com"));
+        user.request_mail_address_change(mail("second@example.com"));
+
+        assert!(matches!(
+            user.verify_mail_address(&mail("first@example.com"), Utc::now()),
+            Err(UserError::MailAddressMismatch)
+        ));
+        assert!(!user.request_mail_address_change(mail("old@example.com")));
+        assert_eq!(user.pending_mail_address, None);
+    }
+
+    #[rstest]
+    #[case(false)]
+    #[case(true)]
+    fn revert_restores_old_address(#[case] confirmed: bool) {
+        let mut user = verified_user();
+        user.request_mail_address_change(mail("new@example.com"));
+        if confirmed {
+            user.verify_mail_address(&mail("new@example.com"), Utc::now())
+                .unwrap();
+        }
+
+        user.revert_mail_address(mail("old@example.com"), Utc::now());
+        assert_eq!(user.mail_address, mail("old@example.com"));
+        assert_eq!(user.pending_mail_address, None);
+        assert!(user.is_mail_verified());
+    }
+
+    #[rstest]
+    fn verification_activates_pending_user() {
+        let mut user = verified_user();
+        user.status = UserStatus::Pending;
+        user.mail_verified_at = None;
+
+        user.verify_mail_address(&mail("old@example.com"), Utc::now())
+            .unwrap();
+        assert_eq!(user.status, UserStatus::Active);
+        // NOTE: 利用停止中のユーザーは確認しても停止されたまま
+        user.suspend("spam".to_string(), None, Utc::now()).unwrap();
+        user.verify_mail_address(&mail("old@example.com"), Utc::now())
+            .unwrap();
+        assert!(!user.is_available_at(Utc::now()));
+    }
+
+    #[rstest]
+    fn delete_discards_credentials() {
+        let mut user = verified_user();
+        user.delete(Utc::now()).unwrap();
+        assert!(user.status.is_deleted());
+        assert!(user.password_hash.is_none());
+        assert!(user.delete(Utc::now()).is_err());
+    }
+
+    #[rstest]
+    fn lockout_and_unlock() {
+        let policy = LoginThrottlePolicy {
+            account_threshold: 2,
+            ip_address_threshold: 10,
+            base_delay: Duration::minutes(1),
+            max_delay: Duration::hours(1),
+            ip_address_window: Duration::hours(1),
+        };
+        let now = Utc::now();
+        let mut user = verified_user();
+
+        assert_eq!(user.record_login_failure(now, &policy), None);
+        assert_eq!(user.lock_remaining(now), None);
+        let event = user.record_login_failure(now, &policy).unwrap();
+        assert_eq!(event.kind, AccountLockEventKind::Locked);
+        assert_eq!(user.lock_remaining(now), Some(Duration::minutes(1)));
+        assert_eq!(user.lock_remaining(now + Duration::minutes(1)), None);
+
+        let later = now + Duration::minutes(1);
+        user.record_login_failure(later, &policy).unwrap();
+        assert_eq!(user.lock_remaining(later), Some(Duration::minutes(2)));
+
+        let admin_id = UserId::new(Uuid::new_v4()).unwrap();
+        let event = user.unlock(admin_id, later);
+        assert_eq!(event.kind, AccountLockEventKind::Unlocked);
+        assert_eq!(event.actor_id, Some(admin_id));
+        assert_eq!(user.failed_login_count, 0);
+        assert_eq!(user.lock_remaining(later), None);
+    }
 }
@@ -1,14 +1,32 @@
 mod default_user_factory;
 pub use default_user_factory::DefaultUserFactory;
 
-use crate::domain::{MailAddress, User, UserIdError, UserName};
+use async_trait::async_trait;
 
-pub trait UserFactory {
-    fn create(&self, name: UserName, mail_address: MailAddress) -> Result<User, UserFactoryError>;
+use crate::{
+    domain::{IdGeneratorError, MailAddress, PasswordHash, User, UserIdError, UserName},
+    repository::TransactionManager,
+};
+
+#[async_trait]
+pub trait UserFactory<TM>
+where
+    TM: TransactionManager,
+{
+    async fn create(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        name: UserName,
+        mail_address: MailAddress,
+        password_hash: PasswordHash,
+    ) -> Result<User, UserFactoryError>;
 }
 
-pub trait HasUserFactory {
-    type UserFactory: UserFactory;
+pub trait HasUserFactory<TM>
+where
+    TM: TransactionManager,
+{
+    type UserFactory: UserFactory<TM>;
     fn user_repository(&self) -> &Self::UserFactory;
 }
 
@@ -16,4 +34,6 @@ pub trait HasUserFactory {
 pub enum UserFactoryError {
     #[error(transparent)]
     UserIdError(#[from] UserIdError),
+    #[error(transparent)]
+    IdGeneratorError(#[from] IdGeneratorError),
 }
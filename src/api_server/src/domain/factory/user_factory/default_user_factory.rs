@@ -1,14 +1,70 @@
-use uuid::Uuid;
+use async_trait::async_trait;
 
-use crate::domain::{MailAddress, User, UserId, UserName};
+use crate::{
+    domain::{IdGenerator, MailAddress, PasswordHash, User, UserId, UserName, UuidV7IdGenerator},
+    repository::TransactionManager,
+};
 
 use super::{UserFactory, UserFactoryError};
 
 #[derive(Default, Clone)]
-pub struct DefaultUserFactory {}
+pub struct DefaultUserFactory<Generator = UuidV7IdGenerator> {
+    id_generator: Generator,
+}
+
+impl<Generator> DefaultUserFactory<Generator> {
+    pub fn new(id_generator: Generator) -> Self {
+        Self { id_generator }
+    }
+}
+
+#[async_trait]
+impl<TM, Generator> UserFactory<TM> for DefaultUserFactory<Generator>
+where
+    TM: TransactionManager,
+    Generator: IdGenerator<TM> + Send + Sync,
+{
+    async fn create(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        name: UserName,
+        mail_address: MailAddress,
+        password_hash: PasswordHash,
+    ) -> Result<User, UserFactoryError> {
+        Ok(User::new(
+            UserId::new(self.id_generator.generate(tx).await?)?,
+            name,
+            mail_address,
+            password_hash,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::SequentialIdGenerator, repository::NoopTransactionManager};
+
+    async fn create(factory: &DefaultUserFactory<SequentialIdGenerator>) -> User {
+        UserFactory::<NoopTransactionManager>::create(
+            factory,
+            &mut (),
+            UserName::new("hoge".to_string()).unwrap(),
+            MailAddress::new("hoge@example.com".to_string()).unwrap(),
+            PasswordHash::new(
+                "$argon2id$v=19$m=19456,t=2,p=1$c2FsdHNhbHRzYWx0$3nB0X2mK4d2n9JqJ0kq6mF2d3Qx3y8oVn8w0bq7fJ8Q".to_string(),
+            )
+            .unwrap(),
+        )
+        .await
+        .unwrap()
+    }
 
-impl UserFactory for DefaultUserFactory {
-    fn create(&self, name: UserName, mail_address: MailAddress) -> Result<User, UserFactoryError> {
-        Ok(User::new(UserId::new(Uuid::new_v4())?, name, mail_address))
+    #[tokio::test]
+    async fn uses_injected_id_generator() {
+        let factory = DefaultUserFactory::new(SequentialIdGenerator::new(1));
+        let first = create(&factory).await;
+        let second = create(&factory).await;
+        assert!(first.id.get() < second.id.get());
     }
 }
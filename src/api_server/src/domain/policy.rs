@@ -0,0 +1,85 @@
+mod api_key_policy;
+mod login_throttle_policy;
+mod password_reset_policy;
+mod user_name_policy;
+mod user_policy;
+
+pub use api_key_policy::*;
+pub use login_throttle_policy::*;
+pub use password_reset_policy::*;
+pub use user_name_policy::*;
+pub use user_policy::*;
+
+use crate::domain::{ApiKey, ApiKeyId, ApiKeyScope, Role, User, UserId};
+
+// NOTE: 操作を行う主体の識別子。ユーザーのほか、APIキーを用いるサービスも主体となる
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ActorId {
+    User(UserId),
+    ApiKey(ApiKeyId),
+}
+
+impl std::fmt::Display for ActorId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::User(user_id) => write!(f, "user:{user_id}"),
+            Self::ApiKey(api_key_id) => write!(f, "api_key:{api_key_id}"),
+        }
+    }
+}
+
+impl From<UserId> for ActorId {
+    fn from(value: UserId) -> Self {
+        Self::User(value)
+    }
+}
+
+// NOTE: 操作を行う主体。ポリシーはHTTPに依存せず、Actorと対象のみから判定する
+#[derive(Debug, Clone, PartialEq)]
+pub enum Actor {
+    User {
+        id: UserId,
+        role: Role,
+        mail_verified: bool,
+    },
+    // NOTE: APIキーはロールを持たず、操作できる範囲は付与されたスコープのみで決まる
+    ApiKey {
+        id: ApiKeyId,
+        scopes: Vec<ApiKeyScope>,
+    },
+}
+
+impl Actor {
+    pub fn is_admin(&self) -> bool {
+        matches!(
+            self,
+            Self::User {
+                role: Role::Admin,
+                ..
+            }
+        )
+    }
+
+    pub fn is_user(&self, user_id: &UserId) -> bool {
+        matches!(self, Self::User { id, .. } if id == user_id)
+    }
+}
+
+impl From<&User> for Actor {
+    fn from(value: &User) -> Self {
+        Self::User {
+            id: value.id,
+            role: value.role,
+            mail_verified: value.is_mail_verified(),
+        }
+    }
+}
+
+impl From<&ApiKey> for Actor {
+    fn from(value: &ApiKey) -> Self {
+        Self::ApiKey {
+            id: value.id,
+            scopes: value.scopes.clone(),
+        }
+    }
+}
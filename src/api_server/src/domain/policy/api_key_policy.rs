@@ -0,0 +1,63 @@
+use super::Actor;
+
+pub struct ApiKeyPolicy;
+
+impl ApiKeyPolicy {
+    // NOTE: APIキーの管理は管理者のユーザーのみ許可する
+    //       APIキーによるAPIキーの発行は、スコープを超えた権限の取得につながるため許可しない
+    pub fn authorize_management(actor: &Actor) -> Result<(), ApiKeyPolicyError> {
+        match actor {
+            Actor::User {
+                mail_verified: true,
+                ..
+            } if actor.is_admin() => Ok(()),
+            _ => Err(ApiKeyPolicyError::NotPermitted),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ApiKeyPolicyError {
+    #[error("APIキーの管理は管理者のみ行えます。")]
+    NotPermitted,
+    // NOTE: トークンの発行後に退会したユーザー等
+    #[error("操作を行うユーザーが存在しません。")]
+    ActorNotFound,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{ApiKeyId, ApiKeyScope, Role, UserId};
+    use rstest::rstest;
+    use uuid::Uuid;
+
+    #[rstest]
+    #[case(Role::User, true, Err(ApiKeyPolicyError::NotPermitted))]
+    #[case(Role::Admin, true, Ok(()))]
+    #[case(Role::Admin, false, Err(ApiKeyPolicyError::NotPermitted))]
+    fn user_actor(
+        #[case] role: Role,
+        #[case] mail_verified: bool,
+        #[case] expected: Result<(), ApiKeyPolicyError>,
+    ) {
+        let actor = Actor::User {
+            id: UserId::new(Uuid::new_v4()).unwrap(),
+            role,
+            mail_verified,
+        };
+        assert_eq!(ApiKeyPolicy::authorize_management(&actor), expected);
+    }
+
+    #[rstest]
+    fn api_key_actor() {
+        let actor = Actor::ApiKey {
+            id: ApiKeyId::new(Uuid::new_v4()).unwrap(),
+            scopes: vec![ApiKeyScope::UsersRead, ApiKeyScope::UsersWrite],
+        };
+        assert_eq!(
+            ApiKeyPolicy::authorize_management(&actor),
+            Err(ApiKeyPolicyError::NotPermitted)
+        );
+    }
+}
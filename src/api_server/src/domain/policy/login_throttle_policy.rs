@@ -0,0 +1,55 @@
+use chrono::Duration;
+
+// NOTE: ログイン失敗時の待機時間。閾値に達した後は失敗の度に倍になる
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoginThrottlePolicy {
+    pub account_threshold: u32,
+    pub ip_address_threshold: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    // NOTE: IPアドレスごとの失敗回数は、最後の失敗からこの期間が経過するとリセットされる
+    pub ip_address_window: Duration,
+}
+
+impl LoginThrottlePolicy {
+    pub fn account_lockout(&self, failed_count: u32) -> Option<Duration> {
+        self.backoff(failed_count, self.account_threshold)
+    }
+
+    pub fn ip_address_backoff(&self, failed_count: u32) -> Option<Duration> {
+        self.backoff(failed_count, self.ip_address_threshold)
+    }
+
+    fn backoff(&self, failed_count: u32, threshold: u32) -> Option<Duration> {
+        let exponent = failed_count.checked_sub(threshold)?;
+        let delay = 2_i32
+            .checked_pow(exponent)
+            .and_then(|factor| self.base_delay.checked_mul(factor))
+            .unwrap_or(self.max_delay);
+        Some(delay.min(self.max_delay))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(4, None)]
+    #[case(5, Some(Duration::seconds(30)))]
+    #[case(6, Some(Duration::seconds(60)))]
+    #[case(8, Some(Duration::seconds(240)))]
+    #[case(20, Some(Duration::hours(1)))]
+    #[case(u32::MAX, Some(Duration::hours(1)))]
+    fn account_lockout(#[case] failed_count: u32, #[case] expected: Option<Duration>) {
+        let policy = LoginThrottlePolicy {
+            account_threshold: 5,
+            ip_address_threshold: 20,
+            base_delay: Duration::seconds(30),
+            max_delay: Duration::hours(1),
+            ip_address_window: Duration::hours(1),
+        };
+        assert_eq!(policy.account_lockout(failed_count), expected);
+    }
+}
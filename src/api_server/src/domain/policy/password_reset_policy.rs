@@ -0,0 +1,62 @@
+use chrono::Duration;
+
+// NOTE: 直近の期間内に行われた再設定の申請数
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PasswordResetRequestCounts {
+    pub by_mail_address: u32,
+    pub by_ip_address: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PasswordResetRateLimit {
+    pub window: Duration,
+    pub max_per_mail_address: u32,
+    pub max_per_ip_address: u32,
+}
+
+impl PasswordResetRateLimit {
+    // NOTE: アドレスの存在有無に関わらず同じ判定を行い、制限の有無から存在を推測できないようにする
+    pub fn check(
+        &self,
+        counts: PasswordResetRequestCounts,
+    ) -> Result<(), PasswordResetPolicyError> {
+        if counts.by_mail_address >= self.max_per_mail_address
+            || counts.by_ip_address >= self.max_per_ip_address
+        {
+            return Err(PasswordResetPolicyError::TooManyRequests {
+                retry_after: self.window,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum PasswordResetPolicyError {
+    #[error("パスワード再設定の申請が多すぎます。しばらく時間をおいてから再度お試しください。")]
+    TooManyRequests { retry_after: Duration },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(0, 0, true)]
+    #[case(2, 9, true)]
+    #[case(3, 0, false)]
+    #[case(0, 10, false)]
+    fn check(#[case] by_mail_address: u32, #[case] by_ip_address: u32, #[case] allowed: bool) {
+        let limit = PasswordResetRateLimit {
+            window: Duration::hours(1),
+            max_per_mail_address: 3,
+            max_per_ip_address: 10,
+        };
+        let counts = PasswordResetRequestCounts {
+            by_mail_address,
+            by_ip_address,
+        };
+        assert_eq!(limit.check(counts).is_ok(), allowed);
+    }
+}
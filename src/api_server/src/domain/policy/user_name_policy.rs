@@ -0,0 +1,169 @@
+use std::collections::HashSet;
+
+use unicode_normalization::UnicodeNormalization;
+use unicode_security::confusable_detection::skeleton;
+
+use crate::domain::{UserName, ValidationError};
+
+// NOTE: 見た目が紛らわしい文字を同じ文字に寄せた比較用の文字列(UTS #39のskeleton)
+//       全角・半角と大文字・小文字は区別しないため、NFKCで正規化して小文字にしてから変換する
+//       変換後の文字は大文字の場合がある(0→O)ため、最後にもう一度小文字にする
+//       キリル文字のаを含む"аdmin"も"admin"と同じskeletonになる
+pub fn user_name_skeleton(name: &str) -> String {
+    let folded = name.nfkc().collect::<String>().to_lowercase();
+    skeleton(&folded).collect::<String>().to_lowercase()
+}
+
+// NOTE: ユーザー名として使用できない語句の判定
+//       予約語はskeletonの完全一致、禁止語句はskeletonの部分一致で判定する
+//       既存ユーザーとの重複はDBを参照するためUserServiceで判定する
+#[derive(Debug, Clone)]
+pub struct UserNamePolicy {
+    reserved: HashSet<String>,
+    blocked: Vec<String>,
+}
+
+impl UserNamePolicy {
+    pub const DEFAULT_RESERVED_WORDS: &'static [&'static str] = &[
+        "admin",
+        "administrator",
+        "root",
+        "system",
+        "support",
+        "staff",
+        "moderator",
+        "official",
+        "security",
+        "api",
+        "null",
+        "undefined",
+        "anonymous",
+        "postmaster",
+        "webmaster",
+        "hostmaster",
+        "abuse",
+        "noreply",
+    ];
+
+    // NOTE: 予約語は組み込みの語句に追加する
+    pub fn new<R, B>(reserved_words: R, blocked_words: B) -> Self
+    where
+        R: IntoIterator,
+        R::Item: AsRef<str>,
+        B: IntoIterator,
+        B::Item: AsRef<str>,
+    {
+        let reserved = Self::DEFAULT_RESERVED_WORDS
+            .iter()
+            .map(|word| user_name_skeleton(word))
+            .chain(
+                reserved_words
+                    .into_iter()
+                    .map(|word| user_name_skeleton(word.as_ref().trim())),
+            )
+            .filter(|word| !word.is_empty())
+            .collect();
+        let blocked = blocked_words
+            .into_iter()
+            .map(|word| user_name_skeleton(word.as_ref().trim()))
+            .filter(|word| !word.is_empty())
+            .collect();
+        Self { reserved, blocked }
+    }
+
+    // NOTE: 1行に1語。空行と#で始まる行は無視する
+    pub fn parse_blocklist(content: &str) -> Vec<&str> {
+        content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .collect()
+    }
+
+    pub fn check(&self, name: UserName) -> Result<UserName, UserNamePolicyError> {
+        let skeleton = user_name_skeleton(name.get());
+        if self.reserved.contains(&skeleton) {
+            return Err(UserNamePolicyError::Reserved);
+        }
+        if self.blocked.iter().any(|word| skeleton.contains(word)) {
+            return Err(UserNamePolicyError::Blocked);
+        }
+        Ok(name)
+    }
+}
+
+impl Default for UserNamePolicy {
+    fn default() -> Self {
+        Self::new(std::iter::empty::<&str>(), std::iter::empty::<&str>())
+    }
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum UserNamePolicyError {
+    #[error("このユーザー名は予約されているため使用できません。")]
+    Reserved,
+    #[error("使用できない語句が含まれています。")]
+    Blocked,
+    // NOTE: 完全に同じ名前も紛らわしい名前に含める
+    #[error("既存のユーザー名と同じか紛らわしいため使用できません。")]
+    Confusable,
+}
+
+impl ValidationError for UserNamePolicyError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Reserved => "reserved",
+            Self::Blocked => "blocked",
+            Self::Confusable => "confusable",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn policy() -> UserNamePolicy {
+        UserNamePolicy::new(["operator"], ["badword"])
+    }
+
+    #[rstest]
+    #[case("admin", "admin")]
+    #[case("ADMIN", "admin")]
+    // NOTE: キリル文字のа(U+0430)
+    #[case("\u{0430}dmin", "admin")]
+    // NOTE: 全角
+    #[case("ａｄｍｉｎ", "admin")]
+    #[case("paypa1", "paypal")]
+    fn skeleton_matches(#[case] name: &str, #[case] other: &str) {
+        assert_eq!(user_name_skeleton(name), user_name_skeleton(other));
+    }
+
+    #[rstest]
+    #[case("alice", "bob")]
+    #[case("やまだ", "山田")]
+    fn skeleton_differs(#[case] name: &str, #[case] other: &str) {
+        assert_ne!(user_name_skeleton(name), user_name_skeleton(other));
+    }
+
+    #[rstest]
+    #[case("hoge", Ok(()))]
+    #[case("administrators", Ok(()))]
+    #[case("Admin", Err(UserNamePolicyError::Reserved))]
+    #[case("\u{0430}dmin", Err(UserNamePolicyError::Reserved))]
+    #[case("r00t", Err(UserNamePolicyError::Reserved))]
+    #[case("operator", Err(UserNamePolicyError::Reserved))]
+    #[case("my_badword_1", Err(UserNamePolicyError::Blocked))]
+    #[case("BADW0RD", Err(UserNamePolicyError::Blocked))]
+    fn check(#[case] name: &str, #[case] expected: Result<(), UserNamePolicyError>) {
+        let name = UserName::new(name.to_string()).unwrap();
+        assert_eq!(policy().check(name).map(|_| ()), expected);
+    }
+
+    #[rstest]
+    fn parse_blocklist() {
+        let content = "# comment\nfoo\n\n  bar  \n#baz\n";
+        assert_eq!(UserNamePolicy::parse_blocklist(content), ["foo", "bar"]);
+    }
+}
@@ -0,0 +1,198 @@
+use crate::domain::{ApiKeyScope, UserId};
+
+use super::Actor;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserAction {
+    Read,
+    Update,
+    Delete,
+    Unlock,
+    Suspend,
+}
+
+impl std::fmt::Display for UserAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Read => write!(f, "ユーザー情報の参照"),
+            Self::Update => write!(f, "ユーザー情報の更新"),
+            Self::Delete => write!(f, "ユーザーの削除"),
+            Self::Unlock => write!(f, "アカウントのロック解除"),
+            Self::Suspend => write!(f, "アカウントの利用停止"),
+        }
+    }
+}
+
+pub struct UserPolicy;
+
+impl UserPolicy {
+    // NOTE: 管理者以外は自分自身に対する操作のみ許可する
+    //       メールアドレスが未確認の場合は参照と退会のみ許可する
+    //       ロック解除・利用停止は管理者のみ許可する
+    //       APIキーはスコープごとに許可された操作のみ行え、管理者のみの操作は行えない
+    pub fn authorize(
+        actor: &Actor,
+        action: UserAction,
+        target: &UserId,
+    ) -> Result<(), UserPolicyError> {
+        let permitted = match actor {
+            Actor::User { mail_verified, .. } => {
+                if !mail_verified && !matches!(action, UserAction::Read | UserAction::Delete) {
+                    return Err(UserPolicyError::MailNotVerified { action });
+                }
+                let admin_only = matches!(action, UserAction::Unlock | UserAction::Suspend);
+                actor.is_admin() || (!admin_only && actor.is_user(target))
+            }
+            Actor::ApiKey { scopes, .. } => scopes
+                .iter()
+                .any(|scope| Self::scope_actions(*scope).contains(&action)),
+        };
+        if permitted {
+            Ok(())
+        } else {
+            Err(UserPolicyError::NotPermitted { action })
+        }
+    }
+
+    fn scope_actions(scope: ApiKeyScope) -> &'static [UserAction] {
+        match scope {
+            ApiKeyScope::UsersRead => &[UserAction::Read],
+            ApiKeyScope::UsersWrite => &[UserAction::Read, UserAction::Update, UserAction::Delete],
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum UserPolicyError {
+    #[error("{action}を行う権限がありません。")]
+    NotPermitted { action: UserAction },
+    #[error("メールアドレスの確認が完了するまで{action}は行えません。")]
+    MailNotVerified { action: UserAction },
+    // NOTE: トークンの発行後に退会したユーザー等
+    #[error("操作を行うユーザーが存在しません。")]
+    ActorNotFound,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{ApiKeyId, Role};
+    use rstest::rstest;
+    use uuid::Uuid;
+
+    fn actor(role: Role) -> Actor {
+        Actor::User {
+            id: UserId::new(Uuid::new_v4()).unwrap(),
+            role,
+            mail_verified: true,
+        }
+    }
+
+    fn user_id(actor: &Actor) -> UserId {
+        match actor {
+            Actor::User { id, .. } => *id,
+            Actor::ApiKey { .. } => unreachable!(),
+        }
+    }
+
+    #[rstest]
+    #[case(Role::User, true, Ok(()))]
+    #[case(Role::User, false, Err(UserPolicyError::NotPermitted { action: UserAction::Update }))]
+    #[case(Role::Admin, true, Ok(()))]
+    #[case(Role::Admin, false, Ok(()))]
+    fn authorize(
+        #[case] role: Role,
+        #[case] is_self: bool,
+        #[case] expected: Result<(), UserPolicyError>,
+        #[values(UserAction::Read, UserAction::Update, UserAction::Delete)] action: UserAction,
+    ) {
+        let actor = actor(role);
+        let target = if is_self {
+            user_id(&actor)
+        } else {
+            UserId::new(Uuid::new_v4()).unwrap()
+        };
+        let expected = expected.map_err(|_| UserPolicyError::NotPermitted { action });
+        assert_eq!(UserPolicy::authorize(&actor, action, &target), expected);
+    }
+
+    #[rstest]
+    #[case(UserAction::Read, Ok(()))]
+    #[case(UserAction::Update, Err(UserPolicyError::MailNotVerified { action: UserAction::Update }))]
+    #[case(UserAction::Delete, Ok(()))]
+    fn unverified_actor(
+        #[case] action: UserAction,
+        #[case] expected: Result<(), UserPolicyError>,
+        #[values(Role::User, Role::Admin)] role: Role,
+    ) {
+        let actor = Actor::User {
+            id: UserId::new(Uuid::new_v4()).unwrap(),
+            role,
+            mail_verified: false,
+        };
+        assert_eq!(
+            UserPolicy::authorize(&actor, action, &user_id(&actor)),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(Role::User, false)]
+    #[case(Role::Admin, true)]
+    fn admin_only_actions(
+        #[case] role: Role,
+        #[case] permitted: bool,
+        #[values(UserAction::Unlock, UserAction::Suspend)] action: UserAction,
+    ) {
+        let actor = actor(role);
+        let expected = if permitted {
+            Ok(())
+        } else {
+            Err(UserPolicyError::NotPermitted { action })
+        };
+        assert_eq!(
+            UserPolicy::authorize(&actor, action, &user_id(&actor)),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(ApiKeyScope::UsersRead, UserAction::Read, true)]
+    #[case(ApiKeyScope::UsersRead, UserAction::Update, false)]
+    #[case(ApiKeyScope::UsersRead, UserAction::Delete, false)]
+    #[case(ApiKeyScope::UsersWrite, UserAction::Read, true)]
+    #[case(ApiKeyScope::UsersWrite, UserAction::Update, true)]
+    #[case(ApiKeyScope::UsersWrite, UserAction::Delete, true)]
+    fn api_key_actor_is_limited_to_its_scopes(
+        #[case] scope: ApiKeyScope,
+        #[case] action: UserAction,
+        #[case] permitted: bool,
+    ) {
+        let actor = Actor::ApiKey {
+            id: ApiKeyId::new(Uuid::new_v4()).unwrap(),
+            scopes: vec![scope],
+        };
+        let target = UserId::new(Uuid::new_v4()).unwrap();
+        let expected = if permitted {
+            Ok(())
+        } else {
+            Err(UserPolicyError::NotPermitted { action })
+        };
+        assert_eq!(UserPolicy::authorize(&actor, action, &target), expected);
+    }
+
+    #[rstest]
+    fn api_key_actor_cannot_perform_admin_only_actions(
+        #[values(UserAction::Unlock, UserAction::Suspend)] action: UserAction,
+    ) {
+        let actor = Actor::ApiKey {
+            id: ApiKeyId::new(Uuid::new_v4()).unwrap(),
+            scopes: vec![ApiKeyScope::UsersRead, ApiKeyScope::UsersWrite],
+        };
+        let target = UserId::new(Uuid::new_v4()).unwrap();
+        assert_eq!(
+            UserPolicy::authorize(&actor, action, &target),
+            Err(UserPolicyError::NotPermitted { action })
+        );
+    }
+}
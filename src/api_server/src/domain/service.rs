@@ -1,3 +1,13 @@
+mod access_token_codec;
+mod clock;
+mod id_generator;
+mod password_hasher;
+mod totp;
 mod user_service;
 
+pub use access_token_codec::*;
+pub use clock::*;
+pub use id_generator::*;
+pub use password_hasher::*;
+pub use totp::*;
 pub use user_service::*;
@@ -0,0 +1,41 @@
+mod jwt_access_token_codec;
+pub use jwt_access_token_codec::{JwtAccessTokenCodec, JwtKeys};
+
+use chrono::{DateTime, Utc};
+
+use crate::domain::UserId;
+
+// NOTE: 署名付きのアクセストークンの発行と検証
+//       検証にDBを参照しないため、失効はリフレッシュトークン側で行う
+pub trait AccessTokenCodec {
+    fn encode(
+        &self,
+        user_id: &UserId,
+        now: DateTime<Utc>,
+    ) -> Result<IssuedAccessToken, AccessTokenError>;
+    fn decode(&self, token: &str) -> Result<AccessTokenClaims, AccessTokenError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct IssuedAccessToken {
+    pub token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessTokenClaims {
+    pub user_id: UserId,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AccessTokenError {
+    #[error("アクセストークンの発行に失敗しました。{0}")]
+    EncodeError(String),
+    #[error("アクセストークンの鍵が不正です。{0}")]
+    InvalidKey(String),
+    #[error("アクセストークンの有効期限が切れています。")]
+    Expired,
+    #[error("アクセストークンが不正です。{0}")]
+    Invalid(String),
+}
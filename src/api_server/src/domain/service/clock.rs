@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+
+// NOTE: 現在時刻の取得を差し替え可能にし、TOTPの時間窓等を固定の時刻でテストできるようにする
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+// NOTE: テスト用。常に同じ時刻を返す
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(pub DateTime<Utc>);
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
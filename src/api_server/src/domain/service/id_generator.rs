@@ -0,0 +1,161 @@
+#[cfg(test)]
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use ulid::Ulid;
+use uuid::Uuid;
+
+use crate::repository::{database_error::DatabaseError, TransactionManager};
+
+// NOTE: エンティティのIDの採番方法を差し替え可能にする
+//       v4のようなランダムな値は主キーのインデックスを断片化させるため、時刻順に並ぶ値を使う
+//       DBの連番を使う実装もあるため、ユースケースのトランザクションを受け取る
+#[async_trait]
+pub trait IdGenerator<TM>
+where
+    TM: TransactionManager,
+{
+    async fn generate(&self, tx: &mut TM::Transaction<'_>) -> Result<Uuid, IdGeneratorError>;
+}
+
+#[async_trait]
+impl<TM, T> IdGenerator<TM> for Arc<T>
+where
+    TM: TransactionManager,
+    T: IdGenerator<TM> + Send + Sync + ?Sized,
+{
+    async fn generate(&self, tx: &mut TM::Transaction<'_>) -> Result<Uuid, IdGeneratorError> {
+        (**self).generate(tx).await
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UuidV7IdGenerator;
+
+#[async_trait]
+impl<TM> IdGenerator<TM> for UuidV7IdGenerator
+where
+    TM: TransactionManager,
+{
+    async fn generate(&self, _tx: &mut TM::Transaction<'_>) -> Result<Uuid, IdGeneratorError> {
+        Ok(Uuid::now_v7())
+    }
+}
+
+// NOTE: ULIDもUUIDと同じ128bitのため、UUIDとして保存する
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UlidIdGenerator;
+
+#[async_trait]
+impl<TM> IdGenerator<TM> for UlidIdGenerator
+where
+    TM: TransactionManager,
+{
+    async fn generate(&self, _tx: &mut TM::Transaction<'_>) -> Result<Uuid, IdGeneratorError> {
+        Ok(Uuid::from_u128(Ulid::new().into()))
+    }
+}
+
+// NOTE: 連番はUUIDv7のタイムスタンプと同じ先頭48bitに格納し、UUIDとしての並び順を連番の順と一致させる
+//       残りのbitは0とし、version 8(独自形式)として扱う
+pub const MAX_SEQUENCE: i64 = (1 << 48) - 1;
+
+pub fn uuid_from_sequence(sequence: i64) -> Result<Uuid, IdGeneratorError> {
+    if !(1..=MAX_SEQUENCE).contains(&sequence) {
+        return Err(IdGeneratorError::SequenceOutOfRange(sequence));
+    }
+    let mut bytes = [0; 16];
+    bytes[..6].copy_from_slice(&sequence.to_be_bytes()[2..]);
+    Ok(uuid::Builder::from_custom_bytes(bytes).into_uuid())
+}
+
+// NOTE: テスト用。startから順に、連番と同じ形式のIDを返す
+#[cfg(test)]
+#[derive(Debug)]
+pub struct SequentialIdGenerator {
+    next: AtomicU64,
+}
+
+#[cfg(test)]
+impl SequentialIdGenerator {
+    pub fn new(start: u64) -> Self {
+        Self {
+            next: AtomicU64::new(start),
+        }
+    }
+}
+
+#[cfg(test)]
+#[async_trait]
+impl<TM> IdGenerator<TM> for SequentialIdGenerator
+where
+    TM: TransactionManager,
+{
+    async fn generate(&self, _tx: &mut TM::Transaction<'_>) -> Result<Uuid, IdGeneratorError> {
+        let sequence = self.next.fetch_add(1, Ordering::Relaxed);
+        uuid_from_sequence(i64::try_from(sequence).unwrap_or(i64::MAX))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum IdGeneratorError {
+    #[error("連番が範囲外です。{0}")]
+    SequenceOutOfRange(i64),
+    #[error("連番の取得に失敗しました。")]
+    SequenceError(#[from] DatabaseError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::NoopTransactionManager;
+    use rstest::rstest;
+
+    async fn generate(generator: &impl IdGenerator<NoopTransactionManager>) -> Uuid {
+        generator.generate(&mut ()).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn uuid_v7() {
+        let id = generate(&UuidV7IdGenerator).await;
+        assert_eq!(id.get_version_num(), 7);
+    }
+
+    #[tokio::test]
+    async fn ulid_keeps_bits() {
+        let id = generate(&UlidIdGenerator).await;
+        let ulid = Ulid::from(id.as_u128());
+        assert_eq!(Uuid::from_u128(ulid.into()), id);
+    }
+
+    #[rstest]
+    #[case(1, "00000000-0001-8000-8000-000000000000")]
+    #[case(MAX_SEQUENCE, "ffffffff-ffff-8000-8000-000000000000")]
+    fn sequence_layout(#[case] sequence: i64, #[case] expected: &str) {
+        assert_eq!(
+            uuid_from_sequence(sequence).unwrap(),
+            Uuid::parse_str(expected).unwrap()
+        );
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(-1)]
+    #[case(MAX_SEQUENCE + 1)]
+    fn sequence_out_of_range(#[case] sequence: i64) {
+        assert!(matches!(
+            uuid_from_sequence(sequence),
+            Err(IdGeneratorError::SequenceOutOfRange(s)) if s == sequence
+        ));
+    }
+
+    #[tokio::test]
+    async fn sequential_ids_are_ordered() {
+        let generator = SequentialIdGenerator::new(255);
+        let first = generate(&generator).await;
+        let second = generate(&generator).await;
+        assert_eq!(first, uuid_from_sequence(255).unwrap());
+        assert!(first < second);
+    }
+}
@@ -0,0 +1,33 @@
+mod argon2_password_hasher;
+pub use argon2_password_hasher::{Argon2Params, Argon2PasswordHasher};
+
+use async_trait::async_trait;
+
+use crate::domain::{Password, PasswordHash, PasswordHashError};
+
+#[async_trait]
+pub trait PasswordHasher {
+    async fn hash(&self, password: &Password) -> Result<PasswordHash, PasswordHasherError>;
+    // NOTE: hashがNone(ユーザーが存在しない等)の場合もダミーのハッシュで検証を行い、
+    //       応答時間からユーザーの存在が推測されないようにする
+    async fn verify(
+        &self,
+        password: &Password,
+        hash: Option<&PasswordHash>,
+    ) -> Result<PasswordVerification, PasswordHasherError>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasswordVerification {
+    // NOTE: needs_rehashはハッシュのパラメータが現在の設定と異なることを示す
+    Valid { needs_rehash: bool },
+    Invalid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PasswordHasherError {
+    #[error("パスワードのハッシュ化に失敗しました。{0}")]
+    HashError(String),
+    #[error(transparent)]
+    PasswordHashError(#[from] PasswordHashError),
+}
@@ -0,0 +1,189 @@
+use std::sync::Arc;
+
+use argon2::{
+    password_hash::{self, rand_core::OsRng, SaltString},
+    Algorithm, Argon2, Params, PasswordHasher as _, PasswordVerifier as _, Version,
+};
+use async_trait::async_trait;
+
+use crate::domain::{Password, PasswordHash};
+
+use super::{PasswordHasher, PasswordHasherError, PasswordVerification};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Argon2Params {
+    pub memory_kib: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+}
+
+#[derive(Clone)]
+pub struct Argon2PasswordHasher {
+    params: Params,
+    dummy_hash: Arc<String>,
+}
+
+impl Argon2PasswordHasher {
+    pub fn new(params: Argon2Params) -> Result<Self, PasswordHasherError> {
+        let params = Params::new(
+            params.memory_kib,
+            params.iterations,
+            params.parallelism,
+            None,
+        )
+        .map_err(|e| PasswordHasherError::HashError(e.to_string()))?;
+        let dummy_hash = hash_blocking(&params, "dummy-password-for-timing")?;
+        Ok(Self {
+            params,
+            dummy_hash: Arc::new(dummy_hash),
+        })
+    }
+}
+
+fn argon2(params: &Params) -> Argon2<'static> {
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params.clone())
+}
+
+fn hash_blocking(params: &Params, password: &str) -> Result<String, PasswordHasherError> {
+    let salt = SaltString::generate(&mut OsRng);
+    argon2(params)
+        .hash_password(password.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|e| PasswordHasherError::HashError(e.to_string()))
+}
+
+fn verify_blocking(
+    params: &Params,
+    password: &str,
+    hash: &str,
+) -> Result<PasswordVerification, PasswordHasherError> {
+    let parsed = password_hash::PasswordHash::new(hash)
+        .map_err(|e| PasswordHasherError::HashError(e.to_string()))?;
+    match argon2(params).verify_password(password.as_bytes(), &parsed) {
+        Ok(()) => Ok(PasswordVerification::Valid {
+            needs_rehash: needs_rehash(params, &parsed),
+        }),
+        Err(password_hash::Error::Password) => Ok(PasswordVerification::Invalid),
+        Err(e) => Err(PasswordHasherError::HashError(e.to_string())),
+    }
+}
+
+fn needs_rehash(params: &Params, hash: &password_hash::PasswordHash<'_>) -> bool {
+    if hash.algorithm != Algorithm::Argon2id.ident() || hash.version != Some(Version::V0x13.into())
+    {
+        return true;
+    }
+    match Params::try_from(hash) {
+        Ok(current) => {
+            current.m_cost() != params.m_cost()
+                || current.t_cost() != params.t_cost()
+                || current.p_cost() != params.p_cost()
+        }
+        Err(_) => true,
+    }
+}
+
+// NOTE: Argon2はCPU負荷が高いため、非同期ランタイムをブロックしないよう別スレッドで実行する
+async fn spawn_blocking<T, F>(f: F) -> Result<T, PasswordHasherError>
+where
+    F: FnOnce() -> Result<T, PasswordHasherError> + Send + 'static,
+    T: Send + 'static,
+{
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|e| PasswordHasherError::HashError(e.to_string()))?
+}
+
+#[async_trait]
+impl PasswordHasher for Argon2PasswordHasher {
+    async fn hash(&self, password: &Password) -> Result<PasswordHash, PasswordHasherError> {
+        let params = self.params.clone();
+        let password = password.get().to_string();
+        let hash = spawn_blocking(move || hash_blocking(&params, &password)).await?;
+        Ok(PasswordHash::new(hash)?)
+    }
+
+    async fn verify(
+        &self,
+        password: &Password,
+        hash: Option<&PasswordHash>,
+    ) -> Result<PasswordVerification, PasswordHasherError> {
+        let params = self.params.clone();
+        let password = password.get().to_string();
+        let exists = hash.is_some();
+        let hash = hash
+            .map(|hash| hash.get().to_string())
+            .unwrap_or_else(|| self.dummy_hash.to_string());
+        let verification =
+            spawn_blocking(move || verify_blocking(&params, &password, &hash)).await?;
+        Ok(if exists {
+            verification
+        } else {
+            PasswordVerification::Invalid
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    // NOTE: テストを高速にするため最小のパラメータを使用する
+    const PARAMS: Argon2Params = Argon2Params {
+        memory_kib: 8,
+        iterations: 1,
+        parallelism: 1,
+    };
+
+    fn password(value: &str) -> Password {
+        Password::new(value.to_string()).unwrap()
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn hash_and_verify() {
+        let hasher = Argon2PasswordHasher::new(PARAMS).unwrap();
+        let hash = hasher.hash(&password("passw0rd!")).await.unwrap();
+
+        assert_eq!(
+            hasher
+                .verify(&password("passw0rd!"), Some(&hash))
+                .await
+                .unwrap(),
+            PasswordVerification::Valid {
+                needs_rehash: false
+            }
+        );
+        assert_eq!(
+            hasher
+                .verify(&password("wrong-passw0rd"), Some(&hash))
+                .await
+                .unwrap(),
+            PasswordVerification::Invalid
+        );
+        assert_eq!(
+            hasher.verify(&password("passw0rd!"), None).await.unwrap(),
+            PasswordVerification::Invalid
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn detects_changed_params() {
+        let old = Argon2PasswordHasher::new(PARAMS).unwrap();
+        let hash = old.hash(&password("passw0rd!")).await.unwrap();
+        let new = Argon2PasswordHasher::new(Argon2Params {
+            iterations: 2,
+            ..PARAMS
+        })
+        .unwrap();
+
+        assert_eq!(
+            new.verify(&password("passw0rd!"), Some(&hash))
+                .await
+                .unwrap(),
+            PasswordVerification::Valid { needs_rehash: true }
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac as _};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use sha1::Sha1;
+
+use crate::domain::TotpSecret;
+
+// NOTE: RFC 3986の非予約文字以外をエスケープする
+const URI_COMPONENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+// NOTE: RFC 6238のTOTP(HMAC-SHA1)。多くの認証アプリが対応している既定値を使用する
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Totp {
+    pub step_secs: i64,
+    pub digits: u32,
+    // NOTE: 端末の時計のずれを許容する前後のステップ数
+    pub skew_steps: i64,
+}
+
+impl Default for Totp {
+    fn default() -> Self {
+        Self {
+            step_secs: 30,
+            digits: 6,
+            skew_steps: 1,
+        }
+    }
+}
+
+impl Totp {
+    pub fn step_at(&self, at: DateTime<Utc>) -> i64 {
+        at.timestamp().div_euclid(self.step_secs)
+    }
+
+    pub fn code_at(&self, secret: &TotpSecret, step: i64) -> String {
+        let mut mac =
+            Hmac::<Sha1>::new_from_slice(secret.get()).expect("HMAC accepts keys of any length");
+        mac.update(&step.to_be_bytes());
+        let digest = mac.finalize().into_bytes();
+        // NOTE: RFC 4226 5.3 Dynamic Truncation
+        let offset = (digest[digest.len() - 1] & 0x0f) as usize;
+        let binary = u32::from_be_bytes([
+            digest[offset] & 0x7f,
+            digest[offset + 1],
+            digest[offset + 2],
+            digest[offset + 3],
+        ]);
+        let code = binary % 10_u32.pow(self.digits);
+        format!("{code:0width$}", width = self.digits as usize)
+    }
+
+    // NOTE: 一致したステップを返す。リプレイを防ぐため、最後に使用したステップ以前のコードは受け付けない
+    pub fn verify(
+        &self,
+        secret: &TotpSecret,
+        code: &str,
+        now: DateTime<Utc>,
+        last_used_step: Option<i64>,
+    ) -> Option<i64> {
+        let current = self.step_at(now);
+        (current - self.skew_steps..=current + self.skew_steps)
+            .filter(|step| last_used_step.is_none_or(|last| *step > last))
+            .find(|step| constant_time_eq(self.code_at(secret, *step).as_bytes(), code.as_bytes()))
+    }
+
+    // NOTE: 認証アプリにQRコードで読み込ませるためのURI
+    //       https://github.com/google/google-authenticator/wiki/Key-Uri-Format
+    pub fn otpauth_uri(&self, secret: &TotpSecret, issuer: &str, account_name: &str) -> String {
+        let issuer = utf8_percent_encode(issuer, URI_COMPONENT).to_string();
+        let account_name = utf8_percent_encode(account_name, URI_COMPONENT);
+        format!(
+            "otpauth://totp/{issuer}:{account_name}?secret={}&issuer={issuer}&algorithm=SHA1&digits={}&period={}",
+            secret.to_base32(),
+            self.digits,
+            self.step_secs,
+        )
+    }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Clock as _, FixedClock};
+    use rstest::rstest;
+
+    fn rfc_secret() -> TotpSecret {
+        TotpSecret::new(b"12345678901234567890".to_vec()).unwrap()
+    }
+
+    // NOTE: RFC 6238 Appendix B のテストベクタ(SHA1)
+    #[rstest]
+    #[case(59, "94287082")]
+    #[case(1111111109, "07081804")]
+    #[case(1111111111, "14050471")]
+    #[case(1234567890, "89005924")]
+    #[case(2000000000, "69279037")]
+    #[case(20000000000, "65353130")]
+    fn rfc6238_vectors(#[case] timestamp: i64, #[case] expected: &str) {
+        let totp = Totp {
+            digits: 8,
+            ..Totp::default()
+        };
+        let clock = FixedClock(DateTime::from_timestamp(timestamp, 0).unwrap());
+        assert_eq!(
+            totp.code_at(&rfc_secret(), totp.step_at(clock.now())),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(-30, true)]
+    #[case(0, true)]
+    #[case(30, true)]
+    #[case(-60, false)]
+    #[case(60, false)]
+    fn verify_within_skew(#[case] drift_secs: i64, #[case] accepted: bool) {
+        let totp = Totp::default();
+        let secret = TotpSecret::generate();
+        let clock = FixedClock(DateTime::from_timestamp(1_700_000_010, 0).unwrap());
+        let device_time = clock.now() + chrono::Duration::seconds(drift_secs);
+        let code = totp.code_at(&secret, totp.step_at(device_time));
+        assert_eq!(
+            totp.verify(&secret, &code, clock.now(), None).is_some(),
+            accepted
+        );
+    }
+
+    #[rstest]
+    fn reject_replay() {
+        let totp = Totp::default();
+        let secret = TotpSecret::generate();
+        let now = DateTime::from_timestamp(1_700_000_010, 0).unwrap();
+        let code = totp.code_at(&secret, totp.step_at(now));
+        let step = totp.verify(&secret, &code, now, None).unwrap();
+        assert_eq!(totp.verify(&secret, &code, now, Some(step)), None);
+    }
+
+    #[rstest]
+    fn otpauth_uri() {
+        let uri = Totp::default().otpauth_uri(&rfc_secret(), "api server", "hoge@example.com");
+        assert_eq!(
+            uri,
+            "otpauth://totp/api%20server:hoge%40example.com?secret=GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ&issuer=api%20server&algorithm=SHA1&digits=6&period=30"
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
-    domain::User,
-    repository::{TransactionManager, UserRepository, UserRepositoryError},
+    domain::{user_name_skeleton, MailAddress, User, UserId, UserName},
+    repository::{TransactionManager, UserRepository, UserRepositoryError, UserStatusFilter},
 };
 
 #[derive(Clone)]
@@ -28,10 +28,40 @@ where
     ) -> Result<bool, UserServiceError> {
         let duplicated_user = self
             .user_repository
-            .find_by_mail_address(tx, &user.mail_address)
+            .find_by_mail_address(tx, &user.mail_address, UserStatusFilter::All)
             .await?;
         Ok(duplicated_user.is_some())
     }
+
+    // NOTE: 退会済みを含め、他のユーザーがアドレスを使用しているか
+    //       アドレスを変更する場合は、変更するユーザー自身をexceptに指定する
+    pub async fn mail_address_taken(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        mail_address: &MailAddress,
+        except: Option<&UserId>,
+    ) -> Result<bool, UserServiceError> {
+        let owner = self
+            .user_repository
+            .find_by_mail_address(tx, mail_address, UserStatusFilter::All)
+            .await?;
+        Ok(owner.is_some_and(|owner| Some(&owner.id) != except))
+    }
+
+    // NOTE: 既存のユーザー名と同じか、見た目が紛らわしいか(skeletonが一致するか)を確認する
+    //       退会済みのユーザーの名前も、なりすまし防止のため再利用させない
+    //       名前を変更する場合は、変更するユーザー自身をexceptに指定する
+    pub async fn user_name_taken(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        name: &UserName,
+        except: Option<&UserId>,
+    ) -> Result<bool, UserServiceError> {
+        Ok(self
+            .user_repository
+            .exists_by_user_name_skeleton(tx, &user_name_skeleton(name.get()), except)
+            .await?)
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -0,0 +1,167 @@
+use serde::Serialize;
+
+use crate::i18n::{Localize, Message};
+
+// NOTE: 入力値の検証エラー。クライアントがメッセージではなくコードで判定できるようにする
+pub trait ValidationError: std::error::Error {
+    fn code(&self) -> &'static str;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldError {
+    pub field: String,
+    pub code: &'static str,
+    pub message: Message,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, thiserror::Error)]
+#[serde(transparent)]
+pub struct ValidationErrors(Vec<FieldError>);
+
+impl ValidationErrors {
+    pub fn push<E>(&mut self, field: impl Into<String>, error: &E)
+    where
+        E: ValidationError + Localize,
+    {
+        self.0.push(FieldError {
+            field: field.into(),
+            code: error.code(),
+            message: error.message(),
+        });
+    }
+
+    pub fn errors(&self) -> &[FieldError] {
+        &self.0
+    }
+}
+
+impl From<FieldError> for ValidationErrors {
+    fn from(value: FieldError) -> Self {
+        Self(vec![value])
+    }
+}
+
+impl std::fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, error) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}: {}", error.field, error.message)?;
+        }
+        Ok(())
+    }
+}
+
+// NOTE: 最初のエラーで中断せず、全てのフィールドを検証してからまとめてエラーを返す
+//       checkは不正な値の場合にNoneを返し、finishで全ての値が揃っていることを確認する
+//       let name = validator.check("name", UserName::new(name));
+//       let (name,) = validator.finish((name,))?;
+#[derive(Debug, Default)]
+pub struct Validator {
+    errors: ValidationErrors,
+}
+
+impl Validator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn check<T, E>(&mut self, field: &str, result: Result<T, E>) -> Option<T>
+    where
+        E: ValidationError + Localize,
+    {
+        result.inspect_err(|e| self.errors.push(field, e)).ok()
+    }
+
+    // NOTE: 省略可能なフィールド用。省略された場合はSome(None)を返す
+    pub fn check_optional<T, E>(
+        &mut self,
+        field: &str,
+        result: Option<Result<T, E>>,
+    ) -> Option<Option<T>>
+    where
+        E: ValidationError + Localize,
+    {
+        match result {
+            Some(result) => self.check(field, result).map(Some),
+            None => Some(None),
+        }
+    }
+
+    pub fn finish<T>(self, values: T) -> Result<T::Output, ValidationErrors>
+    where
+        T: Checked,
+    {
+        match values.all() {
+            Some(values) if self.errors.0.is_empty() => Ok(values),
+            _ => Err(self.errors),
+        }
+    }
+}
+
+// NOTE: checkの結果のタプルから、全てSomeの場合に値を取り出す
+pub trait Checked {
+    type Output;
+    fn all(self) -> Option<Self::Output>;
+}
+
+macro_rules! impl_checked {
+    ($($name:ident),+) => {
+        impl<$($name),+> Checked for ($(Option<$name>,)+) {
+            type Output = ($($name,)+);
+
+            #[allow(non_snake_case)]
+            fn all(self) -> Option<Self::Output> {
+                let ($($name,)+) = self;
+                Some(($($name?,)+))
+            }
+        }
+    };
+}
+
+impl_checked!(A);
+impl_checked!(A, B);
+impl_checked!(A, B, C);
+impl_checked!(A, B, C, D);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{MailAddress, MailAddressError, UserName};
+    use rstest::rstest;
+
+    #[rstest]
+    fn collects_all_errors() {
+        let mut validator = Validator::new();
+        let name = validator.check("name", UserName::new("ab".to_string()));
+        let mail_address = validator.check("email", MailAddress::new("hoge".to_string()));
+        let errors = validator.finish((name, mail_address)).unwrap_err();
+        assert_eq!(
+            errors.errors(),
+            [
+                FieldError {
+                    field: "name".to_string(),
+                    code: "too_short",
+                    message: Message::new("user-name-too-short").arg("min_length", 3usize),
+                },
+                FieldError {
+                    field: "email".to_string(),
+                    code: "invalid_format",
+                    message: Message::new("mail-address-invalid-format"),
+                },
+            ]
+        );
+    }
+
+    #[rstest]
+    fn returns_values_when_valid() {
+        let mut validator = Validator::new();
+        let name = validator.check("name", UserName::new("hoge".to_string()));
+        let mail_address =
+            validator.check_optional("email", None::<Result<MailAddress, MailAddressError>>);
+        let (name, mail_address) = validator.finish((name, mail_address)).unwrap();
+        assert_eq!(name.get(), "hoge");
+        assert_eq!(mail_address, None);
+    }
+}
@@ -1,39 +1,31 @@
+mod api_key_id;
+mod api_key_scope;
+mod api_key_token;
 mod mail_address;
+mod password;
+mod password_hash;
+mod recovery_code;
+mod role;
+mod secret_token;
+mod session_id;
+mod single_use_token;
+mod totp_secret;
 mod user_id;
 mod user_name;
+mod user_status;
 
+pub use api_key_id::*;
+pub use api_key_scope::*;
+pub use api_key_token::*;
 pub use mail_address::*;
+pub use password::*;
+pub use password_hash::*;
+pub use recovery_code::*;
+pub use role::*;
+pub use secret_token::*;
+pub use session_id::*;
+pub use single_use_token::*;
+pub use totp_secret::*;
 pub use user_id::*;
 pub use user_name::*;
-
-// derive_macroとどっちがいいか検討
-#[macro_export]
-macro_rules! define_value_object {
-    (pub struct $struct_name:ident($type:ty)) => {
-        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-        pub struct $struct_name(pub $type);
-
-        impl sqlx::Type<sqlx::Postgres> for $struct_name {
-            fn type_info() -> sqlx::postgres::PgTypeInfo {
-                <$type as sqlx::Type<sqlx::Postgres>>::type_info()
-            }
-        }
-
-        impl<'q> sqlx::Encode<'q, sqlx::Postgres> for $struct_name {
-            fn encode_by_ref(
-                &self,
-                buf: &mut sqlx::postgres::PgArgumentBuffer,
-            ) -> sqlx::encode::IsNull {
-                <$type as sqlx::Encode<sqlx::Postgres>>::encode(self.0.clone(), buf)
-            }
-        }
-
-        impl<'r> sqlx::Decode<'r, sqlx::Postgres> for $struct_name {
-            fn decode(
-                value: sqlx::postgres::PgValueRef<'r>,
-            ) -> Result<Self, Box<dyn std::error::Error + 'static + Send + Sync>> {
-                <$type as sqlx::Decode<sqlx::Postgres>>::decode(value).map(Self)
-            }
-        }
-    };
-}
+pub use user_status::*;
@@ -0,0 +1,23 @@
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ApiKeyId(Uuid);
+
+impl ApiKeyId {
+    pub fn new(uuid: Uuid) -> Result<Self, ApiKeyIdError> {
+        Ok(Self(uuid))
+    }
+
+    pub fn get(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl std::fmt::Display for ApiKeyId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ApiKeyIdError {}
@@ -0,0 +1,59 @@
+// NOTE: APIキーで呼び出せる操作の範囲。ルートごとに必要なスコープを検査する
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ApiKeyScope {
+    UsersRead,
+    UsersWrite,
+}
+
+impl ApiKeyScope {
+    pub fn new(value: &str) -> Result<Self, ApiKeyScopeError> {
+        match value {
+            "users:read" => Ok(Self::UsersRead),
+            "users:write" => Ok(Self::UsersWrite),
+            _ => Err(ApiKeyScopeError::UnknownScope(value.to_string())),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::UsersRead => "users:read",
+            Self::UsersWrite => "users:write",
+        }
+    }
+}
+
+impl std::fmt::Display for ApiKeyScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ApiKeyScopeError {
+    #[error("{0}は不明なスコープです。")]
+    UnknownScope(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(ApiKeyScope::UsersRead)]
+    #[case(ApiKeyScope::UsersWrite)]
+    fn round_trip(#[case] scope: ApiKeyScope) {
+        assert_eq!(ApiKeyScope::new(scope.as_str()), Ok(scope));
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("users")]
+    #[case("USERS:READ")]
+    fn unknown_scope(#[case] value: &str) {
+        assert_eq!(
+            ApiKeyScope::new(value),
+            Err(ApiKeyScopeError::UnknownScope(value.to_string()))
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use data_encoding::BASE32_NOPAD;
+use rand::RngCore as _;
+
+use crate::domain::{SecretToken, SecretTokenHash};
+
+// NOTE: クライアントに渡すAPIキー。ak_{prefix}_{secret}の形式とする
+//       prefixはキーの識別用に平文で保存し、DBにはキー全体のハッシュ値のみ保存する
+#[derive(Clone, PartialEq)]
+pub struct ApiKeyToken {
+    value: String,
+    prefix_len: usize,
+}
+
+impl ApiKeyToken {
+    const SCHEME: &'static str = "ak_";
+    const PREFIX_BYTES: usize = 5;
+    const PREFIX_LENGTH: usize = 8;
+
+    pub fn generate() -> Self {
+        let mut bytes = [0u8; Self::PREFIX_BYTES];
+        rand::rngs::OsRng.fill_bytes(&mut bytes);
+        let prefix = BASE32_NOPAD.encode(&bytes).to_lowercase();
+        let secret = SecretToken::generate();
+        Self {
+            value: format!("{}{prefix}_{}", Self::SCHEME, secret.get()),
+            prefix_len: Self::SCHEME.len() + prefix.len(),
+        }
+    }
+
+    pub fn new(value: &str) -> Result<Self, ApiKeyTokenError> {
+        let (prefix, secret) = value
+            .strip_prefix(Self::SCHEME)
+            .and_then(|rest| rest.split_once('_'))
+            .ok_or(ApiKeyTokenError::InvalidFormat)?;
+        let valid_prefix = prefix.len() == Self::PREFIX_LENGTH
+            && prefix
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || ('2'..='7').contains(&c));
+        if !valid_prefix || SecretToken::new(secret.to_string()).is_err() {
+            return Err(ApiKeyTokenError::InvalidFormat);
+        }
+        Ok(Self {
+            value: value.to_string(),
+            prefix_len: Self::SCHEME.len() + prefix.len(),
+        })
+    }
+
+    // NOTE: 秘密の部分を含まないため、一覧やログに表示してよい
+    pub fn prefix(&self) -> &str {
+        &self.value[..self.prefix_len]
+    }
+
+    pub fn hash(&self) -> SecretTokenHash {
+        SecretTokenHash::digest(self.value.as_bytes())
+    }
+
+    pub fn get(&self) -> &str {
+        &self.value
+    }
+}
+
+impl std::fmt::Debug for ApiKeyToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ApiKeyToken({}_********)", self.prefix())
+    }
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ApiKeyTokenError {
+    #[error("APIキーの形式が不正です。")]
+    InvalidFormat,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn generated_token_round_trips() {
+        let token = ApiKeyToken::generate();
+        let parsed = ApiKeyToken::new(token.get()).unwrap();
+
+        assert_eq!(parsed.prefix(), token.prefix());
+        assert_eq!(parsed.hash(), token.hash());
+        assert!(token.get().starts_with(token.prefix()));
+        assert_eq!(token.prefix().len(), 11);
+        assert!(!format!("{token:?}").contains(&token.get()[12..]));
+        assert_ne!(ApiKeyToken::generate().hash(), token.hash());
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("ak_")]
+    #[case("ak_abcdefgh")]
+    #[case("ak_abcdefgh_short")]
+    #[case("ak_ABCDEFGH_{secret}")]
+    #[case("ak_abcdefg1_{secret}")]
+    #[case("ak_abcdefghi_{secret}")]
+    #[case("xx_abcdefgh_{secret}")]
+    fn invalid_token(#[case] value: &str) {
+        let value = value.replace("{secret}", &"A".repeat(43));
+        assert_eq!(
+            ApiKeyToken::new(&value),
+            Err(ApiKeyTokenError::InvalidFormat)
+        );
+    }
+
+    #[rstest]
+    fn valid_token() {
+        let value = format!("ak_abcdef23_{}", "A".repeat(43));
+        assert_eq!(ApiKeyToken::new(&value).unwrap().prefix(), "ak_abcdef23");
+    }
+}
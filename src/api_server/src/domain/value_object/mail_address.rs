@@ -1,23 +1,27 @@
-#[derive(Debug, Clone, PartialEq)]
-pub struct MailAddress(String);
+use sqlx_macros::ValueObject;
 
-impl MailAddress {
-    pub fn new(mail_address: String) -> Result<Self, MailAddressError> {
-        Ok(Self(mail_address))
-    }
+use crate::domain::ValidationError;
 
-    pub fn get(&self) -> &str {
-        &self.0
-    }
+// NOTE: 厳密な検証は確認メールで行うため、形式は最低限のみ確認する
+//       形式の検証を追加する前に登録されたアドレスも読み込めるよう、DBの値は検証しない
+#[derive(Debug, Clone, PartialEq, ValueObject)]
+#[vo(
+    label = "メールアドレス",
+    trim,
+    non_empty,
+    max_len = 254,
+    regex = r"^[^@\s]+@[^@\s]+\.[^@\s]+$",
+    trust_stored,
+    serde
+)]
+pub struct MailAddress(String);
 
-    pub fn into_inner(self) -> String {
-        self.0
+impl ValidationError for MailAddressError {
+    fn code(&self) -> &'static str {
+        Self::code(self)
     }
 }
 
-#[derive(Debug, PartialEq, thiserror::Error)]
-pub enum MailAddressError {}
-
 #[cfg(test)]
 mod test {
     use super::*;
@@ -31,4 +35,25 @@ mod test {
             Ok(MailAddress(mail_address.to_string()))
         );
     }
+
+    #[rstest]
+    #[case("", MailAddressError::Empty)]
+    #[case("hoge", MailAddressError::InvalidFormat)]
+    #[case("hoge@example", MailAddressError::InvalidFormat)]
+    #[case("ho ge@example.com", MailAddressError::InvalidFormat)]
+    fn invalid(#[case] mail_address: &str, #[case] expected: MailAddressError) {
+        assert_eq!(MailAddress::new(mail_address.to_string()), Err(expected));
+    }
+
+    #[rstest]
+    fn stored_value_is_not_revalidated() {
+        assert_eq!(
+            MailAddress::from_stored("hoge@localhost".to_string()),
+            MailAddress("hoge@localhost".to_string())
+        );
+        assert_eq!(
+            MailAddress::try_from("hoge@localhost".to_string()),
+            Err(MailAddressError::InvalidFormat)
+        );
+    }
 }
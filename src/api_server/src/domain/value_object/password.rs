@@ -0,0 +1,90 @@
+use crate::domain::ValidationError;
+
+// NOTE: 平文のパスワード。ログ等に出力されないようDebugは値を伏せる
+#[derive(Clone, PartialEq)]
+pub struct Password(String);
+
+impl Password {
+    pub const MIN_LENGTH: usize = 8;
+    pub const MAX_LENGTH: usize = 128;
+
+    pub fn new(value: String) -> Result<Self, PasswordError> {
+        let length = value.chars().count();
+        if length < Self::MIN_LENGTH {
+            return Err(PasswordError::TooShort {
+                min_length: Self::MIN_LENGTH,
+            });
+        }
+        if length > Self::MAX_LENGTH {
+            return Err(PasswordError::TooLong {
+                max_length: Self::MAX_LENGTH,
+            });
+        }
+        // NOTE: 英字と数字(または記号)の両方を含むことを要求する
+        let has_letter = value.chars().any(char::is_alphabetic);
+        let has_non_letter = value.chars().any(|c| !c.is_alphabetic());
+        let first = value.chars().next();
+        if !has_letter || !has_non_letter || value.chars().all(|c| Some(c) == first) {
+            return Err(PasswordError::TooWeak);
+        }
+        Ok(Self(value))
+    }
+
+    // NOTE: ログイン時の照合用。強度のルールは登録・変更時にのみ適用し、
+    //       ルール変更前に設定されたパスワードでもログインできるようにする
+    pub fn unvalidated(value: String) -> Self {
+        Self(value)
+    }
+
+    pub fn get(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Debug for Password {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Password(********)")
+    }
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum PasswordError {
+    #[error("パスワードは{min_length}文字以上で入力してください。")]
+    TooShort { min_length: usize },
+    #[error("パスワードは{max_length}文字以下で入力してください。")]
+    TooLong { max_length: usize },
+    #[error("パスワードは英字と数字または記号を組み合わせてください。")]
+    TooWeak,
+}
+
+impl ValidationError for PasswordError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::TooShort { .. } => "too_short",
+            Self::TooLong { .. } => "too_long",
+            Self::TooWeak => "too_weak",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("passw0rd!", Ok(Password("passw0rd!".to_string())))]
+    #[case("pass1", Err(PasswordError::TooShort { min_length: Password::MIN_LENGTH }))]
+    #[case(&"a1".repeat(65), Err(PasswordError::TooLong { max_length: Password::MAX_LENGTH }))]
+    #[case("password", Err(PasswordError::TooWeak))]
+    #[case("12345678", Err(PasswordError::TooWeak))]
+    fn test(#[case] password: &str, #[case] expected: Result<Password, PasswordError>) {
+        assert_eq!(Password::new(password.to_string()), expected);
+    }
+
+    #[rstest]
+    fn debug_hides_value() {
+        let password = Password::new("passw0rd!".to_string()).unwrap();
+        assert!(!format!("{password:?}").contains("passw0rd!"));
+    }
+}
@@ -0,0 +1,52 @@
+// NOTE: PHC文字列形式(例: $argon2id$v=19$m=19456,t=2,p=1$...)のハッシュ
+#[derive(Debug, Clone, PartialEq)]
+pub struct PasswordHash(String);
+
+impl PasswordHash {
+    pub fn new(value: String) -> Result<Self, PasswordHashError> {
+        argon2::password_hash::PasswordHash::new(&value)
+            .map_err(|_| PasswordHashError::InvalidFormat)?;
+        Ok(Self(value))
+    }
+
+    pub fn get(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl TryFrom<String> for PasswordHash {
+    type Error = PasswordHashError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<PasswordHash> for String {
+    fn from(value: PasswordHash) -> Self {
+        value.0
+    }
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum PasswordHashError {
+    #[error("パスワードハッシュの形式が不正です。")]
+    InvalidFormat,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("$argon2id$v=19$m=19456,t=2,p=1$c2FsdHNhbHRzYWx0$3nB0X2mK4d2n9JqJ0kq6mF2d3Qx3y8oVn8w0bq7fJ8Q", true)]
+    #[case("plain text", false)]
+    fn test(#[case] value: &str, #[case] valid: bool) {
+        assert_eq!(PasswordHash::new(value.to_string()).is_ok(), valid);
+    }
+}
@@ -0,0 +1,85 @@
+use data_encoding::BASE32_NOPAD;
+use rand::RngCore as _;
+
+use crate::domain::SecretTokenHash;
+
+// NOTE: 認証アプリを使用できない場合の一度きりのコード。DBにはハッシュ値のみ保存する
+//       入力しやすいよう、小文字のBase32を5文字ずつハイフンで区切った形式とする
+#[derive(Clone, PartialEq)]
+pub struct RecoveryCode(String);
+
+impl RecoveryCode {
+    // NOTE: 80bitのエントロピーがあるため、パスワードと異なりソルトなしのハッシュで十分
+    const BYTES: usize = 10;
+    const LENGTH: usize = 16;
+
+    pub fn generate() -> Self {
+        let mut bytes = [0u8; Self::BYTES];
+        rand::rngs::OsRng.fill_bytes(&mut bytes);
+        let encoded = BASE32_NOPAD.encode(&bytes).to_lowercase();
+        let (first, second) = encoded.split_at(encoded.len() / 2);
+        Self(format!("{first}-{second}"))
+    }
+
+    // NOTE: 大文字・ハイフン・空白の有無は区別しない
+    pub fn new(value: &str) -> Result<Self, RecoveryCodeError> {
+        let normalized: String = value
+            .chars()
+            .filter(|c| *c != '-' && !c.is_whitespace())
+            .collect::<String>()
+            .to_lowercase();
+        if normalized.len() != Self::LENGTH
+            || BASE32_NOPAD
+                .decode(normalized.to_uppercase().as_bytes())
+                .is_err()
+        {
+            return Err(RecoveryCodeError::InvalidFormat);
+        }
+        let (first, second) = normalized.split_at(Self::LENGTH / 2);
+        Ok(Self(format!("{first}-{second}")))
+    }
+
+    pub fn hash(&self) -> SecretTokenHash {
+        SecretTokenHash::digest(self.0.as_bytes())
+    }
+
+    pub fn get(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Debug for RecoveryCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RecoveryCode(********)")
+    }
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum RecoveryCodeError {
+    #[error("リカバリーコードの形式が不正です。")]
+    InvalidFormat,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn normalize_input() {
+        let code = RecoveryCode::generate();
+        let typed = code.get().to_uppercase().replace('-', " ");
+        assert_eq!(RecoveryCode::new(&typed).unwrap().hash(), code.hash());
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("abcd-efgh")]
+    #[case("abcdefgh-abcdefg1")]
+    fn invalid(#[case] value: &str) {
+        assert_eq!(
+            RecoveryCode::new(value),
+            Err(RecoveryCodeError::InvalidFormat)
+        );
+    }
+}
@@ -0,0 +1,66 @@
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Role {
+    #[default]
+    User,
+    Admin,
+}
+
+impl Role {
+    pub fn new(value: &str) -> Result<Self, RoleError> {
+        match value {
+            "user" => Ok(Self::User),
+            "admin" => Ok(Self::Admin),
+            _ => Err(RoleError::UnknownRole(value.to_string())),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::User => "user",
+            Self::Admin => "admin",
+        }
+    }
+}
+
+impl std::fmt::Display for Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl TryFrom<String> for Role {
+    type Error = RoleError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(&value)
+    }
+}
+
+impl From<Role> for String {
+    fn from(value: Role) -> Self {
+        value.as_str().to_string()
+    }
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum RoleError {
+    #[error("{0}は不明なロールです。")]
+    UnknownRole(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("user", Ok(Role::User))]
+    #[case("admin", Ok(Role::Admin))]
+    #[case("root", Err(RoleError::UnknownRole("root".to_string())))]
+    fn test(#[case] value: &str, #[case] expected: Result<Role, RoleError>) {
+        assert_eq!(Role::new(value), expected);
+        if let Ok(role) = expected {
+            assert_eq!(role.as_str(), value);
+        }
+    }
+}
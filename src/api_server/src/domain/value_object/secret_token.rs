@@ -0,0 +1,90 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use rand::RngCore as _;
+use sha2::{Digest as _, Sha256};
+
+// NOTE: クライアントに渡す秘密のトークン。DBにはハッシュ値のみ保存する
+#[derive(Clone, PartialEq)]
+pub struct SecretToken(String);
+
+impl SecretToken {
+    const BYTES: usize = 32;
+
+    pub fn generate() -> Self {
+        let mut bytes = [0u8; Self::BYTES];
+        rand::rngs::OsRng.fill_bytes(&mut bytes);
+        Self(URL_SAFE_NO_PAD.encode(bytes))
+    }
+
+    pub fn new(value: String) -> Result<Self, SecretTokenError> {
+        match URL_SAFE_NO_PAD.decode(&value) {
+            Ok(bytes) if bytes.len() == Self::BYTES => Ok(Self(value)),
+            _ => Err(SecretTokenError::InvalidFormat),
+        }
+    }
+
+    pub fn hash(&self) -> SecretTokenHash {
+        SecretTokenHash::digest(self.0.as_bytes())
+    }
+
+    pub fn get(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Debug for SecretToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SecretToken(********)")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SecretTokenHash(Vec<u8>);
+
+impl SecretTokenHash {
+    pub fn new(value: Vec<u8>) -> Result<Self, SecretTokenError> {
+        Ok(Self(value))
+    }
+
+    pub fn digest(value: &[u8]) -> Self {
+        Self(Sha256::digest(value).to_vec())
+    }
+
+    pub fn get(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SecretTokenError {
+    #[error("トークンの形式が不正です。")]
+    InvalidFormat,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn generated_token_round_trips() {
+        let token = SecretToken::generate();
+        let parsed = SecretToken::new(token.get().to_string()).unwrap();
+        assert_eq!(parsed.hash(), token.hash());
+        assert_ne!(SecretToken::generate().hash(), token.hash());
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("not base64!")]
+    #[case("c2hvcnQ")]
+    fn invalid_token(#[case] value: &str) {
+        assert_eq!(
+            SecretToken::new(value.to_string()),
+            Err(SecretTokenError::InvalidFormat)
+        );
+    }
+}
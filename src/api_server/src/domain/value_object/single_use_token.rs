@@ -0,0 +1,72 @@
+use chrono::{DateTime, Duration, Utc};
+
+use crate::domain::{SecretToken, SecretTokenHash};
+
+// NOTE: 有効期限付きで一度だけ使用できるトークン。クライアントには発行時のSecretTokenのみを渡し、ハッシュを保存する
+//       パスワード再設定・メールアドレスの確認・ログインの二要素目の確認で使用する
+#[derive(Debug, Clone, PartialEq)]
+pub struct SingleUseToken {
+    pub token_hash: SecretTokenHash,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+}
+
+impl SingleUseToken {
+    pub fn issue(now: DateTime<Utc>, ttl: Duration) -> (Self, SecretToken) {
+        let token = SecretToken::generate();
+        let single_use_token = Self {
+            token_hash: token.hash(),
+            created_at: now,
+            expires_at: now + ttl,
+            used_at: None,
+        };
+        (single_use_token, token)
+    }
+
+    pub fn is_usable(&self, now: DateTime<Utc>) -> bool {
+        self.used_at.is_none() && self.expires_at > now
+    }
+
+    pub fn consume(&mut self, now: DateTime<Utc>) -> Result<(), SingleUseTokenError> {
+        if self.used_at.is_some() {
+            return Err(SingleUseTokenError::AlreadyUsed);
+        }
+        if self.expires_at <= now {
+            return Err(SingleUseTokenError::Expired);
+        }
+        self.used_at = Some(now);
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum SingleUseTokenError {
+    #[error("トークンは使用済みです。")]
+    AlreadyUsed,
+    #[error("トークンの有効期限が切れています。")]
+    Expired,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn consume_once_before_expiry() {
+        let now = Utc::now();
+        let (mut token, secret) = SingleUseToken::issue(now, Duration::minutes(30));
+
+        assert_eq!(secret.hash(), token.token_hash);
+        assert!(token.is_usable(now));
+        assert!(!token.is_usable(now + Duration::minutes(30)));
+        assert_eq!(
+            token.consume(now + Duration::hours(1)),
+            Err(SingleUseTokenError::Expired)
+        );
+        assert_eq!(token.consume(now), Ok(()));
+        assert!(!token.is_usable(now));
+        assert_eq!(token.consume(now), Err(SingleUseTokenError::AlreadyUsed));
+    }
+}
@@ -0,0 +1,70 @@
+use data_encoding::BASE32_NOPAD;
+use rand::RngCore as _;
+
+// NOTE: TOTPの共有鍵。コードの検証に元の値が必要なため、ハッシュ化せずに保存する
+#[derive(Clone, PartialEq)]
+pub struct TotpSecret(Vec<u8>);
+
+impl TotpSecret {
+    // NOTE: RFC 4226の推奨値(160bit)
+    const BYTES: usize = 20;
+    const MIN_BYTES: usize = 16;
+
+    pub fn generate() -> Self {
+        let mut bytes = vec![0u8; Self::BYTES];
+        rand::rngs::OsRng.fill_bytes(&mut bytes);
+        Self(bytes)
+    }
+
+    pub fn new(value: Vec<u8>) -> Result<Self, TotpSecretError> {
+        if value.len() < Self::MIN_BYTES {
+            return Err(TotpSecretError::TooShort {
+                min_bytes: Self::MIN_BYTES,
+            });
+        }
+        Ok(Self(value))
+    }
+
+    pub fn get(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> Vec<u8> {
+        self.0
+    }
+
+    // NOTE: 認証アプリに手入力する場合やotpauth URIで使用する形式
+    pub fn to_base32(&self) -> String {
+        BASE32_NOPAD.encode(&self.0)
+    }
+}
+
+impl std::fmt::Debug for TotpSecret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TotpSecret(********)")
+    }
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum TotpSecretError {
+    #[error("TOTPの共有鍵は{min_bytes}バイト以上である必要があります。")]
+    TooShort { min_bytes: usize },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn generate_and_encode() {
+        let secret = TotpSecret::generate();
+        assert_eq!(secret.get().len(), 20);
+        assert_eq!(secret.to_base32().len(), 32);
+        assert!(!format!("{secret:?}").contains(&secret.to_base32()));
+        assert_eq!(
+            TotpSecret::new(vec![0; 10]),
+            Err(TotpSecretError::TooShort { min_bytes: 16 })
+        );
+    }
+}
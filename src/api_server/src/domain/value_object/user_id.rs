@@ -1,6 +1,6 @@
 use uuid::Uuid;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct UserId(Uuid);
 
 impl UserId {
@@ -19,6 +19,41 @@ impl std::fmt::Display for UserId {
     }
 }
 
+// NOTE: newと同じく、検証を追加できるようにTryFromとする
+#[allow(clippy::infallible_try_from)]
+impl TryFrom<Uuid> for UserId {
+    type Error = UserIdError;
+
+    fn try_from(value: Uuid) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<UserId> for Uuid {
+    fn from(value: UserId) -> Self {
+        value.0
+    }
+}
+
+impl serde::Serialize for UserId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+// NOTE: デシリアライズ時もnewで検証する
+impl<'de> serde::Deserialize<'de> for UserId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Self::new(Uuid::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+    }
+}
+
 #[derive(Debug, PartialEq, thiserror::Error)]
 pub enum UserIdError {}
 
@@ -32,4 +67,13 @@ mod tests {
     fn username(#[case] uuid: Uuid) {
         assert_eq!(UserId::new(uuid), Ok(UserId(uuid)));
     }
+
+    #[rstest]
+    fn serde_round_trip() {
+        let id = UserId(Uuid::new_v4());
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, format!("\"{}\"", id.get()));
+        assert_eq!(serde_json::from_str::<UserId>(&json).unwrap(), id);
+        assert!(serde_json::from_str::<UserId>(r#""not-a-uuid""#).is_err());
+    }
 }
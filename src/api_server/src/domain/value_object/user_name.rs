@@ -1,52 +1,17 @@
-#[derive(Debug, PartialEq)]
-pub struct UserName(String);
-
-impl UserName {
-    pub const MIN_LENGTH: usize = 3;
-    pub const MAX_LENGTH: usize = 20;
-
-    pub fn new(value: String) -> Result<Self, UserNameError> {
-        if value.is_empty() {
-            return Err(UserNameError::EmptyUserName);
-        }
-        if value.len() < Self::MIN_LENGTH {
-            return Err(UserNameError::TooShort {
-                min_length: Self::MIN_LENGTH,
-            });
-        }
-        if value.len() > Self::MAX_LENGTH {
-            return Err(UserNameError::TooLong {
-                max_length: Self::MAX_LENGTH,
-            });
-        }
-        Ok(Self(value))
-    }
+use sqlx_macros::ValueObject;
 
-    pub fn get(&self) -> &str {
-        &self.0
-    }
+use crate::domain::ValidationError;
 
-    pub fn into_inner(self) -> String {
-        self.0
-    }
-}
+#[derive(Debug, Clone, PartialEq, ValueObject)]
+#[vo(label = "ユーザー名", trim, min_len = 3, max_len = 20, serde)]
+pub struct UserName(String);
 
-impl std::fmt::Display for UserName {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+impl ValidationError for UserNameError {
+    fn code(&self) -> &'static str {
+        Self::code(self)
     }
 }
 
-#[derive(Debug, PartialEq, thiserror::Error)]
-pub enum UserNameError {
-    #[error("ユーザー名が空白です。")]
-    EmptyUserName,
-    #[error("ユーザー名は{min_length}文字以上で入力してください。")]
-    TooShort { min_length: usize },
-    #[error("ユーザー名は{max_length}文字以上で入力してください。")]
-    TooLong { max_length: usize },
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -54,9 +19,32 @@ mod tests {
 
     #[rstest]
     #[case("valid_name", Ok(UserName ("valid_name".to_string() )))]
+    #[case("  valid_name  ", Ok(UserName ("valid_name".to_string() )))]
+    #[case("   ", Err(UserNameError::Empty))]
     #[case("ab", Err(UserNameError::TooShort { min_length: UserName::MIN_LENGTH}))]
     #[case("abcdefghijklmopqrstuvwxyz", Err(UserNameError::TooLong { max_length: UserName::MAX_LENGTH }))]
+    #[case("やまだたろう", Ok(UserName ("やまだたろう".to_string() )))]
     fn test(#[case] name: &str, #[case] expected: Result<UserName, UserNameError>) {
         assert_eq!(UserName::new(name.to_string()), expected);
     }
+
+    #[rstest]
+    fn error_message() {
+        assert_eq!(
+            UserNameError::TooLong { max_length: 20 }.to_string(),
+            "ユーザー名は20文字以下で入力してください。"
+        );
+    }
+
+    #[rstest]
+    #[case(r#""  valid_name  ""#, Ok(UserName("valid_name".to_string())))]
+    #[case(r#""ab""#, Err("too_short"))]
+    #[case(
+        "123",
+        Err("invalid type: integer `123`, expected a string at line 1 column 3")
+    )]
+    fn deserialize(#[case] json: &str, #[case] expected: Result<UserName, &str>) {
+        let result = serde_json::from_str::<UserName>(json).map_err(|e| e.to_string());
+        assert_eq!(result, expected.map_err(str::to_string));
+    }
 }
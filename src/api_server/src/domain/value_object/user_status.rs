@@ -0,0 +1,276 @@
+use chrono::{DateTime, Utc};
+
+// NOTE: ユーザーの状態。遷移はメソッドでのみ行い、不正な遷移はエラーとする
+//       pending: メールアドレス未確認 / active: 利用中 / suspended: 利用停止中 / deleted: 退会済み
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum UserStatus {
+    #[default]
+    Pending,
+    Active,
+    // NOTE: untilがNoneの場合は無期限。期限を過ぎると復帰(reinstate)しなくても利用できる
+    Suspended {
+        reason: String,
+        until: Option<DateTime<Utc>>,
+    },
+    // NOTE: 退会済みのユーザーも、アドレス・ユーザー名の再利用によるなりすましを防ぐため残す
+    Deleted {
+        at: DateTime<Utc>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserStatusTransition {
+    Activate,
+    Suspend,
+    Reinstate,
+    Delete,
+}
+
+impl std::fmt::Display for UserStatusTransition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Activate => write!(f, "有効化"),
+            Self::Suspend => write!(f, "利用停止"),
+            Self::Reinstate => write!(f, "利用停止の解除"),
+            Self::Delete => write!(f, "退会"),
+        }
+    }
+}
+
+impl UserStatus {
+    pub const PENDING: &'static str = "pending";
+    pub const ACTIVE: &'static str = "active";
+    pub const SUSPENDED: &'static str = "suspended";
+    pub const DELETED: &'static str = "deleted";
+
+    // NOTE: DBの各カラムから復元する
+    pub fn from_parts(
+        status: &str,
+        suspension_reason: Option<String>,
+        suspended_until: Option<DateTime<Utc>>,
+        deleted_at: Option<DateTime<Utc>>,
+    ) -> Result<Self, UserStatusError> {
+        match (status, suspension_reason, deleted_at) {
+            (Self::PENDING, _, _) => Ok(Self::Pending),
+            (Self::ACTIVE, _, _) => Ok(Self::Active),
+            (Self::SUSPENDED, Some(reason), _) => Ok(Self::Suspended {
+                reason,
+                until: suspended_until,
+            }),
+            (Self::DELETED, _, Some(at)) => Ok(Self::Deleted { at }),
+            (status, _, _) => Err(UserStatusError::UnknownStatus(status.to_string())),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => Self::PENDING,
+            Self::Active => Self::ACTIVE,
+            Self::Suspended { .. } => Self::SUSPENDED,
+            Self::Deleted { .. } => Self::DELETED,
+        }
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        matches!(self, Self::Deleted { .. })
+    }
+
+    pub fn is_suspended_at(&self, now: DateTime<Utc>) -> bool {
+        match self {
+            Self::Suspended { until, .. } => until.is_none_or(|until| until > now),
+            _ => false,
+        }
+    }
+
+    // NOTE: ログイン等の操作が可能な状態か
+    pub fn is_available_at(&self, now: DateTime<Utc>) -> bool {
+        !self.is_deleted() && !self.is_suspended_at(now)
+    }
+
+    pub fn activate(&self) -> Result<Self, UserStatusError> {
+        match self {
+            Self::Pending => Ok(Self::Active),
+            _ => Err(self.invalid(UserStatusTransition::Activate)),
+        }
+    }
+
+    // NOTE: 停止中の場合は理由・期限を更新する
+    pub fn suspend(
+        &self,
+        reason: String,
+        until: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> Result<Self, UserStatusError> {
+        if self.is_deleted() {
+            return Err(self.invalid(UserStatusTransition::Suspend));
+        }
+        let reason = reason.trim();
+        if reason.is_empty() {
+            return Err(UserStatusError::EmptySuspensionReason);
+        }
+        if until.is_some_and(|until| until <= now) {
+            return Err(UserStatusError::InvalidSuspensionPeriod);
+        }
+        Ok(Self::Suspended {
+            reason: reason.to_string(),
+            until,
+        })
+    }
+
+    // NOTE: メールアドレスが未確認の場合は確認待ちに戻す
+    pub fn reinstate(&self, mail_verified: bool) -> Result<Self, UserStatusError> {
+        match self {
+            Self::Suspended { .. } if mail_verified => Ok(Self::Active),
+            Self::Suspended { .. } => Ok(Self::Pending),
+            _ => Err(self.invalid(UserStatusTransition::Reinstate)),
+        }
+    }
+
+    pub fn delete(&self, now: DateTime<Utc>) -> Result<Self, UserStatusError> {
+        match self {
+            Self::Deleted { .. } => Err(self.invalid(UserStatusTransition::Delete)),
+            _ => Ok(Self::Deleted { at: now }),
+        }
+    }
+
+    fn invalid(&self, transition: UserStatusTransition) -> UserStatusError {
+        UserStatusError::InvalidTransition {
+            from: self.as_str(),
+            transition,
+        }
+    }
+}
+
+// NOTE: DBのカラム(status, suspension_reason, suspended_until, deleted_at)
+pub type UserStatusColumns = (
+    String,
+    Option<String>,
+    Option<DateTime<Utc>>,
+    Option<DateTime<Utc>>,
+);
+
+impl TryFrom<UserStatusColumns> for UserStatus {
+    type Error = UserStatusError;
+
+    fn try_from(
+        (status, suspension_reason, suspended_until, deleted_at): UserStatusColumns,
+    ) -> Result<Self, Self::Error> {
+        Self::from_parts(&status, suspension_reason, suspended_until, deleted_at)
+    }
+}
+
+impl From<UserStatus> for UserStatusColumns {
+    fn from(value: UserStatus) -> Self {
+        let status = value.as_str().to_string();
+        match value {
+            UserStatus::Suspended { reason, until } => (status, Some(reason), until, None),
+            UserStatus::Deleted { at } => (status, None, None, Some(at)),
+            UserStatus::Pending | UserStatus::Active => (status, None, None, None),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum UserStatusError {
+    #[error("状態が{from}のユーザーに対して{transition}は行えません。")]
+    InvalidTransition {
+        from: &'static str,
+        transition: UserStatusTransition,
+    },
+    #[error("利用停止の理由を入力してください。")]
+    EmptySuspensionReason,
+    #[error("利用停止の期限には現在より後の日時を指定してください。")]
+    InvalidSuspensionPeriod,
+    #[error("{0}は不明なユーザーの状態です。")]
+    UnknownStatus(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use rstest::rstest;
+
+    fn now() -> DateTime<Utc> {
+        DateTime::from_timestamp(1_700_000_000, 0).unwrap()
+    }
+
+    fn suspended(until: Option<DateTime<Utc>>) -> UserStatus {
+        UserStatus::Suspended {
+            reason: "spam".to_string(),
+            until,
+        }
+    }
+
+    #[rstest]
+    #[case(UserStatus::Pending, UserStatusTransition::Activate, true)]
+    #[case(UserStatus::Active, UserStatusTransition::Activate, false)]
+    #[case(UserStatus::Pending, UserStatusTransition::Suspend, true)]
+    #[case(UserStatus::Active, UserStatusTransition::Suspend, true)]
+    #[case(suspended(None), UserStatusTransition::Suspend, true)]
+    #[case(UserStatus::Deleted { at: now() }, UserStatusTransition::Suspend, false)]
+    #[case(UserStatus::Active, UserStatusTransition::Reinstate, false)]
+    #[case(suspended(None), UserStatusTransition::Reinstate, true)]
+    #[case(suspended(None), UserStatusTransition::Delete, true)]
+    #[case(UserStatus::Deleted { at: now() }, UserStatusTransition::Delete, false)]
+    #[case(UserStatus::Deleted { at: now() }, UserStatusTransition::Activate, false)]
+    fn transitions(
+        #[case] status: UserStatus,
+        #[case] transition: UserStatusTransition,
+        #[case] allowed: bool,
+    ) {
+        let result = match transition {
+            UserStatusTransition::Activate => status.activate(),
+            UserStatusTransition::Suspend => status.suspend("spam".to_string(), None, now()),
+            UserStatusTransition::Reinstate => status.reinstate(true),
+            UserStatusTransition::Delete => status.delete(now()),
+        };
+        match result {
+            Ok(_) => assert!(allowed),
+            Err(e) => {
+                assert!(!allowed);
+                assert_eq!(
+                    e,
+                    UserStatusError::InvalidTransition {
+                        from: status.as_str(),
+                        transition
+                    }
+                );
+            }
+        }
+    }
+
+    #[rstest]
+    #[case(" ", None, Err(UserStatusError::EmptySuspensionReason))]
+    #[case("spam", Some(now()), Err(UserStatusError::InvalidSuspensionPeriod))]
+    #[case(" spam ", Some(now() + Duration::days(1)), Ok(suspended(Some(now() + Duration::days(1)))))]
+    fn suspend_validation(
+        #[case] reason: &str,
+        #[case] until: Option<DateTime<Utc>>,
+        #[case] expected: Result<UserStatus, UserStatusError>,
+    ) {
+        assert_eq!(
+            UserStatus::Active.suspend(reason.to_string(), until, now()),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn suspension_expires() {
+        let status = suspended(Some(now() + Duration::days(1)));
+        assert!(!status.is_available_at(now()));
+        assert!(status.is_available_at(now() + Duration::days(2)));
+        assert!(!suspended(None).is_available_at(now() + Duration::days(365)));
+        assert_eq!(suspended(None).reinstate(false), Ok(UserStatus::Pending));
+    }
+
+    #[rstest]
+    #[case(UserStatus::Pending)]
+    #[case(UserStatus::Active)]
+    #[case(suspended(Some(now() + Duration::days(1))))]
+    #[case(UserStatus::Deleted { at: now() })]
+    fn columns_round_trip(#[case] status: UserStatus) {
+        let columns = UserStatusColumns::from(status.clone());
+        assert_eq!(UserStatus::try_from(columns), Ok(status));
+    }
+}
@@ -0,0 +1,221 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+use sqlx::{migrate::Migrator, PgPool};
+
+// NOTE: ビルド時にマイグレーションファイルを埋め込み、期待するバージョンとして使用する
+static MIGRATOR: Migrator = sqlx::migrate!("../../migrations");
+
+pub struct HealthChecker {
+    pool: Arc<PgPool>,
+    probe_timeout: Duration,
+    started_at: Instant,
+    shutting_down: AtomicBool,
+}
+
+impl HealthChecker {
+    pub fn new(pool: Arc<PgPool>, probe_timeout: Duration) -> Self {
+        Self {
+            pool,
+            probe_timeout,
+            started_at: Instant::now(),
+            shutting_down: AtomicBool::new(false),
+        }
+    }
+
+    // NOTE: グレースフルシャットダウン開始時に呼び出し、readinessを失敗させる
+    pub fn mark_shutting_down(&self) {
+        self.shutting_down.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_shutting_down(&self) -> bool {
+        self.shutting_down.load(Ordering::SeqCst)
+    }
+
+    pub fn liveness(&self) -> HealthReport {
+        HealthReport::from_checks([(
+            "process",
+            CheckResult::ok()
+                .with_detail(format!("uptime {}s", self.started_at.elapsed().as_secs())),
+        )])
+    }
+
+    pub async fn readiness(&self) -> HealthReport {
+        let shutdown = if self.is_shutting_down() {
+            CheckResult::fail("shutting down")
+        } else {
+            CheckResult::ok()
+        };
+        let (database, migrations) = tokio::join!(self.check_database(), self.check_migrations());
+        HealthReport::from_checks([
+            ("shutdown", shutdown),
+            ("database", database),
+            ("migrations", migrations),
+        ])
+    }
+
+    // NOTE: 公開されるエンドポイントのため、DBのエラーの詳細はログにのみ出力する
+    async fn check_database(&self) -> CheckResult {
+        let started = Instant::now();
+        let probe = async {
+            let mut conn = self.pool.acquire().await?;
+            sqlx::query("SELECT 1").execute(&mut *conn).await
+        };
+        match tokio::time::timeout(self.probe_timeout, probe).await {
+            Ok(Ok(_)) => CheckResult::ok().with_latency(started.elapsed()),
+            Ok(Err(e)) => {
+                tracing::warn!(error = %e, "database readiness probe failed");
+                CheckResult::fail("unavailable").with_latency(started.elapsed())
+            }
+            Err(_) => CheckResult::fail("timed out").with_latency(started.elapsed()),
+        }
+    }
+
+    // NOTE: ローリングデプロイ中は新しいバージョンのマイグレーションが先に適用されるため、
+    //       DBが先行していることは許容し、このバージョンが必要とするマイグレーションの欠落のみ失敗とする
+    async fn check_migrations(&self) -> CheckResult {
+        let probe =
+            sqlx::query_scalar::<_, i64>("SELECT version FROM _sqlx_migrations WHERE success")
+                .fetch_all(&*self.pool);
+        match tokio::time::timeout(self.probe_timeout, probe).await {
+            Ok(Ok(applied)) => {
+                let expected: Vec<i64> = MIGRATOR.iter().map(|m| m.version).collect();
+                match missing_migrations(&expected, &applied).as_slice() {
+                    [] => CheckResult::ok().with_detail(format!(
+                        "version {}",
+                        applied.iter().max().copied().unwrap_or_default()
+                    )),
+                    missing => CheckResult::fail(format!("{} migrations pending", missing.len())),
+                }
+            }
+            Ok(Err(e)) => {
+                tracing::warn!(error = %e, "migration readiness probe failed");
+                CheckResult::fail("unavailable")
+            }
+            Err(_) => CheckResult::fail("timed out"),
+        }
+    }
+}
+
+fn missing_migrations(expected: &[i64], applied: &[i64]) -> Vec<i64> {
+    let applied: BTreeSet<_> = applied.iter().collect();
+    expected
+        .iter()
+        .filter(|version| !applied.contains(version))
+        .copied()
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Ok,
+    Fail,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CheckResult {
+    pub status: HealthStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<u128>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+impl CheckResult {
+    pub fn ok() -> Self {
+        Self {
+            status: HealthStatus::Ok,
+            latency_ms: None,
+            detail: None,
+        }
+    }
+
+    pub fn fail(detail: impl Into<String>) -> Self {
+        Self {
+            status: HealthStatus::Fail,
+            latency_ms: None,
+            detail: Some(detail.into()),
+        }
+    }
+
+    fn with_latency(mut self, latency: Duration) -> Self {
+        self.latency_ms = Some(latency.as_millis());
+        self
+    }
+
+    fn with_detail(mut self, detail: String) -> Self {
+        self.detail = Some(detail);
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HealthReport {
+    pub status: HealthStatus,
+    pub checks: BTreeMap<&'static str, CheckResult>,
+}
+
+impl HealthReport {
+    fn from_checks(checks: impl IntoIterator<Item = (&'static str, CheckResult)>) -> Self {
+        let checks: BTreeMap<_, _> = checks.into_iter().collect();
+        let status = if checks.values().all(|c| c.status == HealthStatus::Ok) {
+            HealthStatus::Ok
+        } else {
+            HealthStatus::Fail
+        };
+        Self { status, checks }
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.status == HealthStatus::Ok
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(vec![CheckResult::ok(), CheckResult::ok()], HealthStatus::Ok)]
+    #[case(vec![CheckResult::ok(), CheckResult::fail("down")], HealthStatus::Fail)]
+    #[case(vec![], HealthStatus::Ok)]
+    fn report_status(#[case] checks: Vec<CheckResult>, #[case] expected: HealthStatus) {
+        let names = ["a", "b"];
+        let report = HealthReport::from_checks(names.into_iter().zip(checks));
+        assert_eq!(report.status, expected);
+    }
+
+    #[rstest]
+    #[case(&[1, 2], &[1, 2], &[])]
+    #[case(&[1, 2], &[1, 2, 3], &[])]
+    #[case(&[1, 2, 3], &[1, 2], &[3])]
+    #[case(&[1, 2, 3], &[1, 3, 4], &[2])]
+    fn pending_migrations(
+        #[case] expected: &[i64],
+        #[case] applied: &[i64],
+        #[case] pending: &[i64],
+    ) {
+        assert_eq!(missing_migrations(expected, applied), pending);
+    }
+
+    #[rstest]
+    fn report_json() {
+        let report = HealthReport::from_checks([("database", CheckResult::fail("timed out"))]);
+        assert_eq!(
+            serde_json::to_value(report).unwrap(),
+            serde_json::json!({
+                "status": "fail",
+                "checks": { "database": { "status": "fail", "detail": "timed out" } }
+            })
+        );
+    }
+}
@@ -0,0 +1,242 @@
+mod messages;
+
+use std::{future::Future, sync::LazyLock};
+
+use fluent_bundle::{concurrent::FluentBundle, FluentArgs, FluentResource, FluentValue};
+use serde::Serialize;
+use unic_langid::LanguageIdentifier;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Locale {
+    #[default]
+    Ja,
+    En,
+}
+
+impl Locale {
+    pub const ALL: [Self; 2] = [Self::Ja, Self::En];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ja => "ja",
+            Self::En => "en",
+        }
+    }
+
+    fn resource(&self) -> &'static str {
+        match self {
+            Self::Ja => include_str!("../locales/ja/errors.ftl"),
+            Self::En => include_str!("../locales/en/errors.ftl"),
+        }
+    }
+
+    // NOTE: Accept-Languageのq値が高い順に、対応する言語を選ぶ(en-USのような地域の指定は無視する)
+    //       対応する言語がない場合は日本語とする
+    pub fn negotiate(accept_language: &str) -> Self {
+        let mut ranges: Vec<(&str, f32)> = accept_language
+            .split(',')
+            .filter_map(|range| {
+                let mut parts = range.split(';').map(str::trim);
+                let tag = parts.next().filter(|tag| !tag.is_empty())?;
+                let quality = parts
+                    .find_map(|param| param.strip_prefix("q="))
+                    .map_or(Some(1.0), |q| q.parse::<f32>().ok())?;
+                Some((tag, quality))
+            })
+            .filter(|(_, quality)| *quality > 0.0)
+            .collect();
+        // NOTE: 安定ソートのため、同じq値の場合はヘッダの順序を維持する
+        ranges.sort_by(|a, b| b.1.total_cmp(&a.1));
+        ranges
+            .into_iter()
+            .find_map(|(tag, _)| {
+                let language = tag.split('-').next().unwrap_or(tag);
+                Self::ALL
+                    .into_iter()
+                    .find(|locale| locale.as_str().eq_ignore_ascii_case(language))
+            })
+            .unwrap_or_default()
+    }
+
+    fn bundle(&self) -> &'static FluentBundle<FluentResource> {
+        static JA: LazyLock<FluentBundle<FluentResource>> = LazyLock::new(|| bundle(Locale::Ja));
+        static EN: LazyLock<FluentBundle<FluentResource>> = LazyLock::new(|| bundle(Locale::En));
+        match self {
+            Self::Ja => &JA,
+            Self::En => &EN,
+        }
+    }
+}
+
+fn bundle(locale: Locale) -> FluentBundle<FluentResource> {
+    let langid: LanguageIdentifier = locale.as_str().parse().expect("valid language identifier");
+    let resource = FluentResource::try_new(locale.resource().to_string())
+        .unwrap_or_else(|(_, errors)| panic!("invalid {} catalogue: {errors:?}", locale.as_str()));
+    let mut bundle = FluentBundle::new_concurrent(vec![langid]);
+    // NOTE: 引数の前後に双方向テキストの制御文字を挿入しない
+    bundle.set_use_isolating(false);
+    bundle
+        .add_resource(resource)
+        .expect("message ids are unique");
+    bundle
+}
+
+tokio::task_local! {
+    static CURRENT_LOCALE: Locale;
+}
+
+// NOTE: リクエストごとに交渉した言語をタスクローカルに保持し、エラーレスポンスの生成時に参照する
+pub async fn with_locale<F>(locale: Locale, f: F) -> F::Output
+where
+    F: Future,
+{
+    CURRENT_LOCALE.scope(locale, f).await
+}
+
+pub fn current_locale() -> Locale {
+    CURRENT_LOCALE
+        .try_with(|locale| *locale)
+        .unwrap_or_default()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageArg {
+    String(String),
+    Number(i64),
+}
+
+impl From<String> for MessageArg {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for MessageArg {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<usize> for MessageArg {
+    fn from(value: usize) -> Self {
+        Self::Number(i64::try_from(value).unwrap_or(i64::MAX))
+    }
+}
+
+// NOTE: カタログのキーと引数。表示するときに言語を選んで文言にする
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Message {
+    id: &'static str,
+    args: Vec<(&'static str, MessageArg)>,
+}
+
+impl Message {
+    pub fn new(id: &'static str) -> Self {
+        Self {
+            id,
+            args: Vec::new(),
+        }
+    }
+
+    pub fn arg(mut self, name: &'static str, value: impl Into<MessageArg>) -> Self {
+        self.args.push((name, value.into()));
+        self
+    }
+
+    pub fn id(&self) -> &'static str {
+        self.id
+    }
+
+    // NOTE: カタログにないキーの場合は、キーをそのまま返す
+    pub fn localize(&self, locale: Locale) -> String {
+        let bundle = locale.bundle();
+        let Some(pattern) = bundle
+            .get_message(self.id)
+            .and_then(|message| message.value())
+        else {
+            tracing::warn!(id = self.id, locale = locale.as_str(), "message not found");
+            return self.id.to_string();
+        };
+        let mut args = FluentArgs::new();
+        for (name, value) in &self.args {
+            match value {
+                MessageArg::String(value) => args.set(*name, FluentValue::from(value.as_str())),
+                MessageArg::Number(value) => args.set(*name, FluentValue::from(*value)),
+            }
+        }
+        let mut errors = Vec::new();
+        let message = bundle.format_pattern(pattern, Some(&args), &mut errors);
+        if !errors.is_empty() {
+            tracing::warn!(id = self.id, ?errors, "failed to format message");
+        }
+        message.into_owned()
+    }
+}
+
+// NOTE: 現在のリクエストの言語で表示する
+impl std::fmt::Display for Message {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.localize(current_locale()))
+    }
+}
+
+impl Serialize for Message {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+// NOTE: クライアントに返すエラーのメッセージ
+//       ログには従来どおりDisplay(日本語)を使用する
+pub trait Localize {
+    fn message(&self) -> Message;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use std::collections::BTreeSet;
+
+    #[rstest]
+    #[case("", Locale::Ja)]
+    #[case("en", Locale::En)]
+    #[case("en-US,en;q=0.9", Locale::En)]
+    #[case("fr-FR, en;q=0.8, ja;q=0.9", Locale::Ja)]
+    #[case("ja;q=0.5, EN-gb;q=0.7", Locale::En)]
+    #[case("en;q=0, *", Locale::Ja)]
+    #[case("de, fr", Locale::Ja)]
+    fn negotiate(#[case] header: &str, #[case] expected: Locale) {
+        assert_eq!(Locale::negotiate(header), expected);
+    }
+
+    // NOTE: 行頭から始まる「id =」をメッセージ・タームの定義とみなす
+    fn ids(locale: Locale) -> BTreeSet<&'static str> {
+        locale
+            .resource()
+            .lines()
+            .filter(|line| !line.starts_with([' ', '#', '*', '[', '}']))
+            .filter_map(|line| line.split_once(" =").map(|(id, _)| id))
+            .collect()
+    }
+
+    #[rstest]
+    fn catalogues_have_same_ids() {
+        assert_eq!(ids(Locale::Ja), ids(Locale::En));
+    }
+
+    #[tokio::test]
+    async fn display_uses_current_locale() {
+        let message = Message::new("user-name-too-short").arg("min_length", 3usize);
+        assert_eq!(
+            message.to_string(),
+            "ユーザー名は3文字以上で入力してください。"
+        );
+        let localized = with_locale(Locale::En, async { message.to_string() }).await;
+        assert_eq!(localized, "The user name must be at least 3 characters.");
+        assert_eq!(Message::new("unknown-id").to_string(), "unknown-id");
+    }
+}
@@ -0,0 +1,381 @@
+use crate::{
+    domain::{
+        AccessTokenError, ApiKeyError, ApiKeyPolicyError, ApiKeyScopeError, MailAddressError,
+        PasswordError, PasswordResetPolicyError, TotpCredentialError, UserAction, UserError,
+        UserNameError, UserNamePolicyError, UserPolicyError, UserStatusError, UserStatusTransition,
+    },
+    use_case::{
+        AccountLockUsecaseError, ApiKeyUsecaseError, LoginRejection, PasswordResetUsecaseError,
+        TwoFactorRejection, TwoFactorUsecaseError, UserUsecaseError,
+    },
+};
+
+use super::{Localize, Message};
+
+// NOTE: ドメイン・ユースケースのエラーとカタログのキーの対応
+//       クライアントに詳細を返さない内部エラーはinternal-errorとする
+
+fn internal_error() -> Message {
+    Message::new("internal-error")
+}
+
+fn user_action(action: &UserAction) -> &'static str {
+    match action {
+        UserAction::Read => "read",
+        UserAction::Update => "update",
+        UserAction::Delete => "delete",
+        UserAction::Unlock => "unlock",
+        UserAction::Suspend => "suspend",
+    }
+}
+
+fn user_status_transition(transition: &UserStatusTransition) -> &'static str {
+    match transition {
+        UserStatusTransition::Activate => "activate",
+        UserStatusTransition::Suspend => "suspend",
+        UserStatusTransition::Reinstate => "reinstate",
+        UserStatusTransition::Delete => "delete",
+    }
+}
+
+impl Localize for UserNameError {
+    fn message(&self) -> Message {
+        match self {
+            Self::Empty => Message::new("user-name-empty"),
+            Self::TooShort { min_length } => {
+                Message::new("user-name-too-short").arg("min_length", *min_length)
+            }
+            Self::TooLong { max_length } => {
+                Message::new("user-name-too-long").arg("max_length", *max_length)
+            }
+        }
+    }
+}
+
+impl Localize for UserNamePolicyError {
+    fn message(&self) -> Message {
+        match self {
+            Self::Reserved => Message::new("user-name-reserved"),
+            Self::Blocked => Message::new("user-name-blocked"),
+            Self::Confusable => Message::new("user-name-confusable"),
+        }
+    }
+}
+
+impl Localize for MailAddressError {
+    fn message(&self) -> Message {
+        match self {
+            Self::Empty => Message::new("mail-address-empty"),
+            Self::TooLong { max_length } => {
+                Message::new("mail-address-too-long").arg("max_length", *max_length)
+            }
+            Self::InvalidFormat => Message::new("mail-address-invalid-format"),
+        }
+    }
+}
+
+impl Localize for PasswordError {
+    fn message(&self) -> Message {
+        match self {
+            Self::TooShort { min_length } => {
+                Message::new("password-too-short").arg("min_length", *min_length)
+            }
+            Self::TooLong { max_length } => {
+                Message::new("password-too-long").arg("max_length", *max_length)
+            }
+            Self::TooWeak => Message::new("password-too-weak"),
+        }
+    }
+}
+
+impl Localize for UserStatusError {
+    fn message(&self) -> Message {
+        match self {
+            Self::InvalidTransition { from, transition } => {
+                Message::new("user-status-invalid-transition")
+                    .arg("from", *from)
+                    .arg("transition", user_status_transition(transition))
+            }
+            Self::EmptySuspensionReason => Message::new("user-status-empty-suspension-reason"),
+            Self::InvalidSuspensionPeriod => Message::new("user-status-invalid-suspension-period"),
+            Self::UnknownStatus(_) => internal_error(),
+        }
+    }
+}
+
+impl Localize for UserError {
+    fn message(&self) -> Message {
+        match self {
+            Self::UserNameError(e) => e.message(),
+            Self::MailAddressError(e) => e.message(),
+            Self::UserStatusError(e) => e.message(),
+            Self::MailAddressMismatch => Message::new("user-mail-address-mismatch"),
+            Self::UserIdError(_) | Self::PasswordHashError(_) | Self::RoleError(_) => {
+                internal_error()
+            }
+        }
+    }
+}
+
+impl Localize for UserPolicyError {
+    fn message(&self) -> Message {
+        match self {
+            Self::NotPermitted { action } => {
+                Message::new("user-policy-not-permitted").arg("action", user_action(action))
+            }
+            Self::MailNotVerified { action } => {
+                Message::new("user-policy-mail-not-verified").arg("action", user_action(action))
+            }
+            Self::ActorNotFound => Message::new("actor-not-found"),
+        }
+    }
+}
+
+impl Localize for UserUsecaseError {
+    fn message(&self) -> Message {
+        match self {
+            Self::UserNameError(e) => e.message(),
+            Self::MailAddressError(e) => e.message(),
+            Self::PasswordError(e) => e.message(),
+            Self::UserAlreadyExistsError(name) => {
+                Message::new("user-already-exists").arg("name", name.get())
+            }
+            Self::UserIdNotExistsError(id) => {
+                Message::new("user-id-not-exists").arg("id", id.to_string())
+            }
+            Self::Forbidden(e) => e.message(),
+            Self::UserError(e) => e.message(),
+            Self::InvalidVerificationToken => Message::new("verification-token-invalid"),
+            // NOTE: フィールドごとのメッセージはValidationErrorsのシリアライズ時に変換する
+            Self::ValidationErrors(_)
+            | Self::UserIdError(_)
+            | Self::PasswordHasherError(_)
+            | Self::UserRepositoryError(_)
+            | Self::UserServiceError(_)
+            | Self::UserFactoryError(_)
+            | Self::MailVerificationRepositoryError(_)
+            | Self::MailOutboxRepositoryError(_)
+            | Self::ApiKeyRepositoryError(_) => internal_error(),
+        }
+    }
+}
+
+impl Localize for AccountLockUsecaseError {
+    fn message(&self) -> Message {
+        match self {
+            Self::Forbidden(e) => e.message(),
+            Self::UserIdNotExistsError(id) => {
+                Message::new("user-id-not-exists").arg("id", id.to_string())
+            }
+            Self::UserIdError(_)
+            | Self::UserRepositoryError(_)
+            | Self::AccountLockEventRepositoryError(_) => internal_error(),
+        }
+    }
+}
+
+impl Localize for LoginRejection {
+    fn message(&self) -> Message {
+        match self {
+            Self::InvalidCredentials => Message::new("login-invalid-credentials"),
+            Self::TooManyAttempts { .. } => Message::new("login-too-many-attempts"),
+            Self::AccountSuspended => Message::new("login-account-suspended"),
+            Self::InvalidTwoFactorCode => Message::new("two-factor-invalid-code"),
+            Self::InvalidLoginChallenge => Message::new("login-challenge-invalid"),
+        }
+    }
+}
+
+impl Localize for AccessTokenError {
+    fn message(&self) -> Message {
+        match self {
+            Self::EncodeError(detail) => {
+                Message::new("access-token-encode-error").arg("detail", detail.as_str())
+            }
+            Self::InvalidKey(detail) => {
+                Message::new("access-token-invalid-key").arg("detail", detail.as_str())
+            }
+            Self::Expired => Message::new("access-token-expired"),
+            Self::Invalid(detail) => {
+                Message::new("access-token-invalid").arg("detail", detail.as_str())
+            }
+        }
+    }
+}
+
+impl Localize for TotpCredentialError {
+    fn message(&self) -> Message {
+        match self {
+            Self::AlreadyConfirmed => Message::new("two-factor-already-confirmed"),
+            Self::InvalidCode => Message::new("two-factor-invalid-code"),
+        }
+    }
+}
+
+impl Localize for TwoFactorRejection {
+    fn message(&self) -> Message {
+        match self {
+            Self::InvalidCode => Message::new("two-factor-invalid-code"),
+            Self::TooManyAttempts { .. } => Message::new("two-factor-too-many-attempts"),
+        }
+    }
+}
+
+impl Localize for TwoFactorUsecaseError {
+    fn message(&self) -> Message {
+        match self {
+            Self::UserNotFound => Message::new("two-factor-user-not-found"),
+            Self::AlreadyEnabled => Message::new("two-factor-already-enabled"),
+            Self::NotEnabled => Message::new("two-factor-not-enabled"),
+            Self::EnrollmentNotStarted => Message::new("two-factor-enrollment-not-started"),
+            Self::TotpCredentialError(e) => e.message(),
+            Self::UserRepositoryError(_)
+            | Self::TwoFactorRepositoryError(_)
+            | Self::AccountLockEventRepositoryError(_) => internal_error(),
+        }
+    }
+}
+
+impl Localize for PasswordResetPolicyError {
+    fn message(&self) -> Message {
+        match self {
+            Self::TooManyRequests { .. } => Message::new("password-reset-too-many-requests"),
+        }
+    }
+}
+
+impl Localize for PasswordResetUsecaseError {
+    fn message(&self) -> Message {
+        match self {
+            Self::RateLimited(e) => e.message(),
+            Self::InvalidToken => Message::new("password-reset-token-invalid"),
+            Self::MailAddressError(e) => e.message(),
+            Self::PasswordError(e) => e.message(),
+            Self::PasswordHasherError(_)
+            | Self::UserRepositoryError(_)
+            | Self::PasswordResetRepositoryError(_)
+            | Self::SessionRepositoryError(_)
+            | Self::MailOutboxRepositoryError(_) => internal_error(),
+        }
+    }
+}
+
+impl Localize for ApiKeyPolicyError {
+    fn message(&self) -> Message {
+        match self {
+            Self::NotPermitted => Message::new("api-key-not-permitted"),
+            Self::ActorNotFound => Message::new("actor-not-found"),
+        }
+    }
+}
+
+impl Localize for ApiKeyScopeError {
+    fn message(&self) -> Message {
+        match self {
+            Self::UnknownScope(scope) => {
+                Message::new("api-key-unknown-scope").arg("scope", scope.as_str())
+            }
+        }
+    }
+}
+
+impl Localize for ApiKeyError {
+    fn message(&self) -> Message {
+        match self {
+            Self::EmptyName => Message::new("api-key-empty-name"),
+            Self::NameTooLong { max } => Message::new("api-key-name-too-long").arg("max", *max),
+            Self::NoScopes => Message::new("api-key-no-scopes"),
+            Self::AlreadyRevoked => Message::new("api-key-already-revoked"),
+            Self::ApiKeyIdError(_) => internal_error(),
+        }
+    }
+}
+
+impl Localize for ApiKeyUsecaseError {
+    fn message(&self) -> Message {
+        match self {
+            Self::ApiKeyScopeError(e) => e.message(),
+            Self::ApiKeyError(e) => e.message(),
+            Self::Forbidden(e) => e.message(),
+            Self::ApiKeyNotFound => Message::new("api-key-not-found"),
+            Self::ApiKeyIdError(_)
+            | Self::UserRepositoryError(_)
+            | Self::ApiKeyRepositoryError(_) => internal_error(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{UserId, UserName},
+        i18n::Locale,
+    };
+    use rstest::rstest;
+    use uuid::Uuid;
+
+    // NOTE: 日本語のカタログは、ログに出力するDisplayと同じ文言であることを確認する
+    #[rstest]
+    #[case(UserUsecaseError::UserNameError(UserNameError::TooLong { max_length: 20 }))]
+    #[case(UserUsecaseError::MailAddressError(MailAddressError::InvalidFormat))]
+    #[case(UserUsecaseError::PasswordError(PasswordError::TooShort { min_length: 8 }))]
+    #[case(UserUsecaseError::UserAlreadyExistsError(UserName::new("hoge".to_string()).unwrap()))]
+    #[case(UserUsecaseError::UserIdNotExistsError(UserId::new(Uuid::nil()).unwrap()))]
+    #[case(UserUsecaseError::InvalidVerificationToken)]
+    #[case(UserUsecaseError::Forbidden(UserPolicyError::NotPermitted { action: UserAction::Update }))]
+    #[case(UserUsecaseError::Forbidden(UserPolicyError::MailNotVerified { action: UserAction::Suspend }))]
+    #[case(UserUsecaseError::UserError(UserError::UserStatusError(
+        UserStatusError::InvalidTransition { from: "deleted", transition: UserStatusTransition::Reinstate }
+    )))]
+    #[case(UserUsecaseError::UserError(UserError::MailAddressMismatch))]
+    fn ja_matches_display_for_user_errors(#[case] error: UserUsecaseError) {
+        assert_eq!(error.message().localize(Locale::Ja), error.to_string());
+    }
+
+    #[rstest]
+    #[case(LoginRejection::InvalidCredentials)]
+    #[case(LoginRejection::TooManyAttempts { retry_after: chrono::Duration::seconds(30) })]
+    #[case(LoginRejection::InvalidLoginChallenge)]
+    fn ja_matches_display_for_login(#[case] error: LoginRejection) {
+        assert_eq!(error.message().localize(Locale::Ja), error.to_string());
+    }
+
+    #[rstest]
+    #[case(ApiKeyUsecaseError::ApiKeyNotFound)]
+    #[case(ApiKeyUsecaseError::ApiKeyError(ApiKeyError::NameTooLong { max: 64 }))]
+    #[case(ApiKeyUsecaseError::ApiKeyScopeError(ApiKeyScopeError::UnknownScope("admin".to_string())))]
+    #[case(ApiKeyUsecaseError::Forbidden(ApiKeyPolicyError::NotPermitted))]
+    fn ja_matches_display_for_api_keys(#[case] error: ApiKeyUsecaseError) {
+        assert_eq!(error.message().localize(Locale::Ja), error.to_string());
+    }
+
+    #[rstest]
+    #[case(
+        UserPolicyError::NotPermitted { action: UserAction::Delete }.message(),
+        "You do not have permission to delete the user."
+    )]
+    #[case(
+        UserStatusError::InvalidTransition { from: "deleted", transition: UserStatusTransition::Suspend }.message(),
+        "Cannot suspend a user whose status is deleted."
+    )]
+    #[case(
+        UserNameError::TooShort { min_length: 3 }.message(),
+        "The user name must be at least 3 characters."
+    )]
+    #[case(
+        TwoFactorRejection::InvalidCode.message(),
+        "The verification code is incorrect."
+    )]
+    fn en(#[case] message: Message, #[case] expected: &str) {
+        assert_eq!(message.localize(Locale::En), expected);
+    }
+
+    #[rstest]
+    fn internal_errors_are_not_exposed() {
+        let error = UserUsecaseError::UserError(UserError::UserStatusError(
+            UserStatusError::UnknownStatus("unknown".to_string()),
+        ));
+        assert_eq!(error.message().id(), "internal-error");
+    }
+}
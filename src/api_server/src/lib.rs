@@ -1,4 +1,12 @@
+pub mod config;
 pub mod controller;
 pub mod domain;
+pub mod health;
+pub mod i18n;
+pub mod mailer;
+pub mod metrics;
 pub mod repository;
+pub mod shutdown;
+pub mod telemetry;
 pub mod use_case;
+pub mod worker;
@@ -0,0 +1,62 @@
+mod file_mailer;
+mod outbox_dispatcher;
+mod smtp_mailer;
+
+pub use file_mailer::FileMailer;
+pub use outbox_dispatcher::*;
+pub use smtp_mailer::SmtpMailer;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    config::{MailConfig, MailTransport},
+    domain::MailAddress,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mail {
+    pub to: MailAddress,
+    pub subject: String,
+    pub body: String,
+}
+
+#[async_trait]
+pub trait Mailer {
+    async fn send(&self, mail: Mail) -> Result<(), MailerError>;
+}
+
+#[async_trait]
+impl<T> Mailer for Arc<T>
+where
+    T: Mailer + Send + Sync + ?Sized,
+{
+    async fn send(&self, mail: Mail) -> Result<(), MailerError> {
+        (**self).send(mail).await
+    }
+}
+
+pub fn from_config(config: &MailConfig) -> Result<Arc<dyn Mailer + Send + Sync>, MailerError> {
+    Ok(match config.transport {
+        MailTransport::Stdout => Arc::new(FileMailer::stdout(&config.from)?),
+        MailTransport::File => Arc::new(FileMailer::file(&config.from, &config.file_path)?),
+        MailTransport::Smtp => Arc::new(SmtpMailer::new(
+            &config.from,
+            &config.smtp_host,
+            config.smtp_port,
+        )?),
+    })
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MailerError {
+    #[error("メールアドレスの形式が不正です。{0}")]
+    InvalidAddress(String),
+    #[error("メールの作成に失敗しました。{0}")]
+    BuildError(String),
+    #[error("メールの送信に失敗しました。{0}")]
+    SendError(String),
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+}
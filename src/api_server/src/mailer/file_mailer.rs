@@ -0,0 +1,106 @@
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use lettre::message::Mailbox;
+use tokio::{io::AsyncWriteExt as _, sync::Mutex};
+
+use super::{Mail, Mailer, MailerError};
+
+// NOTE: 開発・テスト用。送信する代わりにメールの内容を標準出力かファイルに追記する
+pub struct FileMailer {
+    from: Mailbox,
+    path: Option<PathBuf>,
+    lock: Mutex<()>,
+}
+
+impl FileMailer {
+    pub fn stdout(from: &str) -> Result<Self, MailerError> {
+        Self::new(from, None)
+    }
+
+    pub fn file(from: &str, path: &Path) -> Result<Self, MailerError> {
+        Self::new(from, Some(path.to_path_buf()))
+    }
+
+    fn new(from: &str, path: Option<PathBuf>) -> Result<Self, MailerError> {
+        Ok(Self {
+            from: from.parse().map_err(|e: lettre::address::AddressError| {
+                MailerError::InvalidAddress(e.to_string())
+            })?,
+            path,
+            lock: Mutex::new(()),
+        })
+    }
+
+    fn format(&self, mail: &Mail) -> String {
+        format!(
+            "From: {}\nTo: {}\nSubject: {}\n\n{}\n---\n",
+            self.from,
+            mail.to.get(),
+            mail.subject,
+            mail.body
+        )
+    }
+}
+
+#[async_trait]
+impl Mailer for FileMailer {
+    #[tracing::instrument(name = "FileMailer::send", skip_all, err)]
+    async fn send(&self, mail: Mail) -> Result<(), MailerError> {
+        let content = self.format(&mail);
+        // NOTE: 複数のメールが混ざらないよう書き込みを直列化する
+        let _guard = self.lock.lock().await;
+        match &self.path {
+            Some(path) => {
+                let mut file = tokio::fs::OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(path)
+                    .await?;
+                file.write_all(content.as_bytes()).await?;
+                file.flush().await?;
+            }
+            None => {
+                let mut stdout = tokio::io::stdout();
+                stdout.write_all(content.as_bytes()).await?;
+                stdout.flush().await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::MailAddress;
+
+    #[tokio::test]
+    async fn appends_to_file() {
+        let path = std::env::temp_dir().join(format!("mailer-{}.log", uuid::Uuid::new_v4()));
+        let mailer = FileMailer::file("api_server <noreply@example.com>", &path).unwrap();
+        for subject in ["first", "second"] {
+            mailer
+                .send(Mail {
+                    to: MailAddress::new("hoge@example.com".to_string()).unwrap(),
+                    subject: subject.to_string(),
+                    body: "body".to_string(),
+                })
+                .await
+                .unwrap();
+        }
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(content.contains("To: hoge@example.com\nSubject: first\n\nbody"));
+        assert!(content.contains("Subject: second"));
+    }
+
+    #[rstest::rstest]
+    fn invalid_from() {
+        assert!(matches!(
+            FileMailer::stdout("not an address"),
+            Err(MailerError::InvalidAddress(_))
+        ));
+    }
+}
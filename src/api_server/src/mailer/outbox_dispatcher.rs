@@ -0,0 +1,234 @@
+use async_trait::async_trait;
+
+use crate::{
+    domain::Clock,
+    repository::{MailOutboxRepository, MailOutboxRepositoryError, TransactionManager},
+    worker::BatchJob,
+};
+
+use super::Mailer;
+
+#[derive(Debug, Clone)]
+pub struct MailOutboxSettings {
+    // NOTE: この回数だけ失敗したメールは送信を諦め、last_errorを残したままにする
+    pub max_attempts: u32,
+    // NOTE: 失敗の度に待機時間が倍になる(retry_backoff * 2^(失敗回数 - 1))
+    pub retry_backoff: chrono::Duration,
+    pub max_retry_backoff: chrono::Duration,
+}
+
+impl MailOutboxSettings {
+    fn retry_delay(&self, attempts: u32) -> chrono::Duration {
+        let exponent = attempts.saturating_sub(1).min(16);
+        (self.retry_backoff * 2_i32.pow(exponent)).min(self.max_retry_backoff)
+    }
+}
+
+// NOTE: mail_outboxに登録されたメールを送信するジョブ
+//       業務処理のトランザクションがコミットされた後に送信されるため、ロールバックされたメールは送られない
+pub struct MailOutboxDispatcher<Repo, M, C> {
+    mail_outbox_repository: Repo,
+    mailer: M,
+    clock: C,
+    settings: MailOutboxSettings,
+}
+
+impl<Repo, M, C> MailOutboxDispatcher<Repo, M, C> {
+    pub fn new(
+        mail_outbox_repository: Repo,
+        mailer: M,
+        clock: C,
+        settings: MailOutboxSettings,
+    ) -> Self {
+        Self {
+            mail_outbox_repository,
+            mailer,
+            clock,
+            settings,
+        }
+    }
+}
+
+#[async_trait]
+impl<TM, Repo, M, C> BatchJob<TM> for MailOutboxDispatcher<Repo, M, C>
+where
+    TM: TransactionManager,
+    Repo: MailOutboxRepository<TM> + Send + Sync,
+    M: Mailer + Send + Sync,
+    C: Clock + Send + Sync,
+{
+    type Error = MailOutboxRepositoryError;
+
+    #[tracing::instrument(name = "MailOutboxDispatcher::run_batch", skip_all, err)]
+    async fn run_batch(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        limit: u32,
+    ) -> Result<usize, MailOutboxRepositoryError> {
+        let mails = self
+            .mail_outbox_repository
+            .lock_due(tx, self.clock.now(), self.settings.max_attempts, limit)
+            .await?;
+        let count = mails.len();
+        for outbox_mail in mails {
+            match self.mailer.send(outbox_mail.mail).await {
+                Ok(()) => {
+                    self.mail_outbox_repository
+                        .mark_sent(tx, outbox_mail.id, self.clock.now())
+                        .await?
+                }
+                Err(e) => {
+                    let attempts = outbox_mail.attempts + 1;
+                    tracing::warn!(id = outbox_mail.id, attempts, error = %e, "mail send failed");
+                    let next_attempt_at = self.clock.now() + self.settings.retry_delay(attempts);
+                    self.mail_outbox_repository
+                        .mark_failed(tx, outbox_mail.id, &e.to_string(), next_attempt_at)
+                        .await?
+                }
+            }
+        }
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{FixedClock, MailAddress},
+        mailer::{Mail, MailerError},
+        repository::{NoopTransactionManager, OutboxMail},
+    };
+    use chrono::{DateTime, Utc};
+    use rstest::rstest;
+    use std::sync::Mutex as StdMutex;
+
+    fn settings() -> MailOutboxSettings {
+        MailOutboxSettings {
+            max_attempts: 5,
+            retry_backoff: chrono::Duration::seconds(30),
+            max_retry_backoff: chrono::Duration::minutes(10),
+        }
+    }
+
+    #[rstest]
+    #[case(1, 30)]
+    #[case(2, 60)]
+    #[case(3, 120)]
+    #[case(5, 480)]
+    #[case(6, 600)]
+    #[case(u32::MAX, 600)]
+    fn retry_delay(#[case] attempts: u32, #[case] expected_secs: i64) {
+        assert_eq!(
+            settings().retry_delay(attempts),
+            chrono::Duration::seconds(expected_secs)
+        );
+    }
+
+    type NoopTransaction<'a> = <NoopTransactionManager as TransactionManager>::Transaction<'a>;
+
+    #[derive(Debug, PartialEq)]
+    enum Marked {
+        Sent(i64, DateTime<Utc>),
+        Failed(i64, DateTime<Utc>),
+    }
+
+    #[derive(Default)]
+    struct InMemoryOutbox {
+        due: StdMutex<Vec<OutboxMail>>,
+        marked: StdMutex<Vec<Marked>>,
+    }
+
+    #[async_trait]
+    impl MailOutboxRepository<NoopTransactionManager> for InMemoryOutbox {
+        async fn enqueue(
+            &self,
+            _tx: &mut NoopTransaction<'_>,
+            _mail: Mail,
+            _now: DateTime<Utc>,
+        ) -> Result<(), MailOutboxRepositoryError> {
+            unreachable!()
+        }
+        async fn lock_due(
+            &self,
+            _tx: &mut NoopTransaction<'_>,
+            _now: DateTime<Utc>,
+            _max_attempts: u32,
+            _limit: u32,
+        ) -> Result<Vec<OutboxMail>, MailOutboxRepositoryError> {
+            Ok(std::mem::take(&mut *self.due.lock().unwrap()))
+        }
+        async fn mark_sent(
+            &self,
+            _tx: &mut NoopTransaction<'_>,
+            id: i64,
+            now: DateTime<Utc>,
+        ) -> Result<(), MailOutboxRepositoryError> {
+            self.marked.lock().unwrap().push(Marked::Sent(id, now));
+            Ok(())
+        }
+        async fn mark_failed(
+            &self,
+            _tx: &mut NoopTransaction<'_>,
+            id: i64,
+            _error: &str,
+            next_attempt_at: DateTime<Utc>,
+        ) -> Result<(), MailOutboxRepositoryError> {
+            self.marked
+                .lock()
+                .unwrap()
+                .push(Marked::Failed(id, next_attempt_at));
+            Ok(())
+        }
+    }
+
+    // NOTE: 宛先がrejected@で始まるメールのみ送信に失敗する
+    struct RejectingMailer;
+
+    #[async_trait]
+    impl Mailer for RejectingMailer {
+        async fn send(&self, mail: Mail) -> Result<(), MailerError> {
+            if mail.to.to_string().starts_with("rejected@") {
+                return Err(MailerError::SendError("rejected".to_string()));
+            }
+            Ok(())
+        }
+    }
+
+    fn outbox_mail(id: i64, to: &str, attempts: u32) -> OutboxMail {
+        OutboxMail {
+            id,
+            mail: Mail {
+                to: MailAddress::new(to.to_string()).unwrap(),
+                subject: "subject".to_string(),
+                body: "body".to_string(),
+            },
+            attempts,
+        }
+    }
+
+    #[tokio::test]
+    async fn run_batch_schedules_retry_from_clock() {
+        let now = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let outbox = InMemoryOutbox::default();
+        *outbox.due.lock().unwrap() = vec![
+            outbox_mail(1, "ok@example.com", 0),
+            outbox_mail(2, "rejected@example.com", 2),
+        ];
+        let dispatcher =
+            MailOutboxDispatcher::new(outbox, RejectingMailer, FixedClock(now), settings());
+
+        let count = BatchJob::<NoopTransactionManager>::run_batch(&dispatcher, &mut (), 10)
+            .await
+            .unwrap();
+
+        assert_eq!(count, 2);
+        assert_eq!(
+            *dispatcher.mail_outbox_repository.marked.lock().unwrap(),
+            vec![
+                Marked::Sent(1, now),
+                Marked::Failed(2, now + chrono::Duration::seconds(120)),
+            ]
+        );
+    }
+}
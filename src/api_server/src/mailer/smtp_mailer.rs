@@ -0,0 +1,52 @@
+use async_trait::async_trait;
+use lettre::{
+    message::{header::ContentType, Mailbox},
+    AsyncSmtpTransport, AsyncTransport as _, Message, Tokio1Executor,
+};
+
+use super::{Mail, Mailer, MailerError};
+
+// NOTE: 現状は開発用のSMTPサーバー(mailpit等)を想定し、TLS・認証は行わない
+pub struct SmtpMailer {
+    from: Mailbox,
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+}
+
+impl SmtpMailer {
+    pub fn new(from: &str, host: &str, port: u16) -> Result<Self, MailerError> {
+        Ok(Self {
+            from: from.parse().map_err(|e: lettre::address::AddressError| {
+                MailerError::InvalidAddress(e.to_string())
+            })?,
+            transport: AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host)
+                .port(port)
+                .build(),
+        })
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    #[tracing::instrument(name = "SmtpMailer::send", skip_all, err)]
+    async fn send(&self, mail: Mail) -> Result<(), MailerError> {
+        let to: Mailbox = mail
+            .to
+            .get()
+            .parse()
+            .map_err(|e: lettre::address::AddressError| {
+                MailerError::InvalidAddress(e.to_string())
+            })?;
+        let message = Message::builder()
+            .from(self.from.clone())
+            .to(to)
+            .subject(mail.subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(mail.body)
+            .map_err(|e| MailerError::BuildError(e.to_string()))?;
+        self.transport
+            .send(message)
+            .await
+            .map_err(|e| MailerError::SendError(e.to_string()))?;
+        Ok(())
+    }
+}
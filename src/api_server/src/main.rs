@@ -1,66 +1,134 @@
-use std::sync::Arc;
+use std::{path::PathBuf, sync::Arc, time::Duration};
 
-use actix_web::{App, HttpServer};
-use env_logger::Env;
+use actix_web::{
+    middleware::{from_fn, Condition, Logger},
+    web, App, HttpServer,
+};
+use anyhow::Context as _;
 
 use api_server::*;
 use clap::Parser as _;
+use config::{AppConfig, ConfigOverrides};
 use repository::pg_transaction::PgTransactionManager;
+use sqlx::postgres::PgPoolOptions;
 use tokio::sync::Mutex;
 
+// NOTE: actix-webのデフォルト書式にリクエストIDを付与したもの
+const ACCESS_LOG_FORMAT: &str =
+    r#"%a "%r" %s %b "%{Referer}i" "%{User-Agent}i" %T request_id=%{x-request-id}o"#;
+
 #[derive(Debug, clap::Parser)]
 #[command(version,about,long_about=None)]
 pub struct ApiServerArguments {
-    /// database user
-    #[arg(long, env("DB_USER"))]
-    database_user: String,
-    /// database password
-    #[arg(long, env("DB_PASSWORD"))]
-    database_password: String,
-    /// database host
-    #[arg(long, env("DB_HOST"))]
-    database_host: String,
-    /// database port
-    #[arg(long, env("DB_PORT"))]
-    database_port: u16,
-    /// database name
-    #[arg(long, env("DB_NAME"))]
-    database_name: String,
+    /// path to the TOML configuration file
+    #[arg(long, env("API_SERVER_CONFIG"))]
+    config: Option<PathBuf>,
+    #[command(flatten)]
+    overrides: ConfigOverrides,
+    #[command(subcommand)]
+    command: Option<Command>,
 }
 
-impl ApiServerArguments {
-    fn database_url(&self) -> String {
-        format!(
-            "postgres://{}:{}@{}:{}/{}",
-            self.database_user,
-            self.database_password,
-            self.database_host,
-            self.database_port,
-            self.database_name,
-        )
+#[derive(Debug, clap::Subcommand)]
+enum Command {
+    /// configuration utilities
+    #[command(subcommand)]
+    Config(ConfigCommand),
+    /// one-off maintenance tasks run by an operator
+    #[command(subcommand)]
+    Maintenance(MaintenanceCommand),
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum ConfigCommand {
+    /// print the effective configuration with secrets redacted
+    Print,
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum MaintenanceCommand {
+    /// compute the user name skeletons of users registered before skeletons were introduced
+    BackfillUserNameSkeletons {
+        /// number of users processed per batch
+        #[arg(long, default_value_t = 1000, value_parser = clap::value_parser!(i64).range(1..))]
+        batch_size: i64,
+    },
+}
+
+// NOTE: 設定に応じてユーザーIDの採番方法を選択する
+fn user_id_generator(
+    config: &config::UserConfig,
+) -> Arc<dyn domain::IdGenerator<PgTransactionManager> + Send + Sync> {
+    match config.id_strategy {
+        config::IdStrategy::UuidV7 => Arc::new(domain::UuidV7IdGenerator),
+        config::IdStrategy::Ulid => Arc::new(domain::UlidIdGenerator),
+        config::IdStrategy::Sequence => Arc::new(repository::PgUserIdGenerator::default()),
     }
 }
 
 #[tokio::main]
-async fn main() -> std::io::Result<()> {
-    // 環境変数からDBの接続情報を取得
+async fn main() -> anyhow::Result<()> {
+    // 設定ファイル・環境変数・CLI引数から設定を取得
     let args = ApiServerArguments::parse();
+    let config = AppConfig::load(args.config.as_deref(), &args.overrides)?;
+
+    if let Some(Command::Config(ConfigCommand::Print)) = args.command {
+        print!("{}", config.redacted().to_toml()?);
+        return Ok(());
+    }
+    config.validate()?;
 
-    env_logger::init_from_env(Env::default().default_filter_or("info"));
+    telemetry::init(&config.log)?;
 
     // DB接続プールの作成
     let pool = Arc::new(
-        sqlx::PgPool::connect(&args.database_url())
+        PgPoolOptions::new()
+            .max_connections(config.database.max_connections)
+            .acquire_timeout(Duration::from_secs(config.database.acquire_timeout_secs))
+            .connect_with(config.database.connect_options())
             .await
-            .expect("database connection failed"),
+            .context("database connection failed")?,
     );
 
-    let tm = Arc::new(Mutex::new(PgTransactionManager::new(pool)));
+    // NOTE: 全てのレプリカの起動時ではなく、リリース時に運用者が一度だけ実行する
+    if let Some(Command::Maintenance(MaintenanceCommand::BackfillUserNameSkeletons {
+        batch_size,
+    })) = args.command
+    {
+        let count =
+            repository::PgUserRepository::backfill_user_name_skeletons(&pool, batch_size).await?;
+        tracing::info!(count, "backfilled user name skeletons");
+        return Ok(());
+    }
+
+    metrics::metrics().register_pg_pool(metrics::PgPoolCollector::new(pool.clone()))?;
+
+    let health = Arc::new(health::HealthChecker::new(
+        pool.clone(),
+        Duration::from_millis(config.health.probe_timeout_ms),
+    ));
+
+    let tm = Arc::new(Mutex::new(PgTransactionManager::new(pool.clone())));
 
     // リポジトリの作成
     let user_repository = repository::PgUserRepository {};
+    let session_repository = repository::PgSessionRepository {};
+    let mail_verification_repository = repository::PgMailVerificationRepository {};
+    let password_reset_repository = repository::PgPasswordResetRepository {};
+    let login_throttle_repository = repository::PgLoginThrottleRepository {};
+    let account_lock_event_repository = repository::PgAccountLockEventRepository {};
+    let two_factor_repository = repository::PgTwoFactorRepository {};
+    let api_key_repository = repository::PgApiKeyRepository {};
+    let mail_outbox_repository = repository::PgMailOutboxRepository {};
 
-    let user_factory = domain::DefaultUserFactory::default();
+    let mailer = mailer::from_config(&config.mail)?;
+
+    let user_factory = domain::DefaultUserFactory::new(user_id_generator(&config.user));
+    let password_hasher = domain::Argon2PasswordHasher::new(domain::Argon2Params {
+        memory_kib: config.auth.password_hash.memory_kib,
+        iterations: config.auth.password_hash.iterations,
+        parallelism: config.auth.password_hash.parallelism,
+    })?;
 
     // サービスの作成
     let user_service = domain::UserService::new(user_repository.clone());
@@ -68,15 +136,213 @@ async fn main() -> std::io::Result<()> {
         user_factory,
         user_repository.clone(),
         user_service,
+        config.user.name_policy.load()?,
+        password_hasher.clone(),
+        mail_verification_repository,
+        mail_outbox_repository.clone(),
+        api_key_repository.clone(),
+        domain::SystemClock,
+        use_case::MailVerificationSettings {
+            ttl: chrono::Duration::seconds(config.auth.mail_verification_ttl_secs as i64),
+            url_template: config.mail.verification_url.clone(),
+            revert_ttl: chrono::Duration::seconds(config.auth.mail_change_revert_ttl_secs as i64),
+            revert_url_template: config.mail.revert_url.clone(),
+        },
+    ));
+    let access_token_codec = domain::JwtAccessTokenCodec::new(
+        config.auth.access_token.load_keys()?,
+        config.auth.access_token.issuer.clone(),
+        chrono::Duration::seconds(config.auth.access_token.ttl_secs as i64),
+    );
+    let access_token_verifier: Arc<controller::authentication::AccessTokenVerifier> =
+        Arc::new(access_token_codec.clone());
+    let password_reset_usecase = Arc::new(use_case::PasswordResetUseCaseImpl::new(
+        user_repository.clone(),
+        password_reset_repository,
+        session_repository.clone(),
+        password_hasher.clone(),
+        mail_outbox_repository.clone(),
+        domain::SystemClock,
+        use_case::PasswordResetSettings {
+            ttl: chrono::Duration::seconds(config.auth.password_reset.ttl_secs as i64),
+            url_template: config.mail.password_reset_url.clone(),
+            rate_limit: config.auth.password_reset.rate_limit(),
+        },
+    ));
+    let account_lock_usecase = Arc::new(use_case::AccountLockUseCaseImpl::new(
+        user_repository.clone(),
+        account_lock_event_repository.clone(),
+        domain::SystemClock,
+    ));
+    let api_key_usecase = Arc::new(use_case::ApiKeyUseCaseImpl::new(
+        user_repository.clone(),
+        api_key_repository,
+        domain::SystemClock,
+    ));
+    let api_key_verifier: Arc<controller::authentication::ApiKeyVerifier> =
+        Arc::new(controller::authentication::UsecaseApiKeyAuthenticator::new(
+            tm.clone(),
+            api_key_usecase.clone(),
+        ));
+    let two_factor_usecase = Arc::new(use_case::TwoFactorUseCaseImpl::new(
+        user_repository.clone(),
+        two_factor_repository.clone(),
+        account_lock_event_repository.clone(),
+        domain::SystemClock,
+        use_case::TwoFactorSettings {
+            issuer: config.auth.two_factor.issuer.clone(),
+            totp: config.auth.two_factor.totp(),
+            recovery_code_count: config.auth.two_factor.recovery_code_count,
+            login_throttle: config.auth.login_throttle.policy(),
+        },
+    ));
+    let session_usecase = Arc::new(use_case::SessionUseCaseImpl::new(
+        user_repository.clone(),
+        session_repository,
+        login_throttle_repository,
+        account_lock_event_repository,
+        two_factor_repository,
+        password_hasher,
+        access_token_codec,
+        domain::SystemClock,
+        use_case::SessionSettings {
+            refresh_token_ttl: chrono::Duration::seconds(config.auth.refresh_token_ttl_secs as i64),
+            login_throttle: config.auth.login_throttle.policy(),
+            totp: config.auth.two_factor.totp(),
+            login_challenge_ttl: chrono::Duration::seconds(
+                config.auth.two_factor.login_challenge_ttl_secs as i64,
+            ),
+        },
     ));
 
+    let mut workers = worker::BackgroundWorkers::default();
+    let mail_outbox_dispatcher = mailer::MailOutboxDispatcher::new(
+        mail_outbox_repository,
+        mailer,
+        domain::SystemClock,
+        config.mail.outbox.settings(),
+    );
+    workers.spawn("password_reset_issue", |token| {
+        worker::run_batch_job(
+            token,
+            tm.clone(),
+            password_reset_usecase.clone(),
+            config.auth.password_reset.issue_interval(),
+            config.auth.password_reset.issue_batch_size,
+        )
+    });
+    workers.spawn("mail_outbox", |token| {
+        worker::run_batch_job(
+            token,
+            tm.clone(),
+            mail_outbox_dispatcher,
+            config.mail.outbox.poll_interval(),
+            config.mail.outbox.batch_size,
+        )
+    });
+    let request_tracker = Arc::new(shutdown::RequestTracker::default());
+
     // Actix Web アプリケーションの起動
-    HttpServer::new(move || {
-        App::new().configure(|cfg| {
-            controller::user_controller::config(cfg, user_usecase.clone(), tm.clone())
-        })
+    let app_config = config.clone();
+    let app_health = health.clone();
+    let app_request_tracker = request_tracker.clone();
+    let mut server = HttpServer::new(move || {
+        App::new()
+            .app_data(web::Data::from(app_request_tracker.clone()))
+            .app_data(web::Data::from(access_token_verifier.clone()))
+            .app_data(web::Data::from(api_key_verifier.clone()))
+            .wrap(from_fn(controller::middleware::locale))
+            .wrap(from_fn(controller::middleware::request_tracking))
+            .wrap(from_fn(controller::middleware::http_metrics))
+            .wrap(from_fn(controller::middleware::request_id))
+            .wrap(Condition::new(
+                app_config.features.access_log,
+                Logger::new(ACCESS_LOG_FORMAT),
+            ))
+            .wrap(app_config.cors.to_cors())
+            .app_data(web::JsonConfig::default().limit(app_config.limits.json_payload_bytes))
+            .configure(|cfg| {
+                controller::user_controller::config(
+                    cfg,
+                    user_usecase.clone(),
+                    tm.clone(),
+                    &app_config.features,
+                );
+                controller::session_controller::config(cfg, session_usecase.clone(), tm.clone());
+                controller::two_factor_controller::config(
+                    cfg,
+                    two_factor_usecase.clone(),
+                    tm.clone(),
+                );
+                controller::api_key_controller::config(cfg, api_key_usecase.clone(), tm.clone());
+                controller::account_lock_controller::config(
+                    cfg,
+                    account_lock_usecase.clone(),
+                    tm.clone(),
+                );
+                controller::password_reset_controller::config(
+                    cfg,
+                    password_reset_usecase.clone(),
+                    tm.clone(),
+                );
+                controller::health_controller::config(cfg, app_health.clone());
+                if app_config.features.metrics {
+                    controller::metrics_controller::config(cfg);
+                }
+            })
     })
-    .bind("0.0.0.0:8080")?
-    .run()
-    .await
+    .max_connections(config.limits.max_connections)
+    .client_request_timeout(Duration::from_millis(
+        config.limits.client_request_timeout_ms,
+    ))
+    .shutdown_timeout(config.server.shutdown_timeout_secs)
+    .disable_signals();
+    if let Some(workers) = config.server.workers {
+        server = server.workers(workers);
+    }
+    let server = server.bind(&config.server.bind)?.run();
+    let server_handle = server.handle();
+    let mut server_task = tokio::spawn(server);
+
+    let signal = tokio::select! {
+        res = &mut server_task => {
+            // NOTE: シグナル以外でサーバーが停止した場合
+            res??;
+            return Ok(());
+        }
+        signal = shutdown::wait_for_signal() => signal,
+    };
+
+    // グレースフルシャットダウン
+    // NOTE: readinessを先に失敗させ、ロードバランサから外れるのを待ってから受付を止める
+    health.mark_shutting_down();
+    // NOTE: 稼働中にクライアントの切断で破棄されたリクエストは除き、シャットダウンで中断された分のみを報告する
+    let aborted_before_shutdown = request_tracker.aborted();
+    tracing::info!(
+        signal,
+        in_flight_requests = request_tracker.in_flight(),
+        "shutdown started"
+    );
+    tokio::time::sleep(Duration::from_millis(config.server.shutdown_delay_ms)).await;
+    server_handle.stop(true).await;
+    server_task.await??;
+
+    let drain_timeout = Duration::from_secs(config.server.shutdown_timeout_secs);
+    let aborted_workers = workers.shutdown(drain_timeout).await;
+    // NOTE: 中断されたリクエストのトランザクションは接続の返却時にロールバックされる
+    pool.close().await;
+
+    let aborted_requests = request_tracker
+        .aborted()
+        .saturating_sub(aborted_before_shutdown);
+    if aborted_requests > 0 || aborted_workers > 0 {
+        tracing::warn!(
+            aborted_requests,
+            aborted_workers,
+            "shutdown completed with aborted work"
+        );
+    } else {
+        tracing::info!("shutdown completed");
+    }
+    Ok(())
 }
@@ -0,0 +1,178 @@
+mod pg_pool_collector;
+
+pub use pg_pool_collector::PgPoolCollector;
+
+use std::{sync::LazyLock, time::Duration};
+
+use prometheus::{
+    Encoder as _, Histogram, HistogramOpts, HistogramVec, IntCounterVec, IntGauge, Opts, Registry,
+    TextEncoder,
+};
+
+use crate::use_case::UsecaseErrorKind;
+
+// NOTE: TransactionManager::commit等は&selfを受け取らないため、グローバルに保持する
+static METRICS: LazyLock<Metrics> = LazyLock::new(Metrics::new);
+
+pub fn metrics() -> &'static Metrics {
+    &METRICS
+}
+
+pub struct Metrics {
+    registry: Registry,
+    http_requests_total: IntCounterVec,
+    http_request_duration_seconds: HistogramVec,
+    usecase_results_total: IntCounterVec,
+    db_transactions_total: IntCounterVec,
+    db_transactions_open: IntGauge,
+    db_pool_acquire_duration_seconds: Histogram,
+}
+
+impl Metrics {
+    fn new() -> Self {
+        let registry = Registry::new();
+        let http_requests_total = IntCounterVec::new(
+            Opts::new("http_requests_total", "Number of HTTP requests"),
+            &["method", "route", "status"],
+        )
+        .expect("valid metric");
+        let http_request_duration_seconds = HistogramVec::new(
+            HistogramOpts::new(
+                "http_request_duration_seconds",
+                "HTTP request latency in seconds",
+            ),
+            &["method", "route", "status"],
+        )
+        .expect("valid metric");
+        let usecase_results_total = IntCounterVec::new(
+            Opts::new("usecase_results_total", "Number of use case executions"),
+            &["usecase", "result", "error"],
+        )
+        .expect("valid metric");
+        let db_transactions_total = IntCounterVec::new(
+            Opts::new("db_transactions_total", "Number of finished transactions"),
+            &["outcome"],
+        )
+        .expect("valid metric");
+        let db_transactions_open = IntGauge::new(
+            "db_transactions_open",
+            "Number of transactions neither committed nor rolled back",
+        )
+        .expect("valid metric");
+        let db_pool_acquire_duration_seconds = Histogram::with_opts(HistogramOpts::new(
+            "db_pool_acquire_duration_seconds",
+            "Time spent waiting for a pooled connection to begin a transaction",
+        ))
+        .expect("valid metric");
+
+        for collector in [
+            Box::new(http_requests_total.clone()) as Box<dyn prometheus::core::Collector>,
+            Box::new(http_request_duration_seconds.clone()),
+            Box::new(usecase_results_total.clone()),
+            Box::new(db_transactions_total.clone()),
+            Box::new(db_transactions_open.clone()),
+            Box::new(db_pool_acquire_duration_seconds.clone()),
+        ] {
+            registry.register(collector).expect("unique metric");
+        }
+
+        Self {
+            registry,
+            http_requests_total,
+            http_request_duration_seconds,
+            usecase_results_total,
+            db_transactions_total,
+            db_transactions_open,
+            db_pool_acquire_duration_seconds,
+        }
+    }
+
+    pub fn register_pg_pool(&self, collector: PgPoolCollector) -> Result<(), prometheus::Error> {
+        self.registry.register(Box::new(collector))
+    }
+
+    pub fn observe_http_request(&self, method: &str, route: &str, status: u16, elapsed: Duration) {
+        let status = status.to_string();
+        let labels = [method, route, status.as_str()];
+        self.http_requests_total.with_label_values(&labels).inc();
+        self.http_request_duration_seconds
+            .with_label_values(&labels)
+            .observe(elapsed.as_secs_f64());
+    }
+
+    pub fn observe_usecase<T, E: UsecaseErrorKind>(&self, usecase: &str, result: &Result<T, E>) {
+        let labels = match result {
+            Ok(_) => [usecase, "success", ""],
+            Err(e) => [usecase, "error", e.kind()],
+        };
+        self.usecase_results_total.with_label_values(&labels).inc();
+    }
+
+    pub fn observe_transaction_begin(&self) {
+        self.db_transactions_open.inc();
+    }
+
+    pub fn observe_transaction(&self, outcome: TransactionOutcome) {
+        self.db_transactions_open.dec();
+        self.db_transactions_total
+            .with_label_values(&[outcome.as_str()])
+            .inc();
+    }
+
+    pub fn observe_pool_acquire(&self, elapsed: Duration) {
+        self.db_pool_acquire_duration_seconds
+            .observe(elapsed.as_secs_f64());
+    }
+
+    pub fn encode(&self) -> Result<String, prometheus::Error> {
+        let mut buf = Vec::new();
+        TextEncoder::new().encode(&self.registry.gather(), &mut buf)?;
+        Ok(String::from_utf8_lossy(&buf).into_owned())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionOutcome {
+    Commit,
+    Rollback,
+    CommitFailed,
+    RollbackFailed,
+}
+
+impl TransactionOutcome {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Commit => "commit",
+            Self::Rollback => "rollback",
+            Self::CommitFailed => "commit_failed",
+            Self::RollbackFailed => "rollback_failed",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::UserNameError, use_case::UserUsecaseError};
+    use rstest::rstest;
+
+    #[rstest]
+    fn encode_contains_observed_values() {
+        let metrics = Metrics::new();
+        metrics.observe_http_request("POST", "/users", 200, Duration::from_millis(5));
+        metrics.observe_usecase::<(), _>(
+            "register",
+            &Err(UserUsecaseError::UserNameError(UserNameError::Empty)),
+        );
+        metrics.observe_transaction(TransactionOutcome::Rollback);
+
+        let text = metrics.encode().unwrap();
+        assert!(
+            text.contains(r#"http_requests_total{method="POST",route="/users",status="200"} 1"#)
+        );
+        assert!(text.contains(
+            r#"usecase_results_total{error="UserNameError",result="error",usecase="register"} 1"#
+        ));
+        assert!(text.contains(r#"db_transactions_total{outcome="rollback"} 1"#));
+    }
+}
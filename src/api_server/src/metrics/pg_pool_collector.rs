@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use prometheus::{
+    core::{Collector, Desc},
+    proto::MetricFamily,
+    IntGauge,
+};
+use sqlx::PgPool;
+
+// NOTE: スクレイプ時にPgPoolの状態を読み取って値を更新する
+pub struct PgPoolCollector {
+    pool: Arc<PgPool>,
+    size: IntGauge,
+    idle: IntGauge,
+    max_size: IntGauge,
+}
+
+impl PgPoolCollector {
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        Self {
+            pool,
+            size: IntGauge::new("db_pool_connections", "Number of open pooled connections")
+                .expect("valid metric"),
+            idle: IntGauge::new(
+                "db_pool_idle_connections",
+                "Number of idle pooled connections",
+            )
+            .expect("valid metric"),
+            max_size: IntGauge::new(
+                "db_pool_max_connections",
+                "Maximum number of pooled connections",
+            )
+            .expect("valid metric"),
+        }
+    }
+}
+
+impl Collector for PgPoolCollector {
+    fn desc(&self) -> Vec<&Desc> {
+        [&self.size, &self.idle, &self.max_size]
+            .into_iter()
+            .flat_map(|gauge| gauge.desc())
+            .collect()
+    }
+
+    fn collect(&self) -> Vec<MetricFamily> {
+        self.size.set(self.pool.size().into());
+        self.idle.set(self.pool.num_idle() as i64);
+        self.max_size
+            .set(self.pool.options().get_max_connections().into());
+        [&self.size, &self.idle, &self.max_size]
+            .into_iter()
+            .flat_map(|gauge| gauge.collect())
+            .collect()
+    }
+}
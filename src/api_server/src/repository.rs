@@ -1,7 +1,23 @@
+mod account_lock_event_repository;
+mod api_key_repository;
 mod error;
+mod login_throttle_repository;
+mod mail_outbox_repository;
+mod mail_verification_repository;
+mod password_reset_repository;
+mod session_repository;
 mod transaction;
+mod two_factor_repository;
 mod user_repository;
 
+pub use account_lock_event_repository::*;
+pub use api_key_repository::*;
 pub use error::*;
+pub use login_throttle_repository::*;
+pub use mail_outbox_repository::*;
+pub use mail_verification_repository::*;
+pub use password_reset_repository::*;
+pub use session_repository::*;
 pub use transaction::*;
+pub use two_factor_repository::*;
 pub use user_repository::*;
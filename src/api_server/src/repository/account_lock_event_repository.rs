@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+
+use crate::domain::AccountLockEvent;
+
+mod pg_account_lock_event_repository;
+pub use pg_account_lock_event_repository::PgAccountLockEventRepository;
+
+use super::{database_error::DatabaseError, TransactionManager};
+
+// NOTE: 監査記録のため追記のみ行う
+#[async_trait]
+pub trait AccountLockEventRepository<TM>
+where
+    TM: TransactionManager,
+{
+    async fn save(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        event: AccountLockEvent,
+    ) -> Result<(), AccountLockEventRepositoryError>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AccountLockEventRepositoryError {
+    #[error(transparent)]
+    DatabaseError(#[from] DatabaseError),
+}
@@ -0,0 +1,35 @@
+use async_trait::async_trait;
+use sqlx::{Postgres, Transaction};
+
+use crate::{
+    domain::AccountLockEvent,
+    repository::{database_error::DatabaseError, pg_transaction::PgTransactionManager},
+};
+
+use super::{AccountLockEventRepository, AccountLockEventRepositoryError};
+
+#[derive(Clone)]
+pub struct PgAccountLockEventRepository {}
+
+#[async_trait]
+impl AccountLockEventRepository<PgTransactionManager> for PgAccountLockEventRepository {
+    #[tracing::instrument(name = "PgAccountLockEventRepository::save", skip_all, fields(user_id = %event.user_id, kind = event.kind.as_str()), err)]
+    async fn save(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        event: AccountLockEvent,
+    ) -> Result<(), AccountLockEventRepositoryError> {
+        sqlx::query!(
+            "INSERT INTO account_lock_events (user_id, kind, actor_id, locked_until, occurred_at) VALUES ($1, $2, $3, $4, $5)",
+            event.user_id.get(),
+            event.kind.as_str(),
+            event.actor_id.map(|actor_id| actor_id.get()),
+            event.locked_until,
+            event.occurred_at,
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use api_key_dto::ApiKeyDomainToDtoConversionError;
+use async_trait::async_trait;
+
+use crate::domain::{ApiKey, ApiKeyId, SecretTokenHash};
+
+mod api_key_dto;
+mod pg_api_key_repository;
+pub use pg_api_key_repository::PgApiKeyRepository;
+
+use super::{database_error::DatabaseError, TransactionManager};
+
+#[async_trait]
+pub trait ApiKeyRepository<TM>
+where
+    TM: TransactionManager,
+{
+    // NOTE: 失効済みのものも返す。有効性の判定は呼び出し側で行う
+    async fn find_by_key_hash(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        key_hash: &SecretTokenHash,
+    ) -> Result<Option<ApiKey>, ApiKeyRepositoryError>;
+    async fn find_by_id(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        id: &ApiKeyId,
+    ) -> Result<Option<ApiKey>, ApiKeyRepositoryError>;
+    // NOTE: 発行日時の新しい順に返す
+    async fn find_all(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+    ) -> Result<Vec<ApiKey>, ApiKeyRepositoryError>;
+    async fn save(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        api_key: ApiKey,
+    ) -> Result<(), ApiKeyRepositoryError>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ApiKeyRepositoryError {
+    #[error(transparent)]
+    DatabaseError(#[from] DatabaseError),
+    #[error(transparent)]
+    ConversionError(#[from] ApiKeyDomainToDtoConversionError),
+}
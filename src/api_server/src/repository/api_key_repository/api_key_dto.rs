@@ -0,0 +1,75 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::domain::{
+    ApiKey, ApiKeyId, ApiKeyIdError, ApiKeyScope, ApiKeyScopeError, SecretTokenError,
+    SecretTokenHash, UserId, UserIdError,
+};
+
+#[derive(FromRow)]
+pub struct ApiKeyDto {
+    pub api_key_id: Uuid,
+    pub name: String,
+    pub prefix: String,
+    pub key_hash: Vec<u8>,
+    pub scopes: Vec<String>,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl From<ApiKey> for ApiKeyDto {
+    fn from(value: ApiKey) -> Self {
+        Self {
+            api_key_id: value.id.get(),
+            name: value.name,
+            prefix: value.prefix,
+            key_hash: value.key_hash.into_inner(),
+            scopes: value
+                .scopes
+                .iter()
+                .map(|scope| scope.as_str().to_string())
+                .collect(),
+            created_by: value.created_by.get(),
+            created_at: value.created_at,
+            last_used_at: value.last_used_at,
+            revoked_at: value.revoked_at,
+        }
+    }
+}
+
+impl TryInto<ApiKey> for ApiKeyDto {
+    type Error = ApiKeyDomainToDtoConversionError;
+
+    fn try_into(self) -> Result<ApiKey, Self::Error> {
+        Ok(ApiKey {
+            id: ApiKeyId::new(self.api_key_id)?,
+            name: self.name,
+            prefix: self.prefix,
+            key_hash: SecretTokenHash::new(self.key_hash)?,
+            scopes: self
+                .scopes
+                .iter()
+                .map(|scope| ApiKeyScope::new(scope))
+                .collect::<Result<_, _>>()?,
+            created_by: UserId::new(self.created_by)?,
+            created_at: self.created_at,
+            last_used_at: self.last_used_at,
+            revoked_at: self.revoked_at,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ApiKeyDomainToDtoConversionError {
+    #[error(transparent)]
+    ApiKeyIdError(#[from] ApiKeyIdError),
+    #[error(transparent)]
+    ApiKeyScopeError(#[from] ApiKeyScopeError),
+    #[error(transparent)]
+    UserIdError(#[from] UserIdError),
+    #[error(transparent)]
+    SecretTokenError(#[from] SecretTokenError),
+}
@@ -0,0 +1,99 @@
+use async_trait::async_trait;
+use sqlx::{Postgres, Transaction};
+
+use crate::{
+    domain::{ApiKey, ApiKeyId, SecretTokenHash},
+    repository::{
+        api_key_repository::api_key_dto::ApiKeyDto, database_error::DatabaseError,
+        pg_transaction::PgTransactionManager,
+    },
+};
+
+use super::{ApiKeyRepository, ApiKeyRepositoryError};
+
+#[derive(Clone)]
+pub struct PgApiKeyRepository {}
+
+#[async_trait]
+impl ApiKeyRepository<PgTransactionManager> for PgApiKeyRepository {
+    #[tracing::instrument(name = "PgApiKeyRepository::find_by_key_hash", skip_all, err)]
+    async fn find_by_key_hash(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        key_hash: &SecretTokenHash,
+    ) -> Result<Option<ApiKey>, ApiKeyRepositoryError> {
+        let api_key_dto = sqlx::query_as!(
+            ApiKeyDto,
+            "SELECT api_key_id, name, prefix, key_hash, scopes, created_by, created_at, last_used_at, revoked_at FROM api_keys WHERE key_hash = $1",
+            key_hash.get(),
+        )
+        .fetch_optional(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        api_key_dto
+            .map(|api_key_dto| Ok(api_key_dto.try_into()?))
+            .transpose()
+    }
+
+    #[tracing::instrument(name = "PgApiKeyRepository::find_by_id", skip(self, tx), fields(api_key_id = %id), err)]
+    async fn find_by_id(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        id: &ApiKeyId,
+    ) -> Result<Option<ApiKey>, ApiKeyRepositoryError> {
+        let api_key_dto = sqlx::query_as!(
+            ApiKeyDto,
+            "SELECT api_key_id, name, prefix, key_hash, scopes, created_by, created_at, last_used_at, revoked_at FROM api_keys WHERE api_key_id = $1",
+            id.get(),
+        )
+        .fetch_optional(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        api_key_dto
+            .map(|api_key_dto| Ok(api_key_dto.try_into()?))
+            .transpose()
+    }
+
+    #[tracing::instrument(name = "PgApiKeyRepository::find_all", skip_all, err)]
+    async fn find_all(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+    ) -> Result<Vec<ApiKey>, ApiKeyRepositoryError> {
+        let api_key_dtos = sqlx::query_as!(
+            ApiKeyDto,
+            "SELECT api_key_id, name, prefix, key_hash, scopes, created_by, created_at, last_used_at, revoked_at FROM api_keys ORDER BY created_at DESC",
+        )
+        .fetch_all(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        api_key_dtos
+            .into_iter()
+            .map(|api_key_dto| Ok(api_key_dto.try_into()?))
+            .collect()
+    }
+
+    #[tracing::instrument(name = "PgApiKeyRepository::save", skip_all, fields(api_key_id = %api_key.id), err)]
+    async fn save(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        api_key: ApiKey,
+    ) -> Result<(), ApiKeyRepositoryError> {
+        let dto = ApiKeyDto::from(api_key);
+        sqlx::query!(
+            "INSERT INTO api_keys (api_key_id, name, prefix, key_hash, scopes, created_by, created_at, last_used_at, revoked_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9) ON CONFLICT (api_key_id) DO UPDATE SET last_used_at = $8, revoked_at = $9",
+            dto.api_key_id,
+            dto.name,
+            dto.prefix,
+            dto.key_hash,
+            &dto.scopes,
+            dto.created_by,
+            dto.created_at,
+            dto.last_used_at,
+            dto.revoked_at,
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        Ok(())
+    }
+}
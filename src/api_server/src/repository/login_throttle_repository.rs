@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+
+use crate::domain::LoginThrottle;
+
+mod login_throttle_dto;
+mod pg_login_throttle_repository;
+pub use pg_login_throttle_repository::PgLoginThrottleRepository;
+
+use super::{database_error::DatabaseError, TransactionManager};
+
+#[async_trait]
+pub trait LoginThrottleRepository<TM>
+where
+    TM: TransactionManager,
+{
+    async fn find_by_ip_address(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        ip_address: &str,
+    ) -> Result<Option<LoginThrottle>, LoginThrottleRepositoryError>;
+    // NOTE: 失敗回数を加算し、加算後の状態を返す。前回の失敗からwindow以上経過していた場合は1から数え直す
+    async fn record_failure(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        ip_address: &str,
+        now: DateTime<Utc>,
+        window: Duration,
+    ) -> Result<LoginThrottle, LoginThrottleRepositoryError>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum LoginThrottleRepositoryError {
+    #[error(transparent)]
+    DatabaseError(#[from] DatabaseError),
+}
@@ -0,0 +1,21 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+
+use crate::domain::LoginThrottle;
+
+#[derive(FromRow)]
+pub struct LoginThrottleDto {
+    pub ip_address: String,
+    pub failed_count: i32,
+    pub last_failed_at: DateTime<Utc>,
+}
+
+impl From<LoginThrottleDto> for LoginThrottle {
+    fn from(value: LoginThrottleDto) -> Self {
+        Self {
+            ip_address: value.ip_address,
+            failed_count: value.failed_count.try_into().unwrap_or_default(),
+            last_failed_at: value.last_failed_at,
+        }
+    }
+}
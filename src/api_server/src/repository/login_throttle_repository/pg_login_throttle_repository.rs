@@ -0,0 +1,60 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use sqlx::{Postgres, Transaction};
+
+use crate::{
+    domain::LoginThrottle,
+    repository::{
+        database_error::DatabaseError,
+        login_throttle_repository::login_throttle_dto::LoginThrottleDto,
+        pg_transaction::PgTransactionManager,
+    },
+};
+
+use super::{LoginThrottleRepository, LoginThrottleRepositoryError};
+
+#[derive(Clone)]
+pub struct PgLoginThrottleRepository {}
+
+#[async_trait]
+impl LoginThrottleRepository<PgTransactionManager> for PgLoginThrottleRepository {
+    #[tracing::instrument(name = "PgLoginThrottleRepository::find_by_ip_address", skip_all, err)]
+    async fn find_by_ip_address(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        ip_address: &str,
+    ) -> Result<Option<LoginThrottle>, LoginThrottleRepositoryError> {
+        let dto = sqlx::query_as!(
+            LoginThrottleDto,
+            "SELECT ip_address, failed_count, last_failed_at FROM login_throttles WHERE ip_address = $1",
+            ip_address,
+        )
+        .fetch_optional(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        Ok(dto.map(LoginThrottle::from))
+    }
+
+    // NOTE: 同時に失敗した場合も数え漏らさないよう、読み取った値ではなく行の現在の値に加算する
+    #[tracing::instrument(name = "PgLoginThrottleRepository::record_failure", skip_all, err)]
+    async fn record_failure(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        ip_address: &str,
+        now: DateTime<Utc>,
+        window: Duration,
+    ) -> Result<LoginThrottle, LoginThrottleRepositoryError> {
+        let window_start = now - window;
+        let dto = sqlx::query_as!(
+            LoginThrottleDto,
+            "INSERT INTO login_throttles (ip_address, failed_count, last_failed_at) VALUES ($1, 1, $2) ON CONFLICT (ip_address) DO UPDATE SET failed_count = CASE WHEN login_throttles.last_failed_at <= $3 THEN 1 ELSE login_throttles.failed_count + 1 END, last_failed_at = $2 RETURNING ip_address, failed_count, last_failed_at",
+            ip_address,
+            now,
+            window_start,
+        )
+        .fetch_one(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        Ok(dto.into())
+    }
+}
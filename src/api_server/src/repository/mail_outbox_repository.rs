@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use mail_outbox_dto::MailOutboxDomainToDtoConversionError;
+
+use crate::mailer::Mail;
+
+mod mail_outbox_dto;
+mod pg_mail_outbox_repository;
+pub use pg_mail_outbox_repository::PgMailOutboxRepository;
+
+use super::{database_error::DatabaseError, TransactionManager};
+
+// NOTE: 送信待ちのメール。idはmark_sent・mark_failedで使用する
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutboxMail {
+    pub id: i64,
+    pub mail: Mail,
+    pub attempts: u32,
+}
+
+#[async_trait]
+pub trait MailOutboxRepository<TM>
+where
+    TM: TransactionManager,
+{
+    // NOTE: 業務処理と同じトランザクションで登録し、コミットされた場合のみ送信される
+    async fn enqueue(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        mail: Mail,
+        now: DateTime<Utc>,
+    ) -> Result<(), MailOutboxRepositoryError>;
+    // NOTE: 取得した行はトランザクションの終了までロックされ、他のワーカーからは取得されない
+    async fn lock_due(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        now: DateTime<Utc>,
+        max_attempts: u32,
+        limit: u32,
+    ) -> Result<Vec<OutboxMail>, MailOutboxRepositoryError>;
+    async fn mark_sent(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        id: i64,
+        now: DateTime<Utc>,
+    ) -> Result<(), MailOutboxRepositoryError>;
+    async fn mark_failed(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        id: i64,
+        error: &str,
+        next_attempt_at: DateTime<Utc>,
+    ) -> Result<(), MailOutboxRepositoryError>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MailOutboxRepositoryError {
+    #[error(transparent)]
+    DatabaseError(#[from] DatabaseError),
+    #[error(transparent)]
+    ConversionError(#[from] MailOutboxDomainToDtoConversionError),
+}
@@ -0,0 +1,38 @@
+use sqlx::FromRow;
+
+use crate::{
+    domain::{MailAddress, MailAddressError},
+    mailer::Mail,
+    repository::OutboxMail,
+};
+
+#[derive(FromRow)]
+pub struct MailOutboxDto {
+    pub id: i64,
+    pub to_address: String,
+    pub subject: String,
+    pub body: String,
+    pub attempts: i32,
+}
+
+impl TryInto<OutboxMail> for MailOutboxDto {
+    type Error = MailOutboxDomainToDtoConversionError;
+
+    fn try_into(self) -> Result<OutboxMail, Self::Error> {
+        Ok(OutboxMail {
+            id: self.id,
+            mail: Mail {
+                to: MailAddress::from_stored(self.to_address),
+                subject: self.subject,
+                body: self.body,
+            },
+            attempts: self.attempts.try_into().unwrap_or_default(),
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MailOutboxDomainToDtoConversionError {
+    #[error(transparent)]
+    MailAddressError(#[from] MailAddressError),
+}
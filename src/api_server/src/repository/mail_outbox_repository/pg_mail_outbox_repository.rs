@@ -0,0 +1,104 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Postgres, Transaction};
+
+use crate::{
+    mailer::Mail,
+    repository::{
+        database_error::DatabaseError, mail_outbox_repository::mail_outbox_dto::MailOutboxDto,
+        pg_transaction::PgTransactionManager, OutboxMail,
+    },
+};
+
+use super::{MailOutboxRepository, MailOutboxRepositoryError};
+
+#[derive(Clone)]
+pub struct PgMailOutboxRepository {}
+
+#[async_trait]
+impl MailOutboxRepository<PgTransactionManager> for PgMailOutboxRepository {
+    #[tracing::instrument(name = "PgMailOutboxRepository::enqueue", skip_all, err)]
+    async fn enqueue(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        mail: Mail,
+        now: DateTime<Utc>,
+    ) -> Result<(), MailOutboxRepositoryError> {
+        sqlx::query!(
+            "INSERT INTO mail_outbox (to_address, subject, body, created_at, next_attempt_at) VALUES ($1, $2, $3, $4, $4)",
+            mail.to.get(),
+            mail.subject,
+            mail.body,
+            now,
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PgMailOutboxRepository::lock_due", skip_all, err)]
+    async fn lock_due(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        now: DateTime<Utc>,
+        max_attempts: u32,
+        limit: u32,
+    ) -> Result<Vec<OutboxMail>, MailOutboxRepositoryError> {
+        // NOTE: SKIP LOCKEDにより、複数のレプリカで同じメールを重複して送信しない
+        let dtos = sqlx::query_as!(
+            MailOutboxDto,
+            "SELECT id, to_address, subject, body, attempts FROM mail_outbox WHERE sent_at IS NULL AND next_attempt_at <= $1 AND attempts < $2 ORDER BY next_attempt_at LIMIT $3 FOR UPDATE SKIP LOCKED",
+            now,
+            i32::try_from(max_attempts).unwrap_or(i32::MAX),
+            i64::from(limit),
+        )
+        .fetch_all(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        dtos.into_iter().map(|dto| Ok(dto.try_into()?)).collect()
+    }
+
+    #[tracing::instrument(name = "PgMailOutboxRepository::mark_sent", skip_all, fields(id = id), err)]
+    async fn mark_sent(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        id: i64,
+        now: DateTime<Utc>,
+    ) -> Result<(), MailOutboxRepositoryError> {
+        sqlx::query!(
+            "UPDATE mail_outbox SET sent_at = $2, attempts = attempts + 1, last_error = NULL WHERE id = $1",
+            id,
+            now,
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        Ok(())
+    }
+
+    #[tracing::instrument(
+        name = "PgMailOutboxRepository::mark_failed",
+        skip_all,
+        fields(id = id),
+        err
+    )]
+    async fn mark_failed(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        id: i64,
+        error: &str,
+        next_attempt_at: DateTime<Utc>,
+    ) -> Result<(), MailOutboxRepositoryError> {
+        sqlx::query!(
+            "UPDATE mail_outbox SET attempts = attempts + 1, last_error = $2, next_attempt_at = $3 WHERE id = $1",
+            id,
+            error,
+            next_attempt_at,
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        Ok(())
+    }
+}
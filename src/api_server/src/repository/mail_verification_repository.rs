@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use mail_verification_dto::MailVerificationDomainToDtoConversionError;
+
+use crate::domain::{MailVerification, SecretTokenHash};
+
+mod mail_verification_dto;
+mod pg_mail_verification_repository;
+pub use pg_mail_verification_repository::PgMailVerificationRepository;
+
+use super::{database_error::DatabaseError, TransactionManager};
+
+#[async_trait]
+pub trait MailVerificationRepository<TM>
+where
+    TM: TransactionManager,
+{
+    // NOTE: 確認トークンが並行して使用されないよう、トランザクションの終了まで行をロックする
+    async fn find_by_token_hash(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        token_hash: &SecretTokenHash,
+    ) -> Result<Option<MailVerification>, MailVerificationRepositoryError>;
+    async fn save(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        verification: MailVerification,
+    ) -> Result<(), MailVerificationRepositoryError>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MailVerificationRepositoryError {
+    #[error(transparent)]
+    DatabaseError(#[from] DatabaseError),
+    #[error(transparent)]
+    ConversionError(#[from] MailVerificationDomainToDtoConversionError),
+}
@@ -0,0 +1,64 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::domain::{
+    MailAddress, MailAddressError, MailVerification, MailVerificationError,
+    MailVerificationPurpose, SecretTokenError, SecretTokenHash, SingleUseToken, UserId,
+    UserIdError,
+};
+
+#[derive(FromRow)]
+pub struct MailVerificationDto {
+    pub token_hash: Vec<u8>,
+    pub user_id: Uuid,
+    pub purpose: String,
+    pub mail_address: String,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+}
+
+impl From<MailVerification> for MailVerificationDto {
+    fn from(value: MailVerification) -> Self {
+        Self {
+            token_hash: value.token.token_hash.into_inner(),
+            user_id: value.user_id.get(),
+            purpose: value.purpose.as_str().to_string(),
+            mail_address: value.mail_address.into_inner(),
+            created_at: value.token.created_at,
+            expires_at: value.token.expires_at,
+            used_at: value.token.used_at,
+        }
+    }
+}
+
+impl TryInto<MailVerification> for MailVerificationDto {
+    type Error = MailVerificationDomainToDtoConversionError;
+
+    fn try_into(self) -> Result<MailVerification, Self::Error> {
+        Ok(MailVerification {
+            token: SingleUseToken {
+                token_hash: SecretTokenHash::new(self.token_hash)?,
+                created_at: self.created_at,
+                expires_at: self.expires_at,
+                used_at: self.used_at,
+            },
+            user_id: UserId::new(self.user_id)?,
+            purpose: MailVerificationPurpose::new(&self.purpose)?,
+            mail_address: MailAddress::from_stored(self.mail_address),
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MailVerificationDomainToDtoConversionError {
+    #[error(transparent)]
+    UserIdError(#[from] UserIdError),
+    #[error(transparent)]
+    MailAddressError(#[from] MailAddressError),
+    #[error(transparent)]
+    SecretTokenError(#[from] SecretTokenError),
+    #[error(transparent)]
+    MailVerificationError(#[from] MailVerificationError),
+}
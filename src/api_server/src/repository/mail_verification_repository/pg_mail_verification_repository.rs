@@ -0,0 +1,63 @@
+use async_trait::async_trait;
+use sqlx::{Postgres, Transaction};
+
+use crate::{
+    domain::{MailVerification, SecretTokenHash},
+    repository::{
+        database_error::DatabaseError,
+        mail_verification_repository::mail_verification_dto::MailVerificationDto,
+        pg_transaction::PgTransactionManager,
+    },
+};
+
+use super::{MailVerificationRepository, MailVerificationRepositoryError};
+
+#[derive(Clone)]
+pub struct PgMailVerificationRepository {}
+
+#[async_trait]
+impl MailVerificationRepository<PgTransactionManager> for PgMailVerificationRepository {
+    #[tracing::instrument(
+        name = "PgMailVerificationRepository::find_by_token_hash",
+        skip_all,
+        err
+    )]
+    async fn find_by_token_hash(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        token_hash: &SecretTokenHash,
+    ) -> Result<Option<MailVerification>, MailVerificationRepositoryError> {
+        let dto = sqlx::query_as!(
+            MailVerificationDto,
+            "SELECT token_hash, user_id, purpose, mail_address, created_at, expires_at, used_at FROM mail_verifications WHERE token_hash = $1 FOR UPDATE",
+            token_hash.get(),
+        )
+        .fetch_optional(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        dto.map(|dto| Ok(dto.try_into()?)).transpose()
+    }
+
+    #[tracing::instrument(name = "PgMailVerificationRepository::save", skip_all, fields(user_id = %verification.user_id), err)]
+    async fn save(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        verification: MailVerification,
+    ) -> Result<(), MailVerificationRepositoryError> {
+        let dto = MailVerificationDto::from(verification);
+        sqlx::query!(
+            "INSERT INTO mail_verifications (token_hash, user_id, purpose, mail_address, created_at, expires_at, used_at) VALUES ($1, $2, $3, $4, $5, $6, $7) ON CONFLICT (token_hash) DO UPDATE SET used_at = $7",
+            dto.token_hash,
+            dto.user_id,
+            dto.purpose,
+            dto.mail_address,
+            dto.created_at,
+            dto.expires_at,
+            dto.used_at,
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        Ok(())
+    }
+}
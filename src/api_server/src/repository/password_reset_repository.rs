@@ -0,0 +1,75 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use password_reset_dto::PasswordResetDomainToDtoConversionError;
+
+use crate::domain::{
+    MailAddress, PasswordReset, PasswordResetRequestCounts, SecretTokenHash, UserId,
+};
+
+mod password_reset_dto;
+mod pg_password_reset_repository;
+pub use pg_password_reset_repository::PgPasswordResetRepository;
+
+use super::{database_error::DatabaseError, TransactionManager};
+
+// NOTE: 再設定メールの発行を待っている申請
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingPasswordResetRequest {
+    pub id: i64,
+    pub mail_address: MailAddress,
+}
+
+#[async_trait]
+pub trait PasswordResetRepository<TM>
+where
+    TM: TransactionManager,
+{
+    // NOTE: 再設定トークンが並行して使用されないよう、トランザクションの終了まで行をロックする
+    async fn find_by_token_hash(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        token_hash: &SecretTokenHash,
+    ) -> Result<Option<PasswordReset>, PasswordResetRepositoryError>;
+    async fn save(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        reset: PasswordReset,
+    ) -> Result<(), PasswordResetRepositoryError>;
+    // NOTE: パスワードの再設定時に、同じユーザーの未使用の再設定トークンをすべて使用済みにする
+    async fn invalidate_unused_for_user(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        user_id: &UserId,
+        now: DateTime<Utc>,
+    ) -> Result<(), PasswordResetRepositoryError>;
+    // NOTE: 発行数の制限のため、アドレスの存在有無に関わらず申請を記録し、sinceより後の申請数(今回の申請を除く)を返す
+    //       同じアドレス・IPアドレスからの同時の申請は直列化され、制限を超えて受け付けることはない
+    async fn record_request(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        mail_address: &MailAddress,
+        ip_address: &str,
+        now: DateTime<Utc>,
+        since: DateTime<Utc>,
+    ) -> Result<PasswordResetRequestCounts, PasswordResetRepositoryError>;
+    // NOTE: 取得した申請はトランザクションの終了までロックされ、他のワーカーからは取得されない
+    async fn lock_pending_requests(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        limit: u32,
+    ) -> Result<Vec<PendingPasswordResetRequest>, PasswordResetRepositoryError>;
+    async fn mark_request_processed(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        id: i64,
+        now: DateTime<Utc>,
+    ) -> Result<(), PasswordResetRepositoryError>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PasswordResetRepositoryError {
+    #[error(transparent)]
+    DatabaseError(#[from] DatabaseError),
+    #[error(transparent)]
+    ConversionError(#[from] PasswordResetDomainToDtoConversionError),
+}
@@ -0,0 +1,75 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        MailAddress, MailAddressError, PasswordReset, SecretTokenError, SecretTokenHash,
+        SingleUseToken, UserId, UserIdError,
+    },
+    repository::PendingPasswordResetRequest,
+};
+
+#[derive(FromRow)]
+pub struct PasswordResetDto {
+    pub token_hash: Vec<u8>,
+    pub user_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+}
+
+impl From<PasswordReset> for PasswordResetDto {
+    fn from(value: PasswordReset) -> Self {
+        Self {
+            token_hash: value.token.token_hash.into_inner(),
+            user_id: value.user_id.get(),
+            created_at: value.token.created_at,
+            expires_at: value.token.expires_at,
+            used_at: value.token.used_at,
+        }
+    }
+}
+
+impl TryInto<PasswordReset> for PasswordResetDto {
+    type Error = PasswordResetDomainToDtoConversionError;
+
+    fn try_into(self) -> Result<PasswordReset, Self::Error> {
+        Ok(PasswordReset {
+            token: SingleUseToken {
+                token_hash: SecretTokenHash::new(self.token_hash)?,
+                created_at: self.created_at,
+                expires_at: self.expires_at,
+                used_at: self.used_at,
+            },
+            user_id: UserId::new(self.user_id)?,
+        })
+    }
+}
+
+#[derive(FromRow)]
+pub struct PasswordResetRequestDto {
+    pub id: i64,
+    pub mail_address: String,
+}
+
+impl TryInto<PendingPasswordResetRequest> for PasswordResetRequestDto {
+    type Error = PasswordResetDomainToDtoConversionError;
+
+    fn try_into(self) -> Result<PendingPasswordResetRequest, Self::Error> {
+        Ok(PendingPasswordResetRequest {
+            id: self.id,
+            mail_address: MailAddress::from_stored(self.mail_address),
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PasswordResetDomainToDtoConversionError {
+    #[error(transparent)]
+    UserIdError(#[from] UserIdError),
+    #[error(transparent)]
+    MailAddressError(#[from] MailAddressError),
+    #[error(transparent)]
+    SecretTokenError(#[from] SecretTokenError),
+}
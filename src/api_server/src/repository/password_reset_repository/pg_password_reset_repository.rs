@@ -0,0 +1,170 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Postgres, Transaction};
+
+use crate::{
+    domain::{MailAddress, PasswordReset, PasswordResetRequestCounts, SecretTokenHash, UserId},
+    repository::{
+        database_error::DatabaseError,
+        password_reset_repository::password_reset_dto::{
+            PasswordResetDto, PasswordResetRequestDto,
+        },
+        pg_transaction::PgTransactionManager,
+        PendingPasswordResetRequest,
+    },
+};
+
+use super::{PasswordResetRepository, PasswordResetRepositoryError};
+
+#[derive(Clone)]
+pub struct PgPasswordResetRepository {}
+
+#[async_trait]
+impl PasswordResetRepository<PgTransactionManager> for PgPasswordResetRepository {
+    #[tracing::instrument(name = "PgPasswordResetRepository::find_by_token_hash", skip_all, err)]
+    async fn find_by_token_hash(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        token_hash: &SecretTokenHash,
+    ) -> Result<Option<PasswordReset>, PasswordResetRepositoryError> {
+        let dto = sqlx::query_as!(
+            PasswordResetDto,
+            "SELECT token_hash, user_id, created_at, expires_at, used_at FROM password_resets WHERE token_hash = $1 FOR UPDATE",
+            token_hash.get(),
+        )
+        .fetch_optional(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        dto.map(|dto| Ok(dto.try_into()?)).transpose()
+    }
+
+    #[tracing::instrument(name = "PgPasswordResetRepository::save", skip_all, fields(user_id = %reset.user_id), err)]
+    async fn save(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        reset: PasswordReset,
+    ) -> Result<(), PasswordResetRepositoryError> {
+        let dto = PasswordResetDto::from(reset);
+        sqlx::query!(
+            "INSERT INTO password_resets (token_hash, user_id, created_at, expires_at, used_at) VALUES ($1, $2, $3, $4, $5) ON CONFLICT (token_hash) DO UPDATE SET used_at = $5",
+            dto.token_hash,
+            dto.user_id,
+            dto.created_at,
+            dto.expires_at,
+            dto.used_at,
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PgPasswordResetRepository::invalidate_unused_for_user", skip(self, tx, now), fields(user_id = %user_id), err)]
+    async fn invalidate_unused_for_user(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        user_id: &UserId,
+        now: DateTime<Utc>,
+    ) -> Result<(), PasswordResetRepositoryError> {
+        sqlx::query!(
+            "UPDATE password_resets SET used_at = $2 WHERE user_id = $1 AND used_at IS NULL",
+            user_id.get(),
+            now,
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PgPasswordResetRepository::record_request", skip_all, err)]
+    async fn record_request(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        mail_address: &MailAddress,
+        ip_address: &str,
+        now: DateTime<Utc>,
+        since: DateTime<Utc>,
+    ) -> Result<PasswordResetRequestCounts, PasswordResetRepositoryError> {
+        // NOTE: 数えてから記録するまでの間に同じアドレス・IPアドレスの申請が割り込まないよう、
+        //       トランザクションの終了まで保持されるロックを取得する。デッドロックを避けるため常に同じ順序で取得する
+        sqlx::query!(
+            "SELECT pg_advisory_xact_lock(key) FROM unnest(ARRAY[hashtextextended('password_reset:mail_address:' || $1, 0), hashtextextended('password_reset:ip_address:' || $2, 0)]) AS key ORDER BY key",
+            mail_address.get(),
+            ip_address,
+        )
+        .fetch_all(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        let row = sqlx::query!(
+            r#"SELECT
+                COUNT(*) FILTER (WHERE mail_address = $1) AS "by_mail_address!",
+                COUNT(*) FILTER (WHERE ip_address = $2) AS "by_ip_address!"
+            FROM password_reset_requests
+            WHERE (mail_address = $1 OR ip_address = $2) AND requested_at > $3"#,
+            mail_address.get(),
+            ip_address,
+            since,
+        )
+        .fetch_one(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        sqlx::query!(
+            "INSERT INTO password_reset_requests (mail_address, ip_address, requested_at) VALUES ($1, $2, $3)",
+            mail_address.get(),
+            ip_address,
+            now,
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        Ok(PasswordResetRequestCounts {
+            by_mail_address: row.by_mail_address.try_into().unwrap_or(u32::MAX),
+            by_ip_address: row.by_ip_address.try_into().unwrap_or(u32::MAX),
+        })
+    }
+
+    #[tracing::instrument(
+        name = "PgPasswordResetRepository::lock_pending_requests",
+        skip_all,
+        err
+    )]
+    async fn lock_pending_requests(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        limit: u32,
+    ) -> Result<Vec<PendingPasswordResetRequest>, PasswordResetRepositoryError> {
+        let dtos = sqlx::query_as!(
+            PasswordResetRequestDto,
+            "SELECT id, mail_address FROM password_reset_requests WHERE processed_at IS NULL ORDER BY requested_at LIMIT $1 FOR UPDATE SKIP LOCKED",
+            i64::from(limit),
+        )
+        .fetch_all(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        dtos.into_iter().map(|dto| Ok(dto.try_into()?)).collect()
+    }
+
+    #[tracing::instrument(
+        name = "PgPasswordResetRepository::mark_request_processed",
+        skip_all,
+        fields(id = id),
+        err
+    )]
+    async fn mark_request_processed(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        id: i64,
+        now: DateTime<Utc>,
+    ) -> Result<(), PasswordResetRepositoryError> {
+        sqlx::query!(
+            "UPDATE password_reset_requests SET processed_at = $2 WHERE id = $1",
+            id,
+            now,
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use session_dto::SessionDomainToDtoConversionError;
+
+use crate::domain::{SecretTokenHash, Session, SessionId, UserId};
+
+mod pg_session_repository;
+mod session_dto;
+pub use pg_session_repository::PgSessionRepository;
+
+use super::{database_error::DatabaseError, TransactionManager};
+
+#[async_trait]
+pub trait SessionRepository<TM>
+where
+    TM: TransactionManager,
+{
+    // NOTE: 同じリフレッシュトークンで並行してローテーションされないよう、トランザクションの終了まで行をロックする
+    async fn find_by_token_hash(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        token_hash: &SecretTokenHash,
+    ) -> Result<Option<Session>, SessionRepositoryError>;
+    async fn save(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        session: Session,
+    ) -> Result<(), SessionRepositoryError>;
+    // NOTE: 失効済みのトークンが再利用された場合に、同じファミリーをまとめて失効させる
+    async fn revoke_family(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        family_id: &SessionId,
+        now: DateTime<Utc>,
+    ) -> Result<(), SessionRepositoryError>;
+    // NOTE: パスワードの再設定時に、既存のセッションをすべて失効させる
+    async fn revoke_all_for_user(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        user_id: &UserId,
+        now: DateTime<Utc>,
+    ) -> Result<(), SessionRepositoryError>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SessionRepositoryError {
+    #[error(transparent)]
+    DatabaseError(#[from] DatabaseError),
+    #[error(transparent)]
+    ConversionError(#[from] SessionDomainToDtoConversionError),
+}
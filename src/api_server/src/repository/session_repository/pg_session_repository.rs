@@ -0,0 +1,97 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Postgres, Transaction};
+
+use crate::{
+    domain::{SecretTokenHash, Session, SessionId, UserId},
+    repository::{
+        database_error::DatabaseError, pg_transaction::PgTransactionManager,
+        session_repository::session_dto::SessionDto,
+    },
+};
+
+use super::{SessionRepository, SessionRepositoryError};
+
+#[derive(Clone)]
+pub struct PgSessionRepository {}
+
+#[async_trait]
+impl SessionRepository<PgTransactionManager> for PgSessionRepository {
+    #[tracing::instrument(name = "PgSessionRepository::find_by_token_hash", skip_all, err)]
+    async fn find_by_token_hash(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        token_hash: &SecretTokenHash,
+    ) -> Result<Option<Session>, SessionRepositoryError> {
+        let session_dto = sqlx::query_as!(
+            SessionDto,
+            "SELECT session_id, family_id, user_id, token_hash, created_at, expires_at, revoked_at FROM sessions WHERE token_hash = $1 FOR UPDATE",
+            token_hash.get(),
+        )
+        .fetch_optional(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        session_dto
+            .map(|session_dto| Ok(session_dto.try_into()?))
+            .transpose()
+    }
+
+    #[tracing::instrument(name = "PgSessionRepository::save", skip_all, fields(user_id = %session.user_id), err)]
+    async fn save(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        session: Session,
+    ) -> Result<(), SessionRepositoryError> {
+        let dto = SessionDto::from(session);
+        sqlx::query!(
+            "INSERT INTO sessions (session_id, family_id, user_id, token_hash, created_at, expires_at, revoked_at) VALUES ($1, $2, $3, $4, $5, $6, $7) ON CONFLICT (session_id) DO UPDATE SET revoked_at = $7",
+            dto.session_id,
+            dto.family_id,
+            dto.user_id,
+            dto.token_hash,
+            dto.created_at,
+            dto.expires_at,
+            dto.revoked_at,
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PgSessionRepository::revoke_family", skip(self, tx), fields(family_id = %family_id), err)]
+    async fn revoke_family(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        family_id: &SessionId,
+        now: DateTime<Utc>,
+    ) -> Result<(), SessionRepositoryError> {
+        sqlx::query!(
+            "UPDATE sessions SET revoked_at = $2 WHERE family_id = $1 AND revoked_at IS NULL",
+            family_id.get(),
+            now,
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PgSessionRepository::revoke_all_for_user", skip(self, tx), fields(user_id = %user_id), err)]
+    async fn revoke_all_for_user(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        user_id: &UserId,
+        now: DateTime<Utc>,
+    ) -> Result<(), SessionRepositoryError> {
+        sqlx::query!(
+            "UPDATE sessions SET revoked_at = $2 WHERE user_id = $1 AND revoked_at IS NULL",
+            user_id.get(),
+            now,
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::domain::{
+    SecretTokenError, SecretTokenHash, Session, SessionId, SessionIdError, UserId, UserIdError,
+};
+
+#[derive(FromRow)]
+pub struct SessionDto {
+    pub session_id: Uuid,
+    pub family_id: Uuid,
+    pub user_id: Uuid,
+    pub token_hash: Vec<u8>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl From<Session> for SessionDto {
+    fn from(value: Session) -> Self {
+        Self {
+            session_id: value.id.get(),
+            family_id: value.family_id.get(),
+            user_id: value.user_id.get(),
+            token_hash: value.token_hash.into_inner(),
+            created_at: value.created_at,
+            expires_at: value.expires_at,
+            revoked_at: value.revoked_at,
+        }
+    }
+}
+
+impl TryInto<Session> for SessionDto {
+    type Error = SessionDomainToDtoConversionError;
+
+    fn try_into(self) -> Result<Session, Self::Error> {
+        Ok(Session {
+            id: SessionId::new(self.session_id)?,
+            family_id: SessionId::new(self.family_id)?,
+            user_id: UserId::new(self.user_id)?,
+            token_hash: SecretTokenHash::new(self.token_hash)?,
+            created_at: self.created_at,
+            expires_at: self.expires_at,
+            revoked_at: self.revoked_at,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SessionDomainToDtoConversionError {
+    #[error(transparent)]
+    SessionIdError(#[from] SessionIdError),
+    #[error(transparent)]
+    UserIdError(#[from] UserIdError),
+    #[error(transparent)]
+    SecretTokenError(#[from] SecretTokenError),
+}
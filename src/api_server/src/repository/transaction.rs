@@ -36,3 +36,23 @@ pub trait TransactionManager {
         }
     }
 }
+
+// NOTE: テスト用。DBを使わないサービスやジョブのテストで、トランザクションを必要とするトレイトを呼び出す
+#[cfg(test)]
+pub struct NoopTransactionManager;
+
+#[cfg(test)]
+#[async_trait]
+impl TransactionManager for NoopTransactionManager {
+    type Transaction<'a> = ();
+
+    async fn get_transaction<'a>(&self) -> Result<Self::Transaction<'a>, DatabaseError> {
+        Ok(())
+    }
+    async fn commit(_tx: Self::Transaction<'_>) -> Result<(), DatabaseError> {
+        Ok(())
+    }
+    async fn rollback(_tx: Self::Transaction<'_>) -> Result<(), DatabaseError> {
+        Ok(())
+    }
+}
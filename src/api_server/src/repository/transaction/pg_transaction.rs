@@ -1,9 +1,12 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Instant};
 
 use async_trait::async_trait;
 use sqlx::{PgPool, Postgres, Transaction};
 
-use crate::repository::database_error::DatabaseError;
+use crate::{
+    metrics::{metrics, TransactionOutcome},
+    repository::database_error::DatabaseError,
+};
 
 use super::TransactionManager;
 
@@ -21,15 +24,33 @@ impl PgTransactionManager {
 impl TransactionManager for PgTransactionManager {
     type Transaction<'a> = Transaction<'a, Postgres>;
 
+    #[tracing::instrument(name = "transaction.begin", skip_all, err)]
     async fn get_transaction<'a>(&self) -> Result<Self::Transaction<'a>, DatabaseError> {
-        Ok(self.pool.begin().await?)
+        let started = Instant::now();
+        let tx = self.pool.begin().await;
+        metrics().observe_pool_acquire(started.elapsed());
+        let tx = tx?;
+        metrics().observe_transaction_begin();
+        Ok(tx)
     }
 
+    #[tracing::instrument(name = "transaction.commit", skip_all, err)]
     async fn commit(tx: Self::Transaction<'_>) -> Result<(), DatabaseError> {
-        Ok(tx.commit().await?)
+        let result = tx.commit().await;
+        metrics().observe_transaction(match result {
+            Ok(_) => TransactionOutcome::Commit,
+            Err(_) => TransactionOutcome::CommitFailed,
+        });
+        Ok(result?)
     }
 
+    #[tracing::instrument(name = "transaction.rollback", skip_all, err)]
     async fn rollback(tx: Self::Transaction<'_>) -> Result<(), DatabaseError> {
-        Ok(tx.rollback().await?)
+        let result = tx.rollback().await;
+        metrics().observe_transaction(match result {
+            Ok(_) => TransactionOutcome::Rollback,
+            Err(_) => TransactionOutcome::RollbackFailed,
+        });
+        Ok(result?)
     }
 }
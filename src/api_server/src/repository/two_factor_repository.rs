@@ -0,0 +1,73 @@
+use async_trait::async_trait;
+use two_factor_dto::TwoFactorDomainToDtoConversionError;
+
+use crate::domain::{LoginChallenge, RecoveryCodeEntry, SecretTokenHash, TotpCredential, UserId};
+
+mod pg_two_factor_repository;
+mod two_factor_dto;
+pub use pg_two_factor_repository::PgTwoFactorRepository;
+
+use super::{database_error::DatabaseError, TransactionManager};
+
+// NOTE: 二要素認証に関わるTOTPの共有鍵・リカバリーコード・ログインの確認待ちをまとめて扱う
+#[async_trait]
+pub trait TwoFactorRepository<TM>
+where
+    TM: TransactionManager,
+{
+    // NOTE: 同じコードが並行して受け付けられないよう、トランザクションの終了まで行をロックする
+    async fn find_totp_credential(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        user_id: &UserId,
+    ) -> Result<Option<TotpCredential>, TwoFactorRepositoryError>;
+    async fn save_totp_credential(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        credential: TotpCredential,
+    ) -> Result<(), TwoFactorRepositoryError>;
+    // NOTE: 未使用のもののみを返し、トランザクションの終了まで行をロックする
+    async fn find_recovery_code(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        user_id: &UserId,
+        code_hash: &SecretTokenHash,
+    ) -> Result<Option<RecoveryCodeEntry>, TwoFactorRepositoryError>;
+    async fn save_recovery_code(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        entry: RecoveryCodeEntry,
+    ) -> Result<(), TwoFactorRepositoryError>;
+    // NOTE: 既存のリカバリーコードはすべて破棄する
+    async fn replace_recovery_codes(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        user_id: &UserId,
+        entries: Vec<RecoveryCodeEntry>,
+    ) -> Result<(), TwoFactorRepositoryError>;
+    // NOTE: TOTPの共有鍵とリカバリーコードを削除する
+    async fn delete_for_user(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        user_id: &UserId,
+    ) -> Result<(), TwoFactorRepositoryError>;
+    // NOTE: 確認待ちが並行して使用されないよう、トランザクションの終了まで行をロックする
+    async fn find_login_challenge(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        token_hash: &SecretTokenHash,
+    ) -> Result<Option<LoginChallenge>, TwoFactorRepositoryError>;
+    async fn save_login_challenge(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        challenge: LoginChallenge,
+    ) -> Result<(), TwoFactorRepositoryError>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TwoFactorRepositoryError {
+    #[error(transparent)]
+    DatabaseError(#[from] DatabaseError),
+    #[error(transparent)]
+    ConversionError(#[from] TwoFactorDomainToDtoConversionError),
+}
@@ -0,0 +1,179 @@
+use async_trait::async_trait;
+use sqlx::{Postgres, Transaction};
+
+use crate::{
+    domain::{LoginChallenge, RecoveryCodeEntry, SecretTokenHash, TotpCredential, UserId},
+    repository::{
+        database_error::DatabaseError,
+        pg_transaction::PgTransactionManager,
+        two_factor_repository::two_factor_dto::{
+            LoginChallengeDto, RecoveryCodeDto, TotpCredentialDto,
+        },
+    },
+};
+
+use super::{TwoFactorRepository, TwoFactorRepositoryError};
+
+#[derive(Clone)]
+pub struct PgTwoFactorRepository {}
+
+#[async_trait]
+impl TwoFactorRepository<PgTransactionManager> for PgTwoFactorRepository {
+    #[tracing::instrument(name = "PgTwoFactorRepository::find_totp_credential", skip(self, tx), fields(user_id = %user_id), err)]
+    async fn find_totp_credential(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        user_id: &UserId,
+    ) -> Result<Option<TotpCredential>, TwoFactorRepositoryError> {
+        let dto = sqlx::query_as!(
+            TotpCredentialDto,
+            "SELECT user_id, secret, confirmed_at, last_used_step, created_at FROM totp_credentials WHERE user_id = $1 FOR UPDATE",
+            user_id.get(),
+        )
+        .fetch_optional(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        dto.map(|dto| Ok(dto.try_into()?)).transpose()
+    }
+
+    #[tracing::instrument(name = "PgTwoFactorRepository::save_totp_credential", skip_all, fields(user_id = %credential.user_id), err)]
+    async fn save_totp_credential(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        credential: TotpCredential,
+    ) -> Result<(), TwoFactorRepositoryError> {
+        let dto = TotpCredentialDto::from(credential);
+        sqlx::query!(
+            "INSERT INTO totp_credentials (user_id, secret, confirmed_at, last_used_step, created_at) VALUES ($1, $2, $3, $4, $5) ON CONFLICT (user_id) DO UPDATE SET secret = $2, confirmed_at = $3, last_used_step = $4, created_at = $5",
+            dto.user_id,
+            dto.secret,
+            dto.confirmed_at,
+            dto.last_used_step,
+            dto.created_at,
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PgTwoFactorRepository::find_recovery_code", skip(self, tx, code_hash), fields(user_id = %user_id), err)]
+    async fn find_recovery_code(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        user_id: &UserId,
+        code_hash: &SecretTokenHash,
+    ) -> Result<Option<RecoveryCodeEntry>, TwoFactorRepositoryError> {
+        let dto = sqlx::query_as!(
+            RecoveryCodeDto,
+            "SELECT code_hash, user_id, created_at, used_at FROM recovery_codes WHERE user_id = $1 AND code_hash = $2 AND used_at IS NULL FOR UPDATE",
+            user_id.get(),
+            code_hash.get(),
+        )
+        .fetch_optional(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        dto.map(|dto| Ok(dto.try_into()?)).transpose()
+    }
+
+    #[tracing::instrument(name = "PgTwoFactorRepository::save_recovery_code", skip_all, fields(user_id = %entry.user_id), err)]
+    async fn save_recovery_code(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        entry: RecoveryCodeEntry,
+    ) -> Result<(), TwoFactorRepositoryError> {
+        let dto = RecoveryCodeDto::from(entry);
+        sqlx::query!(
+            "INSERT INTO recovery_codes (code_hash, user_id, created_at, used_at) VALUES ($1, $2, $3, $4) ON CONFLICT (code_hash) DO UPDATE SET used_at = $4",
+            dto.code_hash,
+            dto.user_id,
+            dto.created_at,
+            dto.used_at,
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PgTwoFactorRepository::replace_recovery_codes", skip(self, tx, entries), fields(user_id = %user_id), err)]
+    async fn replace_recovery_codes(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        user_id: &UserId,
+        entries: Vec<RecoveryCodeEntry>,
+    ) -> Result<(), TwoFactorRepositoryError> {
+        sqlx::query!(
+            "DELETE FROM recovery_codes WHERE user_id = $1",
+            user_id.get()
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        for entry in entries {
+            self.save_recovery_code(tx, entry).await?;
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PgTwoFactorRepository::delete_for_user", skip(self, tx), fields(user_id = %user_id), err)]
+    async fn delete_for_user(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        user_id: &UserId,
+    ) -> Result<(), TwoFactorRepositoryError> {
+        sqlx::query!(
+            "DELETE FROM recovery_codes WHERE user_id = $1",
+            user_id.get()
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        sqlx::query!(
+            "DELETE FROM totp_credentials WHERE user_id = $1",
+            user_id.get()
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PgTwoFactorRepository::find_login_challenge", skip_all, err)]
+    async fn find_login_challenge(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        token_hash: &SecretTokenHash,
+    ) -> Result<Option<LoginChallenge>, TwoFactorRepositoryError> {
+        let dto = sqlx::query_as!(
+            LoginChallengeDto,
+            "SELECT token_hash, user_id, created_at, expires_at, used_at FROM login_challenges WHERE token_hash = $1 FOR UPDATE",
+            token_hash.get(),
+        )
+        .fetch_optional(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        dto.map(|dto| Ok(dto.try_into()?)).transpose()
+    }
+
+    #[tracing::instrument(name = "PgTwoFactorRepository::save_login_challenge", skip_all, fields(user_id = %challenge.user_id), err)]
+    async fn save_login_challenge(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        challenge: LoginChallenge,
+    ) -> Result<(), TwoFactorRepositoryError> {
+        let dto = LoginChallengeDto::from(challenge);
+        sqlx::query!(
+            "INSERT INTO login_challenges (token_hash, user_id, created_at, expires_at, used_at) VALUES ($1, $2, $3, $4, $5) ON CONFLICT (token_hash) DO UPDATE SET used_at = $5",
+            dto.token_hash,
+            dto.user_id,
+            dto.created_at,
+            dto.expires_at,
+            dto.used_at,
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        Ok(())
+    }
+}
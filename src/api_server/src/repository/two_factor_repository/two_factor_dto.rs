@@ -0,0 +1,122 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::domain::{
+    LoginChallenge, RecoveryCodeEntry, SecretTokenError, SecretTokenHash, SingleUseToken,
+    TotpCredential, TotpSecret, TotpSecretError, UserId, UserIdError,
+};
+
+#[derive(FromRow)]
+pub struct TotpCredentialDto {
+    pub user_id: Uuid,
+    pub secret: Vec<u8>,
+    pub confirmed_at: Option<DateTime<Utc>>,
+    pub last_used_step: Option<i64>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<TotpCredential> for TotpCredentialDto {
+    fn from(value: TotpCredential) -> Self {
+        Self {
+            user_id: value.user_id.get(),
+            secret: value.secret.into_inner(),
+            confirmed_at: value.confirmed_at,
+            last_used_step: value.last_used_step,
+            created_at: value.created_at,
+        }
+    }
+}
+
+impl TryInto<TotpCredential> for TotpCredentialDto {
+    type Error = TwoFactorDomainToDtoConversionError;
+
+    fn try_into(self) -> Result<TotpCredential, Self::Error> {
+        Ok(TotpCredential {
+            user_id: UserId::new(self.user_id)?,
+            secret: TotpSecret::new(self.secret)?,
+            confirmed_at: self.confirmed_at,
+            last_used_step: self.last_used_step,
+            created_at: self.created_at,
+        })
+    }
+}
+
+#[derive(FromRow)]
+pub struct RecoveryCodeDto {
+    pub code_hash: Vec<u8>,
+    pub user_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+}
+
+impl From<RecoveryCodeEntry> for RecoveryCodeDto {
+    fn from(value: RecoveryCodeEntry) -> Self {
+        Self {
+            code_hash: value.code_hash.into_inner(),
+            user_id: value.user_id.get(),
+            created_at: value.created_at,
+            used_at: value.used_at,
+        }
+    }
+}
+
+impl TryInto<RecoveryCodeEntry> for RecoveryCodeDto {
+    type Error = TwoFactorDomainToDtoConversionError;
+
+    fn try_into(self) -> Result<RecoveryCodeEntry, Self::Error> {
+        Ok(RecoveryCodeEntry {
+            code_hash: SecretTokenHash::new(self.code_hash)?,
+            user_id: UserId::new(self.user_id)?,
+            created_at: self.created_at,
+            used_at: self.used_at,
+        })
+    }
+}
+
+#[derive(FromRow)]
+pub struct LoginChallengeDto {
+    pub token_hash: Vec<u8>,
+    pub user_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+}
+
+impl From<LoginChallenge> for LoginChallengeDto {
+    fn from(value: LoginChallenge) -> Self {
+        Self {
+            token_hash: value.token.token_hash.into_inner(),
+            user_id: value.user_id.get(),
+            created_at: value.token.created_at,
+            expires_at: value.token.expires_at,
+            used_at: value.token.used_at,
+        }
+    }
+}
+
+impl TryInto<LoginChallenge> for LoginChallengeDto {
+    type Error = TwoFactorDomainToDtoConversionError;
+
+    fn try_into(self) -> Result<LoginChallenge, Self::Error> {
+        Ok(LoginChallenge {
+            token: SingleUseToken {
+                token_hash: SecretTokenHash::new(self.token_hash)?,
+                created_at: self.created_at,
+                expires_at: self.expires_at,
+                used_at: self.used_at,
+            },
+            user_id: UserId::new(self.user_id)?,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TwoFactorDomainToDtoConversionError {
+    #[error(transparent)]
+    UserIdError(#[from] UserIdError),
+    #[error(transparent)]
+    SecretTokenError(#[from] SecretTokenError),
+    #[error(transparent)]
+    TotpSecretError(#[from] TotpSecretError),
+}
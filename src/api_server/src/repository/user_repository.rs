@@ -3,12 +3,35 @@ use user_dto::UserDomainToDtoConversionError;
 
 use crate::domain::{MailAddress, User, UserId, UserName};
 
+mod pg_user_id_generator;
 mod pg_user_repository;
 mod user_dto;
+pub use pg_user_id_generator::PgUserIdGenerator;
 pub use pg_user_repository::PgUserRepository;
 
 use super::{database_error::DatabaseError, TransactionManager};
 
+// NOTE: find_by_*で検索対象とするユーザーの状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserStatusFilter {
+    // NOTE: 退会済みを含むすべて。アドレス・ユーザー名の重複確認等に使用する
+    All,
+    // NOTE: 退会済みを除く
+    NotDeleted,
+    // NOTE: 退会済み・利用停止中を除く(期限を過ぎた利用停止は含む)
+    Available,
+}
+
+impl UserStatusFilter {
+    pub fn includes_deleted(&self) -> bool {
+        *self == Self::All
+    }
+
+    pub fn includes_suspended(&self) -> bool {
+        *self != Self::Available
+    }
+}
+
 #[async_trait]
 pub trait UserRepository<TM>
 where
@@ -19,17 +42,36 @@ where
         &self,
         tx: &mut TM::Transaction<'_>,
         user_id: &UserId,
+        filter: UserStatusFilter,
+    ) -> Result<Option<User>, UserRepositoryError>;
+    // NOTE: 読み取った値をもとに更新する場合に使用する。トランザクションの終了まで行をロックする
+    async fn find_by_user_id_for_update(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        user_id: &UserId,
+        filter: UserStatusFilter,
     ) -> Result<Option<User>, UserRepositoryError>;
     async fn find_by_user_name(
         &self,
         tx: &mut TM::Transaction<'_>,
         user_name: &UserName,
+        filter: UserStatusFilter,
     ) -> Result<Option<User>, UserRepositoryError>;
     async fn find_by_mail_address(
         &self,
         tx: &mut TM::Transaction<'_>,
         mail_address: &MailAddress,
+        filter: UserStatusFilter,
     ) -> Result<Option<User>, UserRepositoryError>;
+    // NOTE: 退会済みを含め、skeletonが一致するユーザーがいるか。exceptのユーザーは除く
+    async fn exists_by_user_name_skeleton(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        skeleton: &str,
+        except: Option<&UserId>,
+    ) -> Result<bool, UserRepositoryError>;
+    // NOTE: 名前を変更する場合、skeletonが一致するユーザーが既にいればUserNameSkeletonConflictを返す
+    //       他のユーザーと同じアドレスを保存する場合はMailAddressConflictを返す
     async fn save(
         &self,
         tx: &mut TM::Transaction<'_>,
@@ -48,4 +90,8 @@ pub enum UserRepositoryError {
     DatabaseError(#[from] DatabaseError),
     #[error(transparent)]
     ConversionError(#[from] UserDomainToDtoConversionError),
+    #[error("紛らわしいユーザー名が既に使用されています。")]
+    UserNameSkeletonConflict,
+    #[error("メールアドレスが既に使用されています。")]
+    MailAddressConflict,
 }
@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use sqlx::{Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::{
+    domain::{uuid_from_sequence, IdGenerator, IdGeneratorError},
+    repository::{database_error::DatabaseError, pg_transaction::PgTransactionManager},
+};
+
+// NOTE: プールから別の接続を取得すると、接続が枯渇した際にトランザクション同士が互いの解放を待って停止するため、
+//       ユースケースのトランザクションで採番する。nextvalはロールバックしても戻らないため、欠番は生じうる
+#[derive(Clone, Default)]
+pub struct PgUserIdGenerator {}
+
+#[async_trait]
+impl IdGenerator<PgTransactionManager> for PgUserIdGenerator {
+    async fn generate(&self, tx: &mut Transaction<'_, Postgres>) -> Result<Uuid, IdGeneratorError> {
+        let sequence = sqlx::query_scalar!(r#"SELECT nextval('user_id_seq') AS "sequence!""#)
+            .fetch_one(&mut **tx)
+            .await
+            .map_err(DatabaseError::from)?;
+        uuid_from_sequence(sequence)
+    }
+}
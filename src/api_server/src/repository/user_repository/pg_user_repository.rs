@@ -1,32 +1,104 @@
 use async_trait::async_trait;
-use sqlx::{Postgres, Transaction};
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
 
 use crate::{
-    domain::{MailAddress, User, UserId, UserName},
+    domain::{user_name_skeleton, MailAddress, User, UserId, UserName},
     repository::{
         database_error::DatabaseError, pg_transaction::PgTransactionManager,
         user_repository::user_dto::UserDto,
     },
 };
 
-use super::{UserRepository, UserRepositoryError};
+use super::{UserRepository, UserRepositoryError, UserStatusFilter};
 
 #[derive(Clone)]
 pub struct PgUserRepository {}
 
-impl PgUserRepository {}
+impl PgUserRepository {
+    // NOTE: skeletonの導入前に登録されたユーザーの分を、user_idの順にbatch_size件ずつ補完する。補完した件数を返す
+    //       既存のskeletonと重複するユーザーは補完せず、名前を変更した時点で登録される
+    #[tracing::instrument(
+        name = "PgUserRepository::backfill_user_name_skeletons",
+        skip(pool),
+        err
+    )]
+    pub async fn backfill_user_name_skeletons(
+        pool: &PgPool,
+        batch_size: i64,
+    ) -> Result<u64, UserRepositoryError> {
+        let mut count = 0;
+        let mut last_user_id: Option<Uuid> = None;
+        loop {
+            let users = sqlx::query!(
+                "SELECT user_id, user_name FROM users WHERE ($1::uuid IS NULL OR user_id > $1) AND NOT EXISTS (SELECT 1 FROM user_name_skeletons WHERE user_name_skeletons.user_id = users.user_id) ORDER BY user_id LIMIT $2",
+                last_user_id,
+                batch_size,
+            )
+            .fetch_all(pool)
+            .await
+            .map_err(DatabaseError::from)?;
+            let Some(last_user) = users.last() else {
+                break;
+            };
+            last_user_id = Some(last_user.user_id);
+            let (user_ids, skeletons): (Vec<Uuid>, Vec<String>) = users
+                .iter()
+                .map(|user| (user.user_id, user_name_skeleton(&user.user_name)))
+                .unzip();
+            count += sqlx::query!(
+                "INSERT INTO user_name_skeletons (user_id, skeleton) SELECT * FROM UNNEST($1::uuid[], $2::varchar[]) ON CONFLICT DO NOTHING",
+                &user_ids,
+                &skeletons,
+            )
+            .execute(pool)
+            .await
+            .map_err(DatabaseError::from)?
+            .rows_affected();
+            tracing::info!(count, "backfilled a batch of user name skeletons");
+        }
+        Ok(count)
+    }
+}
 
 #[async_trait]
 impl UserRepository<PgTransactionManager> for PgUserRepository {
+    // NOTE: 利用停止の期限はDBの現在時刻で判定する
+    #[tracing::instrument(name = "PgUserRepository::find_by_user_id", skip(self, tx), fields(user_id = %user_id, ?filter), err)]
     async fn find_by_user_id(
         &self,
         tx: &mut Transaction<'_, Postgres>,
         user_id: &UserId,
+        filter: UserStatusFilter,
+    ) -> Result<Option<User>, UserRepositoryError> {
+        let user_dto = sqlx::query_as!(
+            UserDto,
+            "SELECT * FROM users WHERE user_id = $1 AND ($2 OR status <> 'deleted') AND ($3 OR status <> 'suspended' OR suspended_until <= NOW())",
+            user_id.get(),
+            filter.includes_deleted(),
+            filter.includes_suspended(),
+        )
+        .fetch_optional(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        user_dto
+            .map(|user_dto| Ok(user_dto.try_into()?))
+            .transpose()
+    }
+
+    #[tracing::instrument(name = "PgUserRepository::find_by_user_id_for_update", skip(self, tx), fields(user_id = %user_id, ?filter), err)]
+    async fn find_by_user_id_for_update(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        user_id: &UserId,
+        filter: UserStatusFilter,
     ) -> Result<Option<User>, UserRepositoryError> {
         let user_dto = sqlx::query_as!(
             UserDto,
-            "SELECT * FROM users WHERE user_id = $1",
-            user_id.get()
+            "SELECT * FROM users WHERE user_id = $1 AND ($2 OR status <> 'deleted') AND ($3 OR status <> 'suspended' OR suspended_until <= NOW()) FOR UPDATE",
+            user_id.get(),
+            filter.includes_deleted(),
+            filter.includes_suspended(),
         )
         .fetch_optional(&mut **tx)
         .await
@@ -36,15 +108,19 @@ impl UserRepository<PgTransactionManager> for PgUserRepository {
             .transpose()
     }
 
+    #[tracing::instrument(name = "PgUserRepository::find_by_user_name", skip(self, tx), fields(user_name = %user_name, ?filter), err)]
     async fn find_by_user_name(
         &self,
         tx: &mut Transaction<'_, Postgres>,
         user_name: &UserName,
+        filter: UserStatusFilter,
     ) -> Result<Option<User>, UserRepositoryError> {
         let user_dto = sqlx::query_as!(
             UserDto,
-            "SELECT * FROM users WHERE user_name = $1",
+            "SELECT * FROM users WHERE user_name = $1 AND ($2 OR status <> 'deleted') AND ($3 OR status <> 'suspended' OR suspended_until <= NOW())",
             user_name.get(),
+            filter.includes_deleted(),
+            filter.includes_suspended(),
         )
         .fetch_optional(&mut **tx)
         .await
@@ -54,15 +130,19 @@ impl UserRepository<PgTransactionManager> for PgUserRepository {
             .transpose()
     }
 
+    #[tracing::instrument(name = "PgUserRepository::find_by_mail_address", skip(self, tx, mail_address), fields(?filter), err)]
     async fn find_by_mail_address(
         &self,
         tx: &mut Transaction<'_, Postgres>,
         mail_address: &MailAddress,
+        filter: UserStatusFilter,
     ) -> Result<Option<User>, UserRepositoryError> {
         let user_dto = sqlx::query_as!(
             UserDto,
-            "SELECT * FROM users WHERE mail_address = $1",
+            "SELECT * FROM users WHERE mail_address = $1 AND ($2 OR status <> 'deleted') AND ($3 OR status <> 'suspended' OR suspended_until <= NOW())",
             mail_address.get(),
+            filter.includes_deleted(),
+            filter.includes_suspended(),
         )
         .fetch_optional(&mut **tx)
         .await
@@ -72,22 +152,95 @@ impl UserRepository<PgTransactionManager> for PgUserRepository {
             .transpose()
     }
 
+    #[tracing::instrument(
+        name = "PgUserRepository::exists_by_user_name_skeleton",
+        skip(self, tx),
+        err
+    )]
+    async fn exists_by_user_name_skeleton(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+        skeleton: &str,
+        except: Option<&UserId>,
+    ) -> Result<bool, UserRepositoryError> {
+        let exists = sqlx::query_scalar!(
+            r#"SELECT EXISTS (SELECT 1 FROM user_name_skeletons WHERE skeleton = $1 AND ($2::uuid IS NULL OR user_id <> $2)) AS "exists!""#,
+            skeleton,
+            except.map(UserId::get),
+        )
+        .fetch_one(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
+        Ok(exists)
+    }
+
+    #[tracing::instrument(name = "PgUserRepository::save", skip_all, fields(user_id = %user.id), err)]
     async fn save(
         &self,
         tx: &mut Transaction<'_, Postgres>,
         user: User,
     ) -> Result<(), UserRepositoryError> {
+        let skeleton = user_name_skeleton(user.name.get());
+        let dto = UserDto::try_from(user)?;
+        let previous_user_name = sqlx::query_scalar!(
+            "SELECT user_name FROM users WHERE user_id = $1",
+            dto.user_id
+        )
+        .fetch_optional(&mut **tx)
+        .await
+        .map_err(DatabaseError::from)?;
         sqlx::query!(
-            "INSERT INTO users (user_id, user_name, mail_address) VALUES ($1, $2, $3) ON CONFLICT (user_id) DO UPDATE SET user_name = $2, mail_address = $3",
-            user.id.get(),
-            user.name.get(),
-            user.mail_address.get(),
+            "INSERT INTO users (user_id, user_name, mail_address, password_hash, role, mail_verified_at, pending_mail_address, failed_login_count, locked_until, two_factor_enabled, status, suspension_reason, suspended_until, deleted_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14) ON CONFLICT (user_id) DO UPDATE SET user_name = $2, mail_address = $3, password_hash = $4, role = $5, mail_verified_at = $6, pending_mail_address = $7, failed_login_count = $8, locked_until = $9, two_factor_enabled = $10, status = $11, suspension_reason = $12, suspended_until = $13, deleted_at = $14",
+            dto.user_id,
+            dto.user_name,
+            dto.mail_address,
+            dto.password_hash,
+            dto.role,
+            dto.mail_verified_at,
+            dto.pending_mail_address,
+            dto.failed_login_count,
+            dto.locked_until,
+            dto.two_factor_enabled,
+            dto.status,
+            dto.suspension_reason,
+            dto.suspended_until,
+            dto.deleted_at,
         )
         .execute(&mut **tx)
-        .await.map_err(DatabaseError::from)?;
+        .await
+        .map_err(|e| match &e {
+            sqlx::Error::Database(db_error)
+                if db_error.constraint() == Some("users_mail_address_key") =>
+            {
+                UserRepositoryError::MailAddressConflict
+            }
+            _ => DatabaseError::from(e).into(),
+        })?;
+        // NOTE: skeletonは名前を変更した場合のみ更新する。一意制約により、同時に登録された紛らわしい名前を検出する
+        //       重複のため補完されなかったユーザーも、名前を変更しなければ保存できるようにする
+        if previous_user_name.as_deref() == Some(dto.user_name.as_str()) {
+            return Ok(());
+        }
+        sqlx::query!(
+            "INSERT INTO user_name_skeletons (user_id, skeleton) VALUES ($1, $2) ON CONFLICT (user_id) DO UPDATE SET skeleton = $2",
+            dto.user_id,
+            skeleton,
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| match &e {
+            sqlx::Error::Database(db_error)
+                if db_error.constraint() == Some("user_name_skeletons_skeleton_key") =>
+            {
+                UserRepositoryError::UserNameSkeletonConflict
+            }
+            _ => DatabaseError::from(e).into(),
+        })?;
         Ok(())
     }
 
+    // NOTE: 物理削除。退会はUser::deleteで状態を変更して保存する
+    #[tracing::instrument(name = "PgUserRepository::delete", skip_all, fields(user_id = %user.id), err)]
     async fn delete(
         &self,
         tx: &mut Transaction<'_, Postgres>,
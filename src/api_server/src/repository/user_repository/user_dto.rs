@@ -1,47 +1,45 @@
+use chrono::{DateTime, Utc};
 use sqlx::FromRow;
+use sqlx_macros::PersistenceModel;
 use uuid::Uuid;
 
 use crate::domain::{
-    MailAddress, MailAddressError, User, UserId, UserIdError, UserName, UserNameError,
+    MailAddress, MailAddressError, PasswordHash, PasswordHashError, Role, RoleError, User, UserId,
+    UserIdError, UserName, UserNameError, UserStatus, UserStatusError,
 };
 
-#[derive(FromRow)]
+// NOTE: 失敗回数はDBではINTEGERのため、範囲外の値は変換エラーとする
+#[derive(FromRow, PersistenceModel)]
+#[model(
+    domain = User,
+    field(user_id = "id: UserId"),
+    field(user_name = "name: UserName"),
+    field(mail_address = "mail_address: MailAddress", stored),
+    field(password_hash = "password_hash: Option<PasswordHash>"),
+    field(role = "role: Role"),
+    field(pending_mail_address = "pending_mail_address: Option<MailAddress>", stored),
+    field(
+        failed_login_count = "failed_login_count: u32",
+        error = "std::num::TryFromIntError"
+    ),
+    field(
+        status = "status: UserStatus",
+        with(suspension_reason, suspended_until, deleted_at)
+    )
+)]
 pub struct UserDto {
     pub user_id: Uuid,
     pub user_name: String,
     pub mail_address: String,
-}
-
-impl TryFrom<User> for UserDto {
-    type Error = UserDomainToDtoConversionError;
-
-    fn try_from(value: User) -> Result<Self, Self::Error> {
-        Ok(Self {
-            user_id: value.id.get(),
-            user_name: value.name.into_inner(),
-            mail_address: value.mail_address.into_inner(),
-        })
-    }
-}
-
-impl TryInto<User> for UserDto {
-    type Error = UserDomainToDtoConversionError;
-
-    fn try_into(self) -> Result<User, Self::Error> {
-        Ok(User {
-            id: UserId::new(self.user_id)?,
-            name: UserName::new(self.user_name)?,
-            mail_address: MailAddress::new(self.mail_address)?,
-        })
-    }
-}
-
-#[derive(Debug, thiserror::Error)]
-pub enum UserDomainToDtoConversionError {
-    #[error("Invalid UserId: {0}")]
-    InvalidUserId(#[from] UserIdError),
-    #[error("Invalid UserName: {0}")]
-    InvalidUserName(#[from] UserNameError),
-    #[error("Invalid MailAddress: {0}")]
-    InvalidMailAddress(#[from] MailAddressError),
+    pub password_hash: Option<String>,
+    pub role: String,
+    pub mail_verified_at: Option<DateTime<Utc>>,
+    pub pending_mail_address: Option<String>,
+    pub failed_login_count: i32,
+    pub locked_until: Option<DateTime<Utc>>,
+    pub two_factor_enabled: bool,
+    pub status: String,
+    pub suspension_reason: Option<String>,
+    pub suspended_until: Option<DateTime<Utc>>,
+    pub deleted_at: Option<DateTime<Utc>>,
 }
@@ -0,0 +1,91 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+// NOTE: SIGTERM(コンテナ停止)とSIGINT(Ctrl+C)のどちらかを受け取るまで待機する
+pub async fn wait_for_signal() -> &'static str {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("failed to install SIGINT handler");
+    };
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("failed to install SIGTERM handler")
+            .recv()
+            .await;
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => "SIGINT",
+        _ = terminate => "SIGTERM",
+    }
+}
+
+// NOTE: 処理中のリクエスト数と、完了前に破棄(強制終了)されたリクエスト数を数える
+#[derive(Debug, Default)]
+pub struct RequestTracker {
+    in_flight: AtomicUsize,
+    aborted: AtomicUsize,
+}
+
+impl RequestTracker {
+    pub fn start(self: &Arc<Self>) -> RequestGuard {
+        self.in_flight.fetch_add(1, Ordering::SeqCst);
+        RequestGuard {
+            tracker: self.clone(),
+            completed: false,
+        }
+    }
+
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::SeqCst)
+    }
+
+    pub fn aborted(&self) -> usize {
+        self.aborted.load(Ordering::SeqCst)
+    }
+}
+
+pub struct RequestGuard {
+    tracker: Arc<RequestTracker>,
+    completed: bool,
+}
+
+impl RequestGuard {
+    pub fn complete(mut self) {
+        self.completed = true;
+    }
+}
+
+impl Drop for RequestGuard {
+    fn drop(&mut self) {
+        self.tracker.in_flight.fetch_sub(1, Ordering::SeqCst);
+        if !self.completed {
+            self.tracker.aborted.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    fn request_tracker() {
+        let tracker = Arc::new(RequestTracker::default());
+        let completed = tracker.start();
+        let dropped = tracker.start();
+        assert_eq!(tracker.in_flight(), 2);
+
+        completed.complete();
+        drop(dropped);
+        assert_eq!(tracker.in_flight(), 0);
+        assert_eq!(tracker.aborted(), 1);
+    }
+}
@@ -0,0 +1,31 @@
+use tracing_subscriber::{
+    fmt,
+    util::{SubscriberInitExt as _, TryInitError},
+    EnvFilter,
+};
+
+use crate::config::{LogConfig, LogFormat};
+
+// NOTE: logクレート経由のログ(sqlx, actix-web等)もtracingのイベントとして出力される
+pub fn init(config: &LogConfig) -> Result<(), TelemetryError> {
+    let filter = EnvFilter::try_new(&config.level)?;
+    let builder = fmt().with_env_filter(filter);
+    match config.format {
+        LogFormat::Pretty => builder.pretty().finish().try_init()?,
+        LogFormat::Json => builder
+            .json()
+            .with_current_span(true)
+            .with_span_list(true)
+            .finish()
+            .try_init()?,
+    }
+    Ok(())
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TelemetryError {
+    #[error("ログのフィルタ指定が不正です。{0}")]
+    InvalidFilter(#[from] tracing_subscriber::filter::ParseError),
+    #[error(transparent)]
+    InitError(#[from] TryInitError),
+}
@@ -1,3 +1,18 @@
+mod account_lock_application_usecase;
+mod api_key_application_usecase;
+mod password_reset_application_usecase;
+mod session_application_usecase;
+mod two_factor_application_usecase;
 mod user_application_usecase;
 
+pub use account_lock_application_usecase::*;
+pub use api_key_application_usecase::*;
+pub use password_reset_application_usecase::*;
+pub use session_application_usecase::*;
+pub use two_factor_application_usecase::*;
 pub use user_application_usecase::*;
+
+// NOTE: メトリクスのラベル等に使用するため、エラーのバリアント名を返す
+pub trait UsecaseErrorKind {
+    fn kind(&self) -> &'static str;
+}
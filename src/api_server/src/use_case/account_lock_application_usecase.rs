@@ -0,0 +1,81 @@
+mod account_unlock_usecase;
+
+pub use account_unlock_usecase::*;
+
+use crate::{
+    domain::{Actor, UserId, UserIdError, UserPolicyError},
+    repository::{
+        AccountLockEventRepositoryError, TransactionManager, UserRepository, UserRepositoryError,
+        UserStatusFilter,
+    },
+};
+
+use super::UsecaseErrorKind;
+
+pub struct AccountLockUseCaseImpl<Tx, UserRepo, LockEventRepo, Clk> {
+    _marker: std::marker::PhantomData<fn() -> Tx>,
+    user_repository: UserRepo,
+    account_lock_event_repository: LockEventRepo,
+    clock: Clk,
+}
+
+impl<Tx, UserRepo, LockEventRepo, Clk> AccountLockUseCaseImpl<Tx, UserRepo, LockEventRepo, Clk> {
+    pub fn new(
+        user_repository: UserRepo,
+        account_lock_event_repository: LockEventRepo,
+        clock: Clk,
+    ) -> Self {
+        Self {
+            _marker: std::marker::PhantomData,
+            user_repository,
+            account_lock_event_repository,
+            clock,
+        }
+    }
+}
+
+impl<Tx, UserRepo, LockEventRepo, Clk> AccountLockUseCaseImpl<Tx, UserRepo, LockEventRepo, Clk>
+where
+    Tx: TransactionManager,
+    UserRepo: UserRepository<Tx>,
+{
+    async fn find_actor(
+        &self,
+        tx: &mut Tx::Transaction<'_>,
+        actor_id: &UserId,
+    ) -> Result<Actor, AccountLockUsecaseError> {
+        self.user_repository
+            .find_by_user_id(tx, actor_id, UserStatusFilter::Available)
+            .await?
+            .map(|user| Actor::from(&user))
+            .ok_or(AccountLockUsecaseError::Forbidden(
+                UserPolicyError::ActorNotFound,
+            ))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AccountLockUsecaseError {
+    #[error(transparent)]
+    UserIdError(#[from] UserIdError),
+    #[error(transparent)]
+    Forbidden(#[from] UserPolicyError),
+    #[error("{0}は不適切なuser_idです")]
+    UserIdNotExistsError(UserId),
+    #[error(transparent)]
+    UserRepositoryError(#[from] UserRepositoryError),
+    #[error(transparent)]
+    AccountLockEventRepositoryError(#[from] AccountLockEventRepositoryError),
+}
+
+impl UsecaseErrorKind for AccountLockUsecaseError {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::UserIdError(_) => "UserIdError",
+            Self::Forbidden(_) => "Forbidden",
+            Self::UserIdNotExistsError(_) => "UserIdNotExistsError",
+            Self::UserRepositoryError(_) => "UserRepositoryError",
+            Self::AccountLockEventRepositoryError(_) => "AccountLockEventRepositoryError",
+        }
+    }
+}
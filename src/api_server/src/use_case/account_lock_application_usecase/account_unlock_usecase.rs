@@ -0,0 +1,44 @@
+use sqlx_macros::usecase;
+use uuid::Uuid;
+
+use crate::{
+    domain::{Clock, UserAction, UserId, UserPolicy},
+    repository::{
+        AccountLockEventRepository, TransactionManager, UserRepository, UserStatusFilter,
+    },
+};
+
+use super::{AccountLockUseCaseImpl, AccountLockUsecaseError};
+
+#[usecase]
+impl<Tx, UserRepo, LockEventRepo, Clk> AccountUnlockUsecase<Tx>
+    for AccountLockUseCaseImpl<Tx, UserRepo, LockEventRepo, Clk>
+where
+    Tx: TransactionManager,
+    UserRepo: UserRepository<Tx>,
+    LockEventRepo: AccountLockEventRepository<Tx>,
+    Clk: Clock,
+{
+    #[tracing::instrument(name = "AccountUnlockUsecase::unlock", skip(self, tx, actor_id), fields(actor_id = %actor_id), err)]
+    async fn unlock(
+        &self,
+        tx: &mut Tx::Transaction<'_>,
+        actor_id: UserId,
+        user_id: Uuid,
+    ) -> Result<(), AccountLockUsecaseError> {
+        let target_id = UserId::new(user_id)?;
+        let actor = self.find_actor(tx, &actor_id).await?;
+        UserPolicy::authorize(&actor, UserAction::Unlock, &target_id)?;
+        let mut target_user = self
+            .user_repository
+            .find_by_user_id(tx, &target_id, UserStatusFilter::NotDeleted)
+            .await?
+            .ok_or_else(|| AccountLockUsecaseError::UserIdNotExistsError(target_id))?;
+
+        // NOTE: ロックされていない場合も失敗回数をリセットし、操作を記録する
+        let event = target_user.unlock(actor_id, self.clock.now());
+        self.user_repository.save(tx, target_user).await?;
+        self.account_lock_event_repository.save(tx, event).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+mod api_key_authenticate_usecase;
+mod api_key_dto;
+mod api_key_manage_usecase;
+
+pub use api_key_authenticate_usecase::*;
+pub use api_key_dto::{ApiKeyDto, ApiKeyPrincipalDto, IssuedApiKeyDto};
+pub use api_key_manage_usecase::*;
+
+use crate::{
+    domain::{
+        Actor, ApiKeyError, ApiKeyIdError, ApiKeyPolicy, ApiKeyPolicyError, ApiKeyScopeError,
+        UserId,
+    },
+    repository::{
+        ApiKeyRepositoryError, TransactionManager, UserRepository, UserRepositoryError,
+        UserStatusFilter,
+    },
+};
+
+use super::UsecaseErrorKind;
+
+pub struct ApiKeyUseCaseImpl<Tx, UserRepo, ApiKeyRepo, Clk> {
+    _marker: std::marker::PhantomData<fn() -> Tx>,
+    user_repository: UserRepo,
+    api_key_repository: ApiKeyRepo,
+    clock: Clk,
+}
+
+impl<Tx, UserRepo, ApiKeyRepo, Clk> ApiKeyUseCaseImpl<Tx, UserRepo, ApiKeyRepo, Clk> {
+    pub fn new(user_repository: UserRepo, api_key_repository: ApiKeyRepo, clock: Clk) -> Self {
+        Self {
+            _marker: std::marker::PhantomData,
+            user_repository,
+            api_key_repository,
+            clock,
+        }
+    }
+}
+
+impl<Tx, UserRepo, ApiKeyRepo, Clk> ApiKeyUseCaseImpl<Tx, UserRepo, ApiKeyRepo, Clk>
+where
+    Tx: TransactionManager,
+    UserRepo: UserRepository<Tx>,
+{
+    // NOTE: ロールの変更を即座に反映するため、トークンではなくDBから操作者を取得する
+    async fn authorize_management(
+        &self,
+        tx: &mut Tx::Transaction<'_>,
+        actor_id: &UserId,
+    ) -> Result<(), ApiKeyUsecaseError> {
+        let actor = self
+            .user_repository
+            .find_by_user_id(tx, actor_id, UserStatusFilter::Available)
+            .await?
+            .map(|user| Actor::from(&user))
+            .ok_or(ApiKeyUsecaseError::Forbidden(
+                ApiKeyPolicyError::ActorNotFound,
+            ))?;
+        Ok(ApiKeyPolicy::authorize_management(&actor)?)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ApiKeyUsecaseError {
+    #[error(transparent)]
+    ApiKeyIdError(#[from] ApiKeyIdError),
+    #[error(transparent)]
+    ApiKeyScopeError(#[from] ApiKeyScopeError),
+    #[error(transparent)]
+    ApiKeyError(#[from] ApiKeyError),
+    #[error(transparent)]
+    Forbidden(#[from] ApiKeyPolicyError),
+    #[error("APIキーが存在しません。")]
+    ApiKeyNotFound,
+    #[error(transparent)]
+    UserRepositoryError(#[from] UserRepositoryError),
+    #[error(transparent)]
+    ApiKeyRepositoryError(#[from] ApiKeyRepositoryError),
+}
+
+impl UsecaseErrorKind for ApiKeyUsecaseError {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::ApiKeyIdError(_) => "ApiKeyIdError",
+            Self::ApiKeyScopeError(_) => "ApiKeyScopeError",
+            Self::ApiKeyError(_) => "ApiKeyError",
+            Self::Forbidden(_) => "Forbidden",
+            Self::ApiKeyNotFound => "ApiKeyNotFound",
+            Self::UserRepositoryError(_) => "UserRepositoryError",
+            Self::ApiKeyRepositoryError(_) => "ApiKeyRepositoryError",
+        }
+    }
+}
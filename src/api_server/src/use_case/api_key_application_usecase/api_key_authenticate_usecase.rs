@@ -0,0 +1,48 @@
+use sqlx_macros::usecase;
+
+use crate::{
+    domain::{ApiKeyToken, Clock},
+    repository::{ApiKeyRepository, TransactionManager},
+};
+
+use super::{ApiKeyPrincipalDto, ApiKeyUseCaseImpl, ApiKeyUsecaseError};
+
+#[usecase]
+impl<Tx, UserRepo, ApiKeyRepo, Clk> ApiKeyAuthenticateUsecase<Tx>
+    for ApiKeyUseCaseImpl<Tx, UserRepo, ApiKeyRepo, Clk>
+where
+    Tx: TransactionManager,
+    ApiKeyRepo: ApiKeyRepository<Tx>,
+    Clk: Clock,
+{
+    // NOTE: 形式不正・存在しない・失効済みのいずれもNoneを返す
+    #[tracing::instrument(name = "ApiKeyAuthenticateUsecase::authenticate", skip_all, err)]
+    async fn authenticate(
+        &self,
+        tx: &mut Tx::Transaction<'_>,
+        key: &str,
+    ) -> Result<Option<ApiKeyPrincipalDto>, ApiKeyUsecaseError> {
+        let Ok(token) = ApiKeyToken::new(key) else {
+            return Ok(None);
+        };
+        let Some(mut api_key) = self
+            .api_key_repository
+            .find_by_key_hash(tx, &token.hash())
+            .await?
+            .filter(|api_key| api_key.is_active())
+        else {
+            tracing::info!(prefix = %token.prefix(), "unknown or revoked api key");
+            return Ok(None);
+        };
+
+        let principal = ApiKeyPrincipalDto {
+            id: api_key.id,
+            prefix: api_key.prefix.clone(),
+            scopes: api_key.scopes.clone(),
+        };
+        if api_key.record_use(self.clock.now()) {
+            self.api_key_repository.save(tx, api_key).await?;
+        }
+        Ok(Some(principal))
+    }
+}
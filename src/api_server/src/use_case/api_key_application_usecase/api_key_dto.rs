@@ -0,0 +1,49 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::{ApiKey, ApiKeyId, ApiKeyScope};
+
+// NOTE: ハッシュ値は含めず、識別用のprefixのみを返す
+pub struct ApiKeyDto {
+    pub id: Uuid,
+    pub name: String,
+    pub prefix: String,
+    pub scopes: Vec<String>,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl From<&ApiKey> for ApiKeyDto {
+    fn from(value: &ApiKey) -> Self {
+        Self {
+            id: value.id.get(),
+            name: value.name.clone(),
+            prefix: value.prefix.clone(),
+            scopes: value
+                .scopes
+                .iter()
+                .map(|scope| scope.as_str().to_string())
+                .collect(),
+            created_by: value.created_by.get(),
+            created_at: value.created_at,
+            last_used_at: value.last_used_at,
+            revoked_at: value.revoked_at,
+        }
+    }
+}
+
+// NOTE: 平文のキーは発行時にのみ返す
+pub struct IssuedApiKeyDto {
+    pub api_key: ApiKeyDto,
+    pub key: String,
+}
+
+// NOTE: 認証済みのAPIキー。ルートごとのスコープの検査に使用する
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiKeyPrincipalDto {
+    pub id: ApiKeyId,
+    pub prefix: String,
+    pub scopes: Vec<ApiKeyScope>,
+}
@@ -0,0 +1,80 @@
+use sqlx_macros::usecase;
+use uuid::Uuid;
+
+use crate::{
+    domain::{ApiKey, ApiKeyId, ApiKeyScope, Clock, UserId},
+    repository::{ApiKeyRepository, TransactionManager, UserRepository},
+};
+
+use super::{ApiKeyDto, ApiKeyUseCaseImpl, ApiKeyUsecaseError, IssuedApiKeyDto};
+
+#[usecase]
+impl<Tx, UserRepo, ApiKeyRepo, Clk> ApiKeyManageUsecase<Tx>
+    for ApiKeyUseCaseImpl<Tx, UserRepo, ApiKeyRepo, Clk>
+where
+    Tx: TransactionManager,
+    UserRepo: UserRepository<Tx>,
+    ApiKeyRepo: ApiKeyRepository<Tx>,
+    Clk: Clock,
+{
+    #[tracing::instrument(name = "ApiKeyManageUsecase::create", skip(self, tx, actor_id), fields(actor_id = %actor_id), err)]
+    async fn create(
+        &self,
+        tx: &mut Tx::Transaction<'_>,
+        actor_id: UserId,
+        name: String,
+        scopes: Vec<String>,
+    ) -> Result<IssuedApiKeyDto, ApiKeyUsecaseError> {
+        self.authorize_management(tx, &actor_id).await?;
+        let scopes = scopes
+            .iter()
+            .map(|scope| ApiKeyScope::new(scope))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let (api_key, token) = ApiKey::issue(&name, scopes, actor_id, self.clock.now())?;
+        tracing::info!(api_key_id = %api_key.id, prefix = %api_key.prefix, "api key issued");
+        let dto = ApiKeyDto::from(&api_key);
+        self.api_key_repository.save(tx, api_key).await?;
+        Ok(IssuedApiKeyDto {
+            api_key: dto,
+            key: token.get().to_string(),
+        })
+    }
+
+    #[tracing::instrument(name = "ApiKeyManageUsecase::list", skip(self, tx, actor_id), fields(actor_id = %actor_id), err)]
+    async fn list(
+        &self,
+        tx: &mut Tx::Transaction<'_>,
+        actor_id: UserId,
+    ) -> Result<Vec<ApiKeyDto>, ApiKeyUsecaseError> {
+        self.authorize_management(tx, &actor_id).await?;
+        Ok(self
+            .api_key_repository
+            .find_all(tx)
+            .await?
+            .iter()
+            .map(ApiKeyDto::from)
+            .collect())
+    }
+
+    // NOTE: 失効済みのキーも一覧に残し、いつ失効させたかを確認できるようにする
+    #[tracing::instrument(name = "ApiKeyManageUsecase::revoke", skip(self, tx, actor_id), fields(actor_id = %actor_id), err)]
+    async fn revoke(
+        &self,
+        tx: &mut Tx::Transaction<'_>,
+        actor_id: UserId,
+        api_key_id: Uuid,
+    ) -> Result<(), ApiKeyUsecaseError> {
+        self.authorize_management(tx, &actor_id).await?;
+        let api_key_id = ApiKeyId::new(api_key_id)?;
+        let mut api_key = self
+            .api_key_repository
+            .find_by_id(tx, &api_key_id)
+            .await?
+            .ok_or(ApiKeyUsecaseError::ApiKeyNotFound)?;
+
+        api_key.revoke(self.clock.now())?;
+        tracing::info!(api_key_id = %api_key.id, prefix = %api_key.prefix, "api key revoked");
+        Ok(self.api_key_repository.save(tx, api_key).await?)
+    }
+}
@@ -0,0 +1,108 @@
+mod password_reset_confirm_usecase;
+mod password_reset_issue_job;
+mod password_reset_request_usecase;
+
+pub use password_reset_confirm_usecase::*;
+pub use password_reset_request_usecase::*;
+
+use crate::{
+    domain::{
+        MailAddressError, PasswordError, PasswordHasherError, PasswordResetPolicyError,
+        PasswordResetRateLimit, SingleUseTokenError,
+    },
+    repository::{
+        MailOutboxRepositoryError, PasswordResetRepositoryError, SessionRepositoryError,
+        UserRepositoryError,
+    },
+};
+
+use super::UsecaseErrorKind;
+
+pub struct PasswordResetUseCaseImpl<Tx, UserRepo, ResetRepo, SessionRepo, Hasher, Outbox, Clk> {
+    _marker: std::marker::PhantomData<fn() -> Tx>,
+    user_repository: UserRepo,
+    password_reset_repository: ResetRepo,
+    session_repository: SessionRepo,
+    password_hasher: Hasher,
+    mail_outbox_repository: Outbox,
+    clock: Clk,
+    settings: PasswordResetSettings,
+}
+
+#[derive(Debug, Clone)]
+pub struct PasswordResetSettings {
+    pub ttl: chrono::Duration,
+    // NOTE: {token}が再設定トークンに置き換えられる
+    pub url_template: String,
+    pub rate_limit: PasswordResetRateLimit,
+}
+
+impl<Tx, UserRepo, ResetRepo, SessionRepo, Hasher, Outbox, Clk>
+    PasswordResetUseCaseImpl<Tx, UserRepo, ResetRepo, SessionRepo, Hasher, Outbox, Clk>
+{
+    pub fn new(
+        user_repository: UserRepo,
+        password_reset_repository: ResetRepo,
+        session_repository: SessionRepo,
+        password_hasher: Hasher,
+        mail_outbox_repository: Outbox,
+        clock: Clk,
+        settings: PasswordResetSettings,
+    ) -> Self {
+        Self {
+            _marker: std::marker::PhantomData,
+            user_repository,
+            password_reset_repository,
+            session_repository,
+            password_hasher,
+            mail_outbox_repository,
+            clock,
+            settings,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PasswordResetUsecaseError {
+    #[error(transparent)]
+    RateLimited(#[from] PasswordResetPolicyError),
+    // NOTE: 存在しない・使用済み・期限切れのいずれも同じエラーとする
+    #[error("再設定トークンが無効です。")]
+    InvalidToken,
+    #[error(transparent)]
+    MailAddressError(#[from] MailAddressError),
+    #[error(transparent)]
+    PasswordError(#[from] PasswordError),
+    #[error(transparent)]
+    PasswordHasherError(#[from] PasswordHasherError),
+    #[error(transparent)]
+    UserRepositoryError(#[from] UserRepositoryError),
+    #[error(transparent)]
+    PasswordResetRepositoryError(#[from] PasswordResetRepositoryError),
+    #[error(transparent)]
+    SessionRepositoryError(#[from] SessionRepositoryError),
+    #[error(transparent)]
+    MailOutboxRepositoryError(#[from] MailOutboxRepositoryError),
+}
+
+impl From<SingleUseTokenError> for PasswordResetUsecaseError {
+    fn from(_: SingleUseTokenError) -> Self {
+        Self::InvalidToken
+    }
+}
+
+impl UsecaseErrorKind for PasswordResetUsecaseError {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::RateLimited(_) => "RateLimited",
+            Self::InvalidToken => "InvalidToken",
+            Self::MailAddressError(_) => "MailAddressError",
+            Self::PasswordError(_) => "PasswordError",
+            Self::PasswordHasherError(_) => "PasswordHasherError",
+            Self::UserRepositoryError(_) => "UserRepositoryError",
+            Self::PasswordResetRepositoryError(_) => "PasswordResetRepositoryError",
+            Self::SessionRepositoryError(_) => "SessionRepositoryError",
+            Self::MailOutboxRepositoryError(_) => "MailOutboxRepositoryError",
+        }
+    }
+}
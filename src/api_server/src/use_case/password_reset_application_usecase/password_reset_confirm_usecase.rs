@@ -0,0 +1,63 @@
+use sqlx_macros::usecase;
+
+use crate::{
+    domain::{Clock, Password, PasswordHasher, SecretToken},
+    repository::{
+        PasswordResetRepository, SessionRepository, TransactionManager, UserRepository,
+        UserStatusFilter,
+    },
+};
+
+use super::{PasswordResetUseCaseImpl, PasswordResetUsecaseError};
+
+#[usecase]
+impl<Tx, UserRepo, ResetRepo, SessionRepo, Hasher, Outbox, Clk> PasswordResetConfirmUsecase<Tx>
+    for PasswordResetUseCaseImpl<Tx, UserRepo, ResetRepo, SessionRepo, Hasher, Outbox, Clk>
+where
+    Tx: TransactionManager,
+    UserRepo: UserRepository<Tx>,
+    ResetRepo: PasswordResetRepository<Tx>,
+    SessionRepo: SessionRepository<Tx>,
+    Hasher: PasswordHasher,
+    Clk: Clock,
+{
+    #[tracing::instrument(name = "PasswordResetConfirmUsecase::reset_password", skip_all, err)]
+    async fn reset_password(
+        &self,
+        tx: &mut Tx::Transaction<'_>,
+        raw_token: String,
+        raw_password: String,
+    ) -> Result<(), PasswordResetUsecaseError> {
+        let token =
+            SecretToken::new(raw_token).map_err(|_| PasswordResetUsecaseError::InvalidToken)?;
+        let mut reset = self
+            .password_reset_repository
+            .find_by_token_hash(tx, &token.hash())
+            .await?
+            .ok_or(PasswordResetUsecaseError::InvalidToken)?;
+        let mut user = self
+            .user_repository
+            .find_by_user_id(tx, &reset.user_id, UserStatusFilter::Available)
+            .await?
+            .ok_or(PasswordResetUsecaseError::InvalidToken)?;
+
+        let now = self.clock.now();
+        reset.token.consume(now)?;
+        let password = Password::new(raw_password)?;
+        user.change_password_hash(self.password_hasher.hash(&password).await?);
+        // NOTE: 新しいパスワードでログインできるよう、失敗回数とロックを解除する
+        user.record_login_success();
+
+        // NOTE: 漏洩したパスワードで作られたセッションを使えないよう、すべて失効させる
+        self.session_repository
+            .revoke_all_for_user(tx, &user.id, now)
+            .await?;
+        // NOTE: 以前に発行された再設定リンクが漏洩していても使えないよう、未使用のトークンも無効にする
+        self.password_reset_repository
+            .invalidate_unused_for_user(tx, &user.id, now)
+            .await?;
+        self.user_repository.save(tx, user).await?;
+        self.password_reset_repository.save(tx, reset).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use async_trait::async_trait;
+
+use crate::{
+    domain::{Clock, PasswordReset},
+    mailer::Mail,
+    repository::{
+        MailOutboxRepository, PasswordResetRepository, TransactionManager, UserRepository,
+        UserStatusFilter,
+    },
+    worker::BatchJob,
+};
+
+use super::{PasswordResetUseCaseImpl, PasswordResetUsecaseError};
+
+// NOTE: 受け付けた申請ごとにユーザーを検索し、存在する場合のみ再設定トークンを発行してメールを送信待ちに登録する
+#[async_trait]
+impl<Tx, UserRepo, ResetRepo, SessionRepo, Hasher, Outbox, Clk> BatchJob<Tx>
+    for PasswordResetUseCaseImpl<Tx, UserRepo, ResetRepo, SessionRepo, Hasher, Outbox, Clk>
+where
+    Tx: TransactionManager,
+    UserRepo: UserRepository<Tx> + Send + Sync,
+    ResetRepo: PasswordResetRepository<Tx> + Send + Sync,
+    SessionRepo: Send + Sync,
+    Hasher: Send + Sync,
+    Outbox: MailOutboxRepository<Tx> + Send + Sync,
+    Clk: Clock + Send + Sync,
+{
+    type Error = PasswordResetUsecaseError;
+
+    #[tracing::instrument(name = "PasswordResetIssueJob::run_batch", skip_all, err)]
+    async fn run_batch(
+        &self,
+        tx: &mut Tx::Transaction<'_>,
+        limit: u32,
+    ) -> Result<usize, PasswordResetUsecaseError> {
+        let requests = self
+            .password_reset_repository
+            .lock_pending_requests(tx, limit)
+            .await?;
+        let count = requests.len();
+        for request in requests {
+            let now = self.clock.now();
+            if let Some(user) = self
+                .user_repository
+                .find_by_mail_address(tx, &request.mail_address, UserStatusFilter::Available)
+                .await?
+            {
+                let (reset, token) = PasswordReset::issue(user.id, now, self.settings.ttl);
+                let url = self.settings.url_template.replace("{token}", token.get());
+                let mail = Mail {
+                    to: user.mail_address.clone(),
+                    subject: "パスワードの再設定".to_string(),
+                    body: format!(
+                        "{}様\n\n以下のURLからパスワードを再設定してください。\n{}\n\nこのURLの有効期限は{}です。\n心当たりがない場合は、このメールを破棄してください。",
+                        user.name,
+                        url,
+                        reset.token.expires_at.to_rfc3339(),
+                    ),
+                };
+                self.password_reset_repository.save(tx, reset).await?;
+                self.mail_outbox_repository.enqueue(tx, mail, now).await?;
+            }
+            self.password_reset_repository
+                .mark_request_processed(tx, request.id, now)
+                .await?;
+        }
+        Ok(count)
+    }
+}
@@ -0,0 +1,47 @@
+use sqlx_macros::usecase;
+
+use crate::{
+    domain::{Clock, MailAddress},
+    repository::{PasswordResetRepository, TransactionManager},
+};
+
+use super::{PasswordResetUseCaseImpl, PasswordResetUsecaseError};
+
+#[usecase]
+impl<Tx, UserRepo, ResetRepo, SessionRepo, Hasher, Outbox, Clk> PasswordResetRequestUsecase<Tx>
+    for PasswordResetUseCaseImpl<Tx, UserRepo, ResetRepo, SessionRepo, Hasher, Outbox, Clk>
+where
+    Tx: TransactionManager,
+    ResetRepo: PasswordResetRepository<Tx>,
+    Clk: Clock,
+{
+    // NOTE: アドレスの存在有無を漏らさないよう、ここでは申請の記録と発行数の制限のみを行う
+    //       ユーザーの検索と再設定メールの発行はPasswordResetIssueJobが非同期に行うため、
+    //       応答の内容・時間はユーザーの有無によらない
+    #[tracing::instrument(
+        name = "PasswordResetRequestUsecase::request_reset",
+        skip(self, tx, raw_mail_address),
+        err
+    )]
+    async fn request_reset(
+        &self,
+        tx: &mut Tx::Transaction<'_>,
+        raw_mail_address: String,
+        ip_address: String,
+    ) -> Result<(), PasswordResetUsecaseError> {
+        let mail_address = MailAddress::new(raw_mail_address)?;
+        let now = self.clock.now();
+        let counts = self
+            .password_reset_repository
+            .record_request(
+                tx,
+                &mail_address,
+                &ip_address,
+                now,
+                now - self.settings.rate_limit.window,
+            )
+            .await?;
+        self.settings.rate_limit.check(counts)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,210 @@
+mod session_create_usecase;
+mod session_dto;
+mod session_refresh_usecase;
+mod session_revoke_usecase;
+mod session_two_factor_usecase;
+
+pub use session_create_usecase::*;
+pub use session_dto::{IssuedSessionDto, LoginChallengeDto, LoginOutcome, LoginRejection};
+pub use session_refresh_usecase::*;
+pub use session_revoke_usecase::*;
+pub use session_two_factor_usecase::*;
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    domain::{
+        AccessTokenCodec, AccessTokenError, LoginThrottlePolicy, PasswordHasherError, SecretToken,
+        Session, SessionError, Totp, UserId,
+    },
+    repository::{
+        AccountLockEventRepository, AccountLockEventRepositoryError, LoginThrottleRepositoryError,
+        SessionRepositoryError, TransactionManager, TwoFactorRepositoryError, UserRepository,
+        UserRepositoryError, UserStatusFilter,
+    },
+};
+
+use super::UsecaseErrorKind;
+
+pub struct SessionUseCaseImpl<
+    Tx,
+    UserRepo,
+    SessionRepo,
+    ThrottleRepo,
+    LockEventRepo,
+    TwoFactorRepo,
+    Hasher,
+    Codec,
+    Clk,
+> {
+    _marker: std::marker::PhantomData<fn() -> Tx>,
+    user_repository: UserRepo,
+    session_repository: SessionRepo,
+    login_throttle_repository: ThrottleRepo,
+    account_lock_event_repository: LockEventRepo,
+    two_factor_repository: TwoFactorRepo,
+    password_hasher: Hasher,
+    access_token_codec: Codec,
+    clock: Clk,
+    settings: SessionSettings,
+}
+
+#[derive(Debug, Clone)]
+pub struct SessionSettings {
+    pub refresh_token_ttl: chrono::Duration,
+    pub login_throttle: LoginThrottlePolicy,
+    pub totp: Totp,
+    // NOTE: パスワードの確認後、二要素目を入力するまでの猶予
+    pub login_challenge_ttl: chrono::Duration,
+}
+
+impl<Tx, UserRepo, SessionRepo, ThrottleRepo, LockEventRepo, TwoFactorRepo, Hasher, Codec, Clk>
+    SessionUseCaseImpl<
+        Tx,
+        UserRepo,
+        SessionRepo,
+        ThrottleRepo,
+        LockEventRepo,
+        TwoFactorRepo,
+        Hasher,
+        Codec,
+        Clk,
+    >
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        user_repository: UserRepo,
+        session_repository: SessionRepo,
+        login_throttle_repository: ThrottleRepo,
+        account_lock_event_repository: LockEventRepo,
+        two_factor_repository: TwoFactorRepo,
+        password_hasher: Hasher,
+        access_token_codec: Codec,
+        clock: Clk,
+        settings: SessionSettings,
+    ) -> Self {
+        Self {
+            _marker: std::marker::PhantomData,
+            user_repository,
+            session_repository,
+            login_throttle_repository,
+            account_lock_event_repository,
+            two_factor_repository,
+            password_hasher,
+            access_token_codec,
+            clock,
+            settings,
+        }
+    }
+}
+
+impl<Tx, UserRepo, SessionRepo, ThrottleRepo, LockEventRepo, TwoFactorRepo, Hasher, Codec, Clk>
+    SessionUseCaseImpl<
+        Tx,
+        UserRepo,
+        SessionRepo,
+        ThrottleRepo,
+        LockEventRepo,
+        TwoFactorRepo,
+        Hasher,
+        Codec,
+        Clk,
+    >
+where
+    Codec: AccessTokenCodec,
+{
+    fn issue_tokens(
+        &self,
+        session: &Session,
+        refresh_token: SecretToken,
+        now: DateTime<Utc>,
+    ) -> Result<IssuedSessionDto, SessionUsecaseError> {
+        let access_token = self.access_token_codec.encode(&session.user_id, now)?;
+        Ok(IssuedSessionDto {
+            access_token: access_token.token,
+            access_token_expires_at: access_token.expires_at,
+            refresh_token: refresh_token.get().to_string(),
+            refresh_token_expires_at: session.expires_at,
+        })
+    }
+}
+
+impl<Tx, UserRepo, SessionRepo, ThrottleRepo, LockEventRepo, TwoFactorRepo, Hasher, Codec, Clk>
+    SessionUseCaseImpl<
+        Tx,
+        UserRepo,
+        SessionRepo,
+        ThrottleRepo,
+        LockEventRepo,
+        TwoFactorRepo,
+        Hasher,
+        Codec,
+        Clk,
+    >
+where
+    Tx: TransactionManager,
+    UserRepo: UserRepository<Tx>,
+    LockEventRepo: AccountLockEventRepository<Tx>,
+{
+    // NOTE: パスワード・二要素目のいずれの誤りも同じ回数として数える
+    //       同時に失敗した場合も数え漏らさないよう、行をロックして読み直してから加算する
+    async fn record_account_failure(
+        &self,
+        tx: &mut Tx::Transaction<'_>,
+        user_id: &UserId,
+        now: DateTime<Utc>,
+    ) -> Result<(), SessionUsecaseError> {
+        let Some(mut user) = self
+            .user_repository
+            .find_by_user_id_for_update(tx, user_id, UserStatusFilter::NotDeleted)
+            .await?
+        else {
+            return Ok(());
+        };
+        if let Some(event) = user.record_login_failure(now, &self.settings.login_throttle) {
+            tracing::warn!(
+                user_id = %user.id,
+                locked_until = ?event.locked_until,
+                "account locked after repeated login failures"
+            );
+            self.account_lock_event_repository.save(tx, event).await?;
+        }
+        self.user_repository.save(tx, user).await?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SessionUsecaseError {
+    #[error(transparent)]
+    SessionError(#[from] SessionError),
+    #[error(transparent)]
+    AccessTokenError(#[from] AccessTokenError),
+    #[error(transparent)]
+    PasswordHasherError(#[from] PasswordHasherError),
+    #[error(transparent)]
+    UserRepositoryError(#[from] UserRepositoryError),
+    #[error(transparent)]
+    SessionRepositoryError(#[from] SessionRepositoryError),
+    #[error(transparent)]
+    LoginThrottleRepositoryError(#[from] LoginThrottleRepositoryError),
+    #[error(transparent)]
+    AccountLockEventRepositoryError(#[from] AccountLockEventRepositoryError),
+    #[error(transparent)]
+    TwoFactorRepositoryError(#[from] TwoFactorRepositoryError),
+}
+
+impl UsecaseErrorKind for SessionUsecaseError {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::SessionError(_) => "SessionError",
+            Self::AccessTokenError(_) => "AccessTokenError",
+            Self::PasswordHasherError(_) => "PasswordHasherError",
+            Self::UserRepositoryError(_) => "UserRepositoryError",
+            Self::SessionRepositoryError(_) => "SessionRepositoryError",
+            Self::LoginThrottleRepositoryError(_) => "LoginThrottleRepositoryError",
+            Self::AccountLockEventRepositoryError(_) => "AccountLockEventRepositoryError",
+            Self::TwoFactorRepositoryError(_) => "TwoFactorRepositoryError",
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use sqlx_macros::usecase;
+
+use crate::{
+    domain::{
+        AccessTokenCodec, Clock, LoginChallenge, MailAddress, Password, PasswordHasher,
+        PasswordVerification, Session,
+    },
+    repository::{
+        AccountLockEventRepository, LoginThrottleRepository, SessionRepository, TransactionManager,
+        TwoFactorRepository, UserRepository, UserStatusFilter,
+    },
+};
+
+use super::{
+    LoginChallengeDto, LoginOutcome, LoginRejection, SessionUseCaseImpl, SessionUsecaseError,
+};
+
+#[usecase]
+impl<Tx, UserRepo, SessionRepo, ThrottleRepo, LockEventRepo, TwoFactorRepo, Hasher, Codec, Clk>
+    SessionCreateUsecase<Tx>
+    for SessionUseCaseImpl<
+        Tx,
+        UserRepo,
+        SessionRepo,
+        ThrottleRepo,
+        LockEventRepo,
+        TwoFactorRepo,
+        Hasher,
+        Codec,
+        Clk,
+    >
+where
+    Tx: TransactionManager,
+    UserRepo: UserRepository<Tx>,
+    SessionRepo: SessionRepository<Tx>,
+    ThrottleRepo: LoginThrottleRepository<Tx>,
+    LockEventRepo: AccountLockEventRepository<Tx>,
+    TwoFactorRepo: TwoFactorRepository<Tx>,
+    Hasher: PasswordHasher,
+    Codec: AccessTokenCodec,
+    Clk: Clock,
+{
+    // NOTE: 認証に失敗した場合はErr(LoginRejection)を返す
+    //       失敗回数・ロックの記録をコミットするため、外側のErrにはしない
+    #[tracing::instrument(
+        name = "SessionCreateUsecase::login",
+        skip(self, tx, raw_mail_address, raw_password),
+        err
+    )]
+    async fn login(
+        &self,
+        tx: &mut Tx::Transaction<'_>,
+        raw_mail_address: String,
+        raw_password: String,
+        ip_address: String,
+    ) -> Result<Result<LoginOutcome, LoginRejection>, SessionUsecaseError> {
+        let policy = &self.settings.login_throttle;
+        let now = self.clock.now();
+        if let Some(retry_after) = self
+            .login_throttle_repository
+            .find_by_ip_address(tx, &ip_address)
+            .await?
+            .and_then(|throttle| throttle.retry_after(now, policy))
+        {
+            return Ok(Err(LoginRejection::TooManyAttempts { retry_after }));
+        }
+
+        // NOTE: 登録済みのアドレスと照合するだけのため、形式の検証を追加する前に登録されたアドレスも受け付ける
+        //       検索にのみ使い、保存はしないため、DBの値と同様に検証せずに扱う
+        let mail_address = MailAddress::from_stored(raw_mail_address.trim().to_string());
+        let password = Password::unvalidated(raw_password);
+        let user = self
+            .user_repository
+            .find_by_mail_address(tx, &mail_address, UserStatusFilter::NotDeleted)
+            .await?;
+
+        // NOTE: アカウントの有無をレスポンスや処理時間から推測できないよう、
+        //       ユーザーが存在しない場合やロック中の場合もハッシュの照合を行う
+        let verification = self
+            .password_hasher
+            .verify(
+                &password,
+                user.as_ref().and_then(|user| user.password_hash.as_ref()),
+            )
+            .await?;
+        let (mut user, needs_rehash) = match (user, verification) {
+            (Some(user), PasswordVerification::Valid { needs_rehash }) => (user, needs_rehash),
+            (user, _) => {
+                self.login_throttle_repository
+                    .record_failure(tx, &ip_address, now, policy.ip_address_window)
+                    .await?;
+                // NOTE: ロック中の失敗は数えない(ロックの期間を延ばし続けられないようにする)
+                if let Some(user) = user.filter(|user| user.lock_remaining(now).is_none()) {
+                    self.record_account_failure(tx, &user.id, now).await?;
+                }
+                return Ok(Err(LoginRejection::InvalidCredentials));
+            }
+        };
+
+        // NOTE: ロック中は正しいパスワードでもログインできない
+        //       ロック・利用停止はパスワードが正しい場合のみ知らせる
+        if let Some(retry_after) = user.lock_remaining(now) {
+            return Ok(Err(LoginRejection::TooManyAttempts { retry_after }));
+        }
+        if user.status.is_suspended_at(now) {
+            return Ok(Err(LoginRejection::AccountSuspended));
+        }
+
+        // NOTE: ハッシュのパラメータが変更されていた場合、現在の設定で再ハッシュする
+        if needs_rehash {
+            user.change_password_hash(self.password_hasher.hash(&password).await?);
+        }
+
+        // NOTE: 二要素目の確認が完了するまで失敗回数はリセットしない
+        if user.two_factor_enabled {
+            let (challenge, challenge_token) =
+                LoginChallenge::issue(user.id, now, self.settings.login_challenge_ttl);
+            let issued = LoginChallengeDto {
+                challenge_token: challenge_token.get().to_string(),
+                expires_at: challenge.token.expires_at,
+            };
+            if needs_rehash {
+                self.user_repository.save(tx, user).await?;
+            }
+            self.two_factor_repository
+                .save_login_challenge(tx, challenge)
+                .await?;
+            return Ok(Ok(LoginOutcome::TwoFactorRequired(issued)));
+        }
+
+        let needs_save = needs_rehash || user.failed_login_count > 0;
+        user.record_login_success();
+
+        let (session, refresh_token) =
+            Session::issue(user.id, now, self.settings.refresh_token_ttl)?;
+        let issued = self.issue_tokens(&session, refresh_token, now)?;
+        if needs_save {
+            self.user_repository.save(tx, user).await?;
+        }
+        self.session_repository.save(tx, session).await?;
+
+        Ok(Ok(LoginOutcome::Issued(issued)))
+    }
+}
@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+
+pub struct IssuedSessionDto {
+    pub access_token: String,
+    pub access_token_expires_at: DateTime<Utc>,
+    pub refresh_token: String,
+    pub refresh_token_expires_at: DateTime<Utc>,
+}
+
+pub struct LoginChallengeDto {
+    pub challenge_token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+// NOTE: 二要素認証が有効なユーザーは、パスワードの確認後に二要素目の確認を求める
+pub enum LoginOutcome {
+    Issued(IssuedSessionDto),
+    TwoFactorRequired(LoginChallengeDto),
+}
+
+// NOTE: ログインの失敗回数を記録するため、エラーにはせずコミットする
+#[derive(Debug, thiserror::Error)]
+pub enum LoginRejection {
+    // NOTE: ユーザーの存在有無が分からないよう、失敗理由は区別しない
+    #[error("メールアドレスまたはパスワードが正しくありません。")]
+    InvalidCredentials,
+    #[error("ログインの試行回数が多すぎます。しばらく時間をおいてから再度お試しください。")]
+    TooManyAttempts { retry_after: chrono::Duration },
+    #[error("このアカウントは利用停止中です。")]
+    AccountSuspended,
+    #[error("確認コードが正しくありません。")]
+    InvalidTwoFactorCode,
+    // NOTE: 期限切れ・使用済みの場合はパスワードの入力からやり直す
+    #[error("ログインの有効期限が切れています。再度ログインしてください。")]
+    InvalidLoginChallenge,
+}
@@ -0,0 +1,85 @@
+use sqlx_macros::usecase;
+
+use crate::{
+    domain::{AccessTokenCodec, Clock, SecretToken, SessionState},
+    repository::{SessionRepository, TransactionManager, UserRepository, UserStatusFilter},
+};
+
+use super::{IssuedSessionDto, SessionUseCaseImpl, SessionUsecaseError};
+
+#[usecase]
+impl<Tx, UserRepo, SessionRepo, ThrottleRepo, LockEventRepo, TwoFactorRepo, Hasher, Codec, Clk>
+    SessionRefreshUsecase<Tx>
+    for SessionUseCaseImpl<
+        Tx,
+        UserRepo,
+        SessionRepo,
+        ThrottleRepo,
+        LockEventRepo,
+        TwoFactorRepo,
+        Hasher,
+        Codec,
+        Clk,
+    >
+where
+    Tx: TransactionManager,
+    UserRepo: UserRepository<Tx>,
+    SessionRepo: SessionRepository<Tx>,
+    Codec: AccessTokenCodec,
+    Clk: Clock,
+{
+    // NOTE: 受け付けられないトークンの場合はNoneを返す
+    //       失効済みトークンの再利用時に行うファミリーの失効をコミットするため、エラーにはしない
+    #[tracing::instrument(name = "SessionRefreshUsecase::refresh", skip_all, err)]
+    async fn refresh(
+        &self,
+        tx: &mut Tx::Transaction<'_>,
+        raw_refresh_token: String,
+    ) -> Result<Option<IssuedSessionDto>, SessionUsecaseError> {
+        let Ok(refresh_token) = SecretToken::new(raw_refresh_token) else {
+            return Ok(None);
+        };
+        let Some(mut session) = self
+            .session_repository
+            .find_by_token_hash(tx, &refresh_token.hash())
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let now = self.clock.now();
+        match session.state(now) {
+            SessionState::Active => {}
+            SessionState::Expired => return Ok(None),
+            // NOTE: ローテーション済みのトークンが使われた場合は漏洩とみなし、ファミリーごと失効させる
+            SessionState::Revoked => {
+                tracing::warn!(
+                    user_id = %session.user_id,
+                    family_id = %session.family_id,
+                    "revoked refresh token reused"
+                );
+                self.session_repository
+                    .revoke_family(tx, &session.family_id, now)
+                    .await?;
+                return Ok(None);
+            }
+        }
+
+        // NOTE: 利用停止・退会したユーザーのセッションは延長しない
+        if self
+            .user_repository
+            .find_by_user_id(tx, &session.user_id, UserStatusFilter::Available)
+            .await?
+            .is_none()
+        {
+            return Ok(None);
+        }
+
+        let (rotated, refresh_token) = session.rotate(now, self.settings.refresh_token_ttl)?;
+        let issued = self.issue_tokens(&rotated, refresh_token, now)?;
+        self.session_repository.save(tx, session).await?;
+        self.session_repository.save(tx, rotated).await?;
+
+        Ok(Some(issued))
+    }
+}
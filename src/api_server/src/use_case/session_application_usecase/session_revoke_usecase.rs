@@ -0,0 +1,50 @@
+use sqlx_macros::usecase;
+
+use crate::{
+    domain::{Clock, SecretToken},
+    repository::{SessionRepository, TransactionManager},
+};
+
+use super::{SessionUseCaseImpl, SessionUsecaseError};
+
+#[usecase]
+impl<Tx, UserRepo, SessionRepo, ThrottleRepo, LockEventRepo, TwoFactorRepo, Hasher, Codec, Clk>
+    SessionRevokeUsecase<Tx>
+    for SessionUseCaseImpl<
+        Tx,
+        UserRepo,
+        SessionRepo,
+        ThrottleRepo,
+        LockEventRepo,
+        TwoFactorRepo,
+        Hasher,
+        Codec,
+        Clk,
+    >
+where
+    Tx: TransactionManager,
+    SessionRepo: SessionRepository<Tx>,
+    Clk: Clock,
+{
+    // NOTE: ログアウト。未知のトークンでも成功として扱う(RFC 7009)
+    #[tracing::instrument(name = "SessionRevokeUsecase::revoke", skip_all, err)]
+    async fn revoke(
+        &self,
+        tx: &mut Tx::Transaction<'_>,
+        raw_refresh_token: String,
+    ) -> Result<(), SessionUsecaseError> {
+        let Ok(refresh_token) = SecretToken::new(raw_refresh_token) else {
+            return Ok(());
+        };
+        if let Some(session) = self
+            .session_repository
+            .find_by_token_hash(tx, &refresh_token.hash())
+            .await?
+        {
+            self.session_repository
+                .revoke_family(tx, &session.family_id, self.clock.now())
+                .await?;
+        }
+        Ok(())
+    }
+}
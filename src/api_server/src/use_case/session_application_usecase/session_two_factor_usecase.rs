@@ -0,0 +1,103 @@
+use sqlx_macros::usecase;
+
+use crate::{
+    domain::{AccessTokenCodec, Clock, SecretToken, Session},
+    repository::{
+        AccountLockEventRepository, SessionRepository, TransactionManager, TwoFactorRepository,
+        UserRepository, UserStatusFilter,
+    },
+    use_case::{verify_two_factor_code, TwoFactorCode},
+};
+
+use super::{IssuedSessionDto, LoginRejection, SessionUseCaseImpl, SessionUsecaseError};
+
+#[usecase]
+impl<Tx, UserRepo, SessionRepo, ThrottleRepo, LockEventRepo, TwoFactorRepo, Hasher, Codec, Clk>
+    SessionTwoFactorUsecase<Tx>
+    for SessionUseCaseImpl<
+        Tx,
+        UserRepo,
+        SessionRepo,
+        ThrottleRepo,
+        LockEventRepo,
+        TwoFactorRepo,
+        Hasher,
+        Codec,
+        Clk,
+    >
+where
+    Tx: TransactionManager,
+    UserRepo: UserRepository<Tx>,
+    SessionRepo: SessionRepository<Tx>,
+    LockEventRepo: AccountLockEventRepository<Tx>,
+    TwoFactorRepo: TwoFactorRepository<Tx>,
+    Codec: AccessTokenCodec,
+    Clk: Clock,
+{
+    // NOTE: ログインの2段階目。パスワードの確認時に発行したトークンと二要素目のコードを検証する
+    //       失敗回数・ロックの記録をコミットするため、認証の失敗は外側のErrにはしない
+    #[tracing::instrument(name = "SessionTwoFactorUsecase::verify_two_factor", skip_all, err)]
+    async fn verify_two_factor(
+        &self,
+        tx: &mut Tx::Transaction<'_>,
+        raw_challenge_token: String,
+        code: TwoFactorCode,
+    ) -> Result<Result<IssuedSessionDto, LoginRejection>, SessionUsecaseError> {
+        let now = self.clock.now();
+        let Ok(challenge_token) = SecretToken::new(raw_challenge_token) else {
+            return Ok(Err(LoginRejection::InvalidLoginChallenge));
+        };
+        let Some(mut challenge) = self
+            .two_factor_repository
+            .find_login_challenge(tx, &challenge_token.hash())
+            .await?
+            .filter(|challenge| challenge.token.is_usable(now))
+        else {
+            return Ok(Err(LoginRejection::InvalidLoginChallenge));
+        };
+        let Some(mut user) = self
+            .user_repository
+            .find_by_user_id(tx, &challenge.user_id, UserStatusFilter::Available)
+            .await?
+        else {
+            return Ok(Err(LoginRejection::InvalidLoginChallenge));
+        };
+        if let Some(retry_after) = user.lock_remaining(now) {
+            return Ok(Err(LoginRejection::TooManyAttempts { retry_after }));
+        }
+
+        // NOTE: 誤りの場合もトークンは有効期限まで再試行できる。総当たりはアカウントのロックで抑止する
+        let verified = verify_two_factor_code(
+            &self.two_factor_repository,
+            tx,
+            &user.id,
+            &self.settings.totp,
+            code,
+            now,
+        )
+        .await?;
+        if !verified {
+            self.record_account_failure(tx, &user.id, now).await?;
+            return Ok(Err(LoginRejection::InvalidTwoFactorCode));
+        }
+
+        if challenge.token.consume(now).is_err() {
+            return Ok(Err(LoginRejection::InvalidLoginChallenge));
+        }
+        let needs_save = user.failed_login_count > 0;
+        user.record_login_success();
+
+        let (session, refresh_token) =
+            Session::issue(user.id, now, self.settings.refresh_token_ttl)?;
+        let issued = self.issue_tokens(&session, refresh_token, now)?;
+        if needs_save {
+            self.user_repository.save(tx, user).await?;
+        }
+        self.two_factor_repository
+            .save_login_challenge(tx, challenge)
+            .await?;
+        self.session_repository.save(tx, session).await?;
+
+        Ok(Ok(issued))
+    }
+}
@@ -0,0 +1,184 @@
+mod two_factor_disable_usecase;
+mod two_factor_dto;
+mod two_factor_enroll_usecase;
+
+pub use two_factor_disable_usecase::*;
+pub use two_factor_dto::{TotpEnrollmentDto, TwoFactorCode, TwoFactorRejection};
+pub use two_factor_enroll_usecase::*;
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    domain::{
+        LoginThrottlePolicy, RecoveryCode, Totp, TotpCredential, TotpCredentialError, User, UserId,
+    },
+    repository::{
+        AccountLockEventRepository, AccountLockEventRepositoryError, TransactionManager,
+        TwoFactorRepository, TwoFactorRepositoryError, UserRepository, UserRepositoryError,
+        UserStatusFilter,
+    },
+};
+
+use super::UsecaseErrorKind;
+
+pub struct TwoFactorUseCaseImpl<Tx, UserRepo, TwoFactorRepo, LockEventRepo, Clk> {
+    _marker: std::marker::PhantomData<fn() -> Tx>,
+    user_repository: UserRepo,
+    two_factor_repository: TwoFactorRepo,
+    account_lock_event_repository: LockEventRepo,
+    clock: Clk,
+    settings: TwoFactorSettings,
+}
+
+#[derive(Debug, Clone)]
+pub struct TwoFactorSettings {
+    // NOTE: 認証アプリに表示されるサービス名
+    pub issuer: String,
+    pub totp: Totp,
+    pub recovery_code_count: usize,
+    // NOTE: 無効化時のコードの誤りはログインの失敗と同様に数え、アカウントをロックする
+    pub login_throttle: LoginThrottlePolicy,
+}
+
+impl<Tx, UserRepo, TwoFactorRepo, LockEventRepo, Clk>
+    TwoFactorUseCaseImpl<Tx, UserRepo, TwoFactorRepo, LockEventRepo, Clk>
+{
+    pub fn new(
+        user_repository: UserRepo,
+        two_factor_repository: TwoFactorRepo,
+        account_lock_event_repository: LockEventRepo,
+        clock: Clk,
+        settings: TwoFactorSettings,
+    ) -> Self {
+        Self {
+            _marker: std::marker::PhantomData,
+            user_repository,
+            two_factor_repository,
+            account_lock_event_repository,
+            clock,
+            settings,
+        }
+    }
+}
+
+impl<Tx, UserRepo, TwoFactorRepo, LockEventRepo, Clk>
+    TwoFactorUseCaseImpl<Tx, UserRepo, TwoFactorRepo, LockEventRepo, Clk>
+where
+    Tx: TransactionManager,
+    UserRepo: UserRepository<Tx>,
+    LockEventRepo: AccountLockEventRepository<Tx>,
+{
+    async fn find_user(
+        &self,
+        tx: &mut Tx::Transaction<'_>,
+        user_id: &UserId,
+    ) -> Result<User, TwoFactorUsecaseError> {
+        self.user_repository
+            .find_by_user_id(tx, user_id, UserStatusFilter::Available)
+            .await?
+            .ok_or(TwoFactorUsecaseError::UserNotFound)
+    }
+
+    async fn record_failure(
+        &self,
+        tx: &mut Tx::Transaction<'_>,
+        mut user: User,
+        now: DateTime<Utc>,
+    ) -> Result<(), TwoFactorUsecaseError> {
+        if let Some(event) = user.record_login_failure(now, &self.settings.login_throttle) {
+            tracing::warn!(
+                user_id = %user.id,
+                locked_until = ?event.locked_until,
+                "account locked after repeated two-factor failures"
+            );
+            self.account_lock_event_repository.save(tx, event).await?;
+        }
+        self.user_repository.save(tx, user).await?;
+        Ok(())
+    }
+}
+
+// NOTE: ログインの2段階目と二要素認証の無効化で共通の、二要素目の確認
+//       受け付けたコードは再利用できないよう記録する
+pub(crate) async fn verify_two_factor_code<Tx, TwoFactorRepo>(
+    two_factor_repository: &TwoFactorRepo,
+    tx: &mut Tx::Transaction<'_>,
+    user_id: &UserId,
+    totp: &Totp,
+    code: TwoFactorCode,
+    now: DateTime<Utc>,
+) -> Result<bool, TwoFactorRepositoryError>
+where
+    Tx: TransactionManager,
+    TwoFactorRepo: TwoFactorRepository<Tx>,
+{
+    match code {
+        TwoFactorCode::Totp(code) => {
+            let Some(mut credential) = two_factor_repository
+                .find_totp_credential(tx, user_id)
+                .await?
+                .filter(TotpCredential::is_confirmed)
+            else {
+                return Ok(false);
+            };
+            if !credential.verify(totp, &code, now) {
+                return Ok(false);
+            }
+            two_factor_repository
+                .save_totp_credential(tx, credential)
+                .await?;
+            Ok(true)
+        }
+        TwoFactorCode::RecoveryCode(code) => {
+            let Some(code) = RecoveryCode::new(&code).ok() else {
+                return Ok(false);
+            };
+            let Some(mut entry) = two_factor_repository
+                .find_recovery_code(tx, user_id, &code.hash())
+                .await?
+            else {
+                return Ok(false);
+            };
+            if !entry.consume(now) {
+                return Ok(false);
+            }
+            two_factor_repository.save_recovery_code(tx, entry).await?;
+            Ok(true)
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TwoFactorUsecaseError {
+    #[error("ユーザーが存在しません。")]
+    UserNotFound,
+    #[error("二要素認証は有効化済みです。")]
+    AlreadyEnabled,
+    #[error("二要素認証は有効化されていません。")]
+    NotEnabled,
+    #[error("二要素認証の登録が開始されていません。")]
+    EnrollmentNotStarted,
+    #[error(transparent)]
+    TotpCredentialError(#[from] TotpCredentialError),
+    #[error(transparent)]
+    UserRepositoryError(#[from] UserRepositoryError),
+    #[error(transparent)]
+    TwoFactorRepositoryError(#[from] TwoFactorRepositoryError),
+    #[error(transparent)]
+    AccountLockEventRepositoryError(#[from] AccountLockEventRepositoryError),
+}
+
+impl UsecaseErrorKind for TwoFactorUsecaseError {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::UserNotFound => "UserNotFound",
+            Self::AlreadyEnabled => "AlreadyEnabled",
+            Self::NotEnabled => "NotEnabled",
+            Self::EnrollmentNotStarted => "EnrollmentNotStarted",
+            Self::TotpCredentialError(_) => "TotpCredentialError",
+            Self::UserRepositoryError(_) => "UserRepositoryError",
+            Self::TwoFactorRepositoryError(_) => "TwoFactorRepositoryError",
+            Self::AccountLockEventRepositoryError(_) => "AccountLockEventRepositoryError",
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use sqlx_macros::usecase;
+
+use crate::{
+    domain::{Clock, UserId},
+    repository::{
+        AccountLockEventRepository, TransactionManager, TwoFactorRepository, UserRepository,
+    },
+};
+
+use super::{
+    verify_two_factor_code, TwoFactorCode, TwoFactorRejection, TwoFactorUseCaseImpl,
+    TwoFactorUsecaseError,
+};
+
+#[usecase]
+impl<Tx, UserRepo, TwoFactorRepo, LockEventRepo, Clk> TwoFactorDisableUsecase<Tx>
+    for TwoFactorUseCaseImpl<Tx, UserRepo, TwoFactorRepo, LockEventRepo, Clk>
+where
+    Tx: TransactionManager,
+    UserRepo: UserRepository<Tx>,
+    TwoFactorRepo: TwoFactorRepository<Tx>,
+    LockEventRepo: AccountLockEventRepository<Tx>,
+    Clk: Clock,
+{
+    // NOTE: アクセストークンの漏洩だけで無効化されないよう、二要素目の確認を要求する
+    #[tracing::instrument(name = "TwoFactorDisableUsecase::disable", skip(self, tx, code), fields(user_id = %user_id), err)]
+    async fn disable(
+        &self,
+        tx: &mut Tx::Transaction<'_>,
+        user_id: UserId,
+        code: TwoFactorCode,
+    ) -> Result<Result<(), TwoFactorRejection>, TwoFactorUsecaseError> {
+        let mut user = self.find_user(tx, &user_id).await?;
+        if !user.two_factor_enabled {
+            return Err(TwoFactorUsecaseError::NotEnabled);
+        }
+        let now = self.clock.now();
+        if let Some(retry_after) = user.lock_remaining(now) {
+            return Ok(Err(TwoFactorRejection::TooManyAttempts { retry_after }));
+        }
+
+        let verified = verify_two_factor_code(
+            &self.two_factor_repository,
+            tx,
+            &user.id,
+            &self.settings.totp,
+            code,
+            now,
+        )
+        .await?;
+        if !verified {
+            self.record_failure(tx, user, now).await?;
+            return Ok(Err(TwoFactorRejection::InvalidCode));
+        }
+
+        user.disable_two_factor();
+        self.two_factor_repository
+            .delete_for_user(tx, &user.id)
+            .await?;
+        self.user_repository.save(tx, user).await?;
+        Ok(Ok(()))
+    }
+}
@@ -0,0 +1,21 @@
+pub struct TotpEnrollmentDto {
+    // NOTE: Base32形式。QRコードを読み取れない場合に手入力する
+    pub secret: String,
+    pub otpauth_uri: String,
+}
+
+// NOTE: 二要素目として受け付けるコード
+#[derive(Debug, Clone)]
+pub enum TwoFactorCode {
+    Totp(String),
+    RecoveryCode(String),
+}
+
+// NOTE: 失敗回数・ロックの記録をコミットするため、エラーにはしない
+#[derive(Debug, thiserror::Error)]
+pub enum TwoFactorRejection {
+    #[error("確認コードが正しくありません。")]
+    InvalidCode,
+    #[error("試行回数が多すぎます。しばらく時間をおいてから再度お試しください。")]
+    TooManyAttempts { retry_after: chrono::Duration },
+}
@@ -0,0 +1,79 @@
+use sqlx_macros::usecase;
+
+use crate::{
+    domain::{Clock, RecoveryCodeEntry, TotpCredential, UserId},
+    repository::{
+        AccountLockEventRepository, TransactionManager, TwoFactorRepository, UserRepository,
+    },
+};
+
+use super::{TotpEnrollmentDto, TwoFactorUseCaseImpl, TwoFactorUsecaseError};
+
+#[usecase]
+impl<Tx, UserRepo, TwoFactorRepo, LockEventRepo, Clk> TwoFactorEnrollUsecase<Tx>
+    for TwoFactorUseCaseImpl<Tx, UserRepo, TwoFactorRepo, LockEventRepo, Clk>
+where
+    Tx: TransactionManager,
+    UserRepo: UserRepository<Tx>,
+    TwoFactorRepo: TwoFactorRepository<Tx>,
+    LockEventRepo: AccountLockEventRepository<Tx>,
+    Clk: Clock,
+{
+    // NOTE: 共有鍵を発行する。認証アプリで確認(confirm_enrollment)するまでは有効にならない
+    #[tracing::instrument(name = "TwoFactorEnrollUsecase::begin_enrollment", skip(self, tx), fields(user_id = %user_id), err)]
+    async fn begin_enrollment(
+        &self,
+        tx: &mut Tx::Transaction<'_>,
+        user_id: UserId,
+    ) -> Result<TotpEnrollmentDto, TwoFactorUsecaseError> {
+        let user = self.find_user(tx, &user_id).await?;
+        if user.two_factor_enabled {
+            return Err(TwoFactorUsecaseError::AlreadyEnabled);
+        }
+
+        // NOTE: 登録途中の共有鍵がある場合は置き換える
+        let credential = TotpCredential::new(user.id, self.clock.now());
+        let enrollment = TotpEnrollmentDto {
+            secret: credential.secret.to_base32(),
+            otpauth_uri: self.settings.totp.otpauth_uri(
+                &credential.secret,
+                &self.settings.issuer,
+                user.mail_address.get(),
+            ),
+        };
+        self.two_factor_repository
+            .save_totp_credential(tx, credential)
+            .await?;
+        Ok(enrollment)
+    }
+
+    // NOTE: 認証アプリのコードを確認して有効化し、リカバリーコードを返す
+    #[tracing::instrument(name = "TwoFactorEnrollUsecase::confirm_enrollment", skip(self, tx, code), fields(user_id = %user_id), err)]
+    async fn confirm_enrollment(
+        &self,
+        tx: &mut Tx::Transaction<'_>,
+        user_id: UserId,
+        code: String,
+    ) -> Result<Vec<String>, TwoFactorUsecaseError> {
+        let mut user = self.find_user(tx, &user_id).await?;
+        let mut credential = self
+            .two_factor_repository
+            .find_totp_credential(tx, &user.id)
+            .await?
+            .ok_or(TwoFactorUsecaseError::EnrollmentNotStarted)?;
+        let now = self.clock.now();
+        credential.confirm(&self.settings.totp, &code, now)?;
+        user.enable_two_factor();
+
+        let (entries, codes) =
+            RecoveryCodeEntry::issue(user.id, self.settings.recovery_code_count, now);
+        self.two_factor_repository
+            .save_totp_credential(tx, credential)
+            .await?;
+        self.two_factor_repository
+            .replace_recovery_codes(tx, &user.id, entries)
+            .await?;
+        self.user_repository.save(tx, user).await?;
+        Ok(codes.iter().map(|code| code.get().to_string()).collect())
+    }
+}
@@ -2,39 +2,260 @@ mod user_delete_usecase;
 mod user_dto;
 mod user_get_usecase;
 mod user_register_usecase;
+mod user_revert_mail_address_usecase;
+mod user_suspend_usecase;
 mod user_update_usecase;
+mod user_verify_usecase;
 
 pub use user_delete_usecase::*;
 pub use user_dto::UserDto;
 pub use user_get_usecase::*;
 pub use user_register_usecase::*;
+pub use user_revert_mail_address_usecase::*;
+pub use user_suspend_usecase::*;
 pub use user_update_usecase::*;
+pub use user_verify_usecase::*;
+
+use chrono::{DateTime, Utc};
 
 use crate::{
     domain::{
-        MailAddressError, UserFactoryError, UserId, UserIdError, UserName, UserNameError,
-        UserService, UserServiceError,
+        Actor, ActorId, ApiKey, MailAddress, MailAddressError, MailVerification,
+        MailVerificationPurpose, PasswordError, PasswordHasherError, SingleUseTokenError, User,
+        UserError, UserFactoryError, UserId, UserIdError, UserName, UserNameError, UserNamePolicy,
+        UserNamePolicyError, UserPolicyError, UserService, UserServiceError, ValidationErrors,
+    },
+    mailer::Mail,
+    repository::{
+        ApiKeyRepository, ApiKeyRepositoryError, MailOutboxRepository, MailOutboxRepositoryError,
+        MailVerificationRepository, MailVerificationRepositoryError, TransactionManager,
+        UserRepository, UserRepositoryError, UserStatusFilter,
     },
-    repository::UserRepositoryError,
 };
 
-pub struct UserUseCaseImpl<Tx, Factory, Repo> {
+use super::UsecaseErrorKind;
+
+pub struct UserUseCaseImpl<Tx, Factory, Repo, Hasher, VerificationRepo, Outbox, ApiKeyRepo, Clk> {
     user_factory: Factory,
     user_repository: Repo,
     user_service: UserService<Tx, Repo>,
+    user_name_policy: UserNamePolicy,
+    password_hasher: Hasher,
+    mail_verification_repository: VerificationRepo,
+    mail_outbox_repository: Outbox,
+    api_key_repository: ApiKeyRepo,
+    clock: Clk,
+    mail_verification: MailVerificationSettings,
 }
 
-impl<Tx, Factory, Repo> UserUseCaseImpl<Tx, Factory, Repo> {
+#[derive(Debug, Clone)]
+pub struct MailVerificationSettings {
+    pub ttl: chrono::Duration,
+    // NOTE: {token}が確認トークンに置き換えられる
+    pub url_template: String,
+    pub revert_ttl: chrono::Duration,
+    // NOTE: {token}が取り消しトークンに置き換えられる
+    pub revert_url_template: String,
+}
+
+impl<Tx, Factory, Repo, Hasher, VerificationRepo, Outbox, ApiKeyRepo, Clk>
+    UserUseCaseImpl<Tx, Factory, Repo, Hasher, VerificationRepo, Outbox, ApiKeyRepo, Clk>
+{
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         user_factory: Factory,
         user_repository: Repo,
         user_service: UserService<Tx, Repo>,
+        user_name_policy: UserNamePolicy,
+        password_hasher: Hasher,
+        mail_verification_repository: VerificationRepo,
+        mail_outbox_repository: Outbox,
+        api_key_repository: ApiKeyRepo,
+        clock: Clk,
+        mail_verification: MailVerificationSettings,
     ) -> Self {
         Self {
             user_factory,
             user_repository,
             user_service,
+            user_name_policy,
+            password_hasher,
+            mail_verification_repository,
+            mail_outbox_repository,
+            api_key_repository,
+            clock,
+            mail_verification,
+        }
+    }
+}
+
+impl<Tx, Factory, Repo, Hasher, VerificationRepo, Outbox, ApiKeyRepo, Clk>
+    UserUseCaseImpl<Tx, Factory, Repo, Hasher, VerificationRepo, Outbox, ApiKeyRepo, Clk>
+where
+    Tx: TransactionManager,
+    Repo: UserRepository<Tx>,
+    ApiKeyRepo: ApiKeyRepository<Tx>,
+{
+    // NOTE: ロール・スコープの変更を即座に反映するため、トークンではなくDBから操作者を取得する
+    async fn find_actor(
+        &self,
+        tx: &mut Tx::Transaction<'_>,
+        actor_id: &ActorId,
+    ) -> Result<Actor, UserUsecaseError> {
+        match actor_id {
+            ActorId::User(user_id) => self
+                .user_repository
+                .find_by_user_id(tx, user_id, UserStatusFilter::Available)
+                .await?
+                .map(|user| Actor::from(&user)),
+            ActorId::ApiKey(api_key_id) => self
+                .api_key_repository
+                .find_by_id(tx, api_key_id)
+                .await?
+                .filter(ApiKey::is_active)
+                .map(|api_key| Actor::from(&api_key)),
         }
+        .ok_or(UserUsecaseError::Forbidden(UserPolicyError::ActorNotFound))
+    }
+}
+
+impl<Tx, Factory, Repo, Hasher, VerificationRepo, Outbox, ApiKeyRepo, Clk>
+    UserUseCaseImpl<Tx, Factory, Repo, Hasher, VerificationRepo, Outbox, ApiKeyRepo, Clk>
+where
+    Tx: TransactionManager,
+    Repo: UserRepository<Tx>,
+{
+    // NOTE: 既存のユーザー名との衝突はDBを参照するため、他のフィールドの検証を通過した後に確認する
+    async fn ensure_user_name_available(
+        &self,
+        tx: &mut Tx::Transaction<'_>,
+        name: &UserName,
+        except: Option<&UserId>,
+    ) -> Result<(), UserUsecaseError> {
+        if self.user_service.user_name_taken(tx, name, except).await? {
+            return Err(confusable_user_name());
+        }
+        Ok(())
+    }
+
+    // NOTE: アドレスの変更時と確認時の両方で、他のユーザーが使用していないことを確認する
+    //       登録時の重複と同じエラーを返す
+    async fn ensure_mail_address_available(
+        &self,
+        tx: &mut Tx::Transaction<'_>,
+        mail_address: &MailAddress,
+        user: &User,
+    ) -> Result<(), UserUsecaseError> {
+        if self
+            .user_service
+            .mail_address_taken(tx, mail_address, Some(&user.id))
+            .await?
+        {
+            return Err(UserUsecaseError::UserAlreadyExistsError(user.name.clone()));
+        }
+        Ok(())
+    }
+
+    // NOTE: 同じアドレスで同時に登録・確認された場合は、保存時の一意制約で検出する
+    async fn save_user(
+        &self,
+        tx: &mut Tx::Transaction<'_>,
+        user: User,
+    ) -> Result<(), UserUsecaseError> {
+        let name = user.name.clone();
+        self.user_repository
+            .save(tx, user)
+            .await
+            .map_err(|e| match e {
+                UserRepositoryError::MailAddressConflict => {
+                    UserUsecaseError::UserAlreadyExistsError(name)
+                }
+                e => e.into(),
+            })
+    }
+
+    // NOTE: 確認トークンはユーザーを参照するため、ユーザーの保存後にsend_mail_verificationで送信する
+    //       変更中のアドレスがある場合はそちらを確認対象とする
+    fn prepare_mail_verification(
+        &self,
+        user: &User,
+        now: DateTime<Utc>,
+    ) -> (MailVerification, Mail) {
+        let mail_address = user
+            .pending_mail_address
+            .as_ref()
+            .unwrap_or(&user.mail_address);
+        let (verification, token) = MailVerification::issue(
+            user.id,
+            MailVerificationPurpose::Verify,
+            mail_address.clone(),
+            now,
+            self.mail_verification.ttl,
+        );
+        let url = self
+            .mail_verification
+            .url_template
+            .replace("{token}", token.get());
+        let mail = Mail {
+            to: mail_address.clone(),
+            subject: "メールアドレスの確認".to_string(),
+            body: format!(
+                "{}様\n\n以下のURLからメールアドレスの確認を完了してください。\n{}\n\nこのURLの有効期限は{}です。",
+                user.name,
+                url,
+                verification.token.expires_at.to_rfc3339(),
+            ),
+        };
+        (verification, mail)
+    }
+
+    // NOTE: 乗っ取り対策として、変更前のアドレスに変更の通知と取り消しリンクを送る
+    fn prepare_mail_revert(&self, user: &User, now: DateTime<Utc>) -> (MailVerification, Mail) {
+        let (verification, token) = MailVerification::issue(
+            user.id,
+            MailVerificationPurpose::Revert,
+            user.mail_address.clone(),
+            now,
+            self.mail_verification.revert_ttl,
+        );
+        let url = self
+            .mail_verification
+            .revert_url_template
+            .replace("{token}", token.get());
+        let mail = Mail {
+            to: user.mail_address.clone(),
+            subject: "メールアドレス変更のお知らせ".to_string(),
+            body: format!(
+                "{}様\n\nメールアドレスの変更が申請されました。\n心当たりがない場合は、以下のURLから変更を取り消してください。\n{}\n\nこのURLの有効期限は{}です。",
+                user.name,
+                url,
+                verification.token.expires_at.to_rfc3339(),
+            ),
+        };
+        (verification, mail)
+    }
+}
+
+impl<Tx, Factory, Repo, Hasher, VerificationRepo, Outbox, ApiKeyRepo, Clk>
+    UserUseCaseImpl<Tx, Factory, Repo, Hasher, VerificationRepo, Outbox, ApiKeyRepo, Clk>
+where
+    Tx: TransactionManager,
+    VerificationRepo: MailVerificationRepository<Tx>,
+    Outbox: MailOutboxRepository<Tx>,
+{
+    // NOTE: メールは同じトランザクションで送信待ちに登録し、コミット後にMailOutboxDispatcherが送信する
+    //       ロールバックされた場合は送信されず、送信の失敗でトランザクションが失敗することもない
+    async fn send_mail_verification(
+        &self,
+        tx: &mut Tx::Transaction<'_>,
+        (verification, mail): (MailVerification, Mail),
+    ) -> Result<(), UserUsecaseError> {
+        let now = verification.token.created_at;
+        self.mail_verification_repository
+            .save(tx, verification)
+            .await?;
+        self.mail_outbox_repository.enqueue(tx, mail, now).await?;
+        Ok(())
     }
 }
 
@@ -49,7 +270,13 @@ pub enum UserUsecaseError {
     #[error(transparent)]
     MailAddressError(#[from] MailAddressError),
     #[error(transparent)]
-    UserRepositoryError(#[from] UserRepositoryError),
+    PasswordError(#[from] PasswordError),
+    #[error(transparent)]
+    ValidationErrors(#[from] ValidationErrors),
+    #[error(transparent)]
+    PasswordHasherError(#[from] PasswordHasherError),
+    #[error(transparent)]
+    UserRepositoryError(UserRepositoryError),
     #[error(transparent)]
     UserServiceError(#[from] UserServiceError),
     #[error(transparent)]
@@ -58,4 +285,63 @@ pub enum UserUsecaseError {
     UserAlreadyExistsError(UserName),
     #[error("{0}は不適切なuser_idです")]
     UserIdNotExistsError(UserId),
+    #[error(transparent)]
+    Forbidden(#[from] UserPolicyError),
+    #[error(transparent)]
+    UserError(#[from] UserError),
+    #[error(transparent)]
+    MailVerificationRepositoryError(#[from] MailVerificationRepositoryError),
+    #[error(transparent)]
+    MailOutboxRepositoryError(#[from] MailOutboxRepositoryError),
+    #[error(transparent)]
+    ApiKeyRepositoryError(#[from] ApiKeyRepositoryError),
+    // NOTE: 存在しない・使用済み・期限切れのいずれも同じエラーとする
+    #[error("確認トークンが無効です。")]
+    InvalidVerificationToken,
+}
+
+// NOTE: 同時に登録された紛らわしいユーザー名は、事前の確認ではなく保存時の一意制約で検出される
+impl From<UserRepositoryError> for UserUsecaseError {
+    fn from(value: UserRepositoryError) -> Self {
+        match value {
+            UserRepositoryError::UserNameSkeletonConflict => confusable_user_name(),
+            value => Self::UserRepositoryError(value),
+        }
+    }
+}
+
+fn confusable_user_name() -> UserUsecaseError {
+    let mut errors = ValidationErrors::default();
+    errors.push("name", &UserNamePolicyError::Confusable);
+    errors.into()
+}
+
+impl From<SingleUseTokenError> for UserUsecaseError {
+    fn from(_: SingleUseTokenError) -> Self {
+        Self::InvalidVerificationToken
+    }
+}
+
+impl UsecaseErrorKind for UserUsecaseError {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::UserIdError(_) => "UserIdError",
+            Self::UserNameError(_) => "UserNameError",
+            Self::MailAddressError(_) => "MailAddressError",
+            Self::PasswordError(_) => "PasswordError",
+            Self::ValidationErrors(_) => "ValidationErrors",
+            Self::PasswordHasherError(_) => "PasswordHasherError",
+            Self::UserRepositoryError(_) => "UserRepositoryError",
+            Self::UserServiceError(_) => "UserServiceError",
+            Self::UserFactoryError(_) => "UserFactoryError",
+            Self::UserAlreadyExistsError(_) => "UserAlreadyExistsError",
+            Self::UserIdNotExistsError(_) => "UserIdNotExistsError",
+            Self::Forbidden(_) => "Forbidden",
+            Self::UserError(_) => "UserError",
+            Self::MailVerificationRepositoryError(_) => "MailVerificationRepositoryError",
+            Self::MailOutboxRepositoryError(_) => "MailOutboxRepositoryError",
+            Self::ApiKeyRepositoryError(_) => "ApiKeyRepositoryError",
+            Self::InvalidVerificationToken => "InvalidVerificationToken",
+        }
+    }
 }
@@ -1,46 +1,45 @@
-use async_trait::async_trait;
+use sqlx_macros::usecase;
 use uuid::Uuid;
 
 use crate::{
-    domain::{UserFactory, UserId},
-    repository::{TransactionManager, UserRepository},
+    domain::{ActorId, Clock, UserAction, UserError, UserFactory, UserId, UserPolicy},
+    repository::{ApiKeyRepository, TransactionManager, UserRepository, UserStatusFilter},
 };
 
 use super::{UserUseCaseImpl, UserUsecaseError};
 
-#[async_trait]
-pub trait UserDeleteUsecase<Tx>
+#[usecase]
+impl<Tx, Factory, Repo, Hasher, VerificationRepo, Outbox, ApiKeyRepo, Clk> UserDeleteUsecase<Tx>
+    for UserUseCaseImpl<Tx, Factory, Repo, Hasher, VerificationRepo, Outbox, ApiKeyRepo, Clk>
 where
     Tx: TransactionManager,
+    Repo: UserRepository<Tx>,
+    ApiKeyRepo: ApiKeyRepository<Tx>,
+    Factory: UserFactory<Tx>,
+    Clk: Clock,
 {
+    #[tracing::instrument(name = "UserDeleteUsecase::delete", skip(self, tx, actor_id), fields(actor_id = %actor_id), err)]
     async fn delete(
         &self,
         tx: &mut Tx::Transaction<'_>,
-        user_id: Uuid,
-    ) -> Result<(), UserUsecaseError>;
-}
-
-#[async_trait]
-impl<Tx, Factory, Repo> UserDeleteUsecase<Tx> for UserUseCaseImpl<Tx, Factory, Repo>
-where
-    Tx: TransactionManager + std::marker::Sync + std::marker::Send,
-    Repo: UserRepository<Tx> + std::marker::Sync,
-    Factory: UserFactory + std::marker::Sync,
-{
-    async fn delete(
-        &self,
-        tx: &mut Tx::Transaction<'_>,
+        actor_id: ActorId,
         user_id: Uuid,
     ) -> Result<(), UserUsecaseError> {
         let target_id = UserId::new(user_id)?;
+        let actor = self.find_actor(tx, &actor_id).await?;
+        UserPolicy::authorize(&actor, UserAction::Delete, &target_id)?;
         // NOTE: Userが見つからなかった場合も退会成功とする場合もある
-        let target_user = self
+        let mut target_user = self
             .user_repository
-            .find_by_user_id(tx, &target_id)
+            .find_by_user_id(tx, &target_id, UserStatusFilter::NotDeleted)
             .await?
             .ok_or_else(|| UserUsecaseError::UserIdNotExistsError(target_id))?;
 
-        Ok(self.user_repository.delete(tx, target_user).await?)
+        // NOTE: 行は削除せず、退会済みの状態として残す
+        target_user
+            .delete(self.clock.now())
+            .map_err(UserError::from)?;
+        Ok(self.user_repository.save(tx, target_user).await?)
     }
 }
 
@@ -1,42 +1,36 @@
-use async_trait::async_trait;
+use sqlx_macros::usecase;
 use uuid::Uuid;
 
 use crate::{
-    domain::{UserFactory, UserId},
-    repository::{TransactionManager, UserRepository},
+    domain::{ActorId, UserAction, UserFactory, UserId, UserPolicy},
+    repository::{ApiKeyRepository, TransactionManager, UserRepository, UserStatusFilter},
 };
 
 use super::{UserDto, UserUseCaseImpl, UserUsecaseError};
 
-#[async_trait]
-pub trait UserGetUsecase<Tx>
+#[usecase]
+impl<Tx, Factory, Repo, Hasher, VerificationRepo, Outbox, ApiKeyRepo, Clk> UserGetUsecase<Tx>
+    for UserUseCaseImpl<Tx, Factory, Repo, Hasher, VerificationRepo, Outbox, ApiKeyRepo, Clk>
 where
     Tx: TransactionManager,
-{
-    async fn get(
-        &self,
-        tx: &mut Tx::Transaction<'_>,
-        user_id: &Uuid,
-    ) -> Result<Option<UserDto>, UserUsecaseError>;
-}
-
-#[async_trait]
-impl<Tx, Factory, Repo> UserGetUsecase<Tx> for UserUseCaseImpl<Tx, Factory, Repo>
-where
-    Tx: TransactionManager + std::marker::Sync + std::marker::Send,
-    Repo: UserRepository<Tx> + std::marker::Sync,
-    Factory: UserFactory + std::marker::Sync,
+    Repo: UserRepository<Tx>,
+    ApiKeyRepo: ApiKeyRepository<Tx>,
+    Factory: UserFactory<Tx>,
 {
     // NOTE: DTOを用いることで、ドメインの流出を防ぐことができる
+    #[tracing::instrument(name = "UserGetUsecase::get", skip(self, tx, actor_id), fields(actor_id = %actor_id), err)]
     async fn get(
         &self,
         tx: &mut Tx::Transaction<'_>,
+        actor_id: ActorId,
         user_id: &Uuid,
     ) -> Result<Option<UserDto>, UserUsecaseError> {
         let target_id = UserId::new(*user_id)?;
+        let actor = self.find_actor(tx, &actor_id).await?;
+        UserPolicy::authorize(&actor, UserAction::Read, &target_id)?;
         Ok(self
             .user_repository
-            .find_by_user_id(tx, &target_id)
+            .find_by_user_id(tx, &target_id, UserStatusFilter::Available)
             .await?
             .map(|user| UserDto {
                 user_id: user.id.get(),
@@ -1,54 +1,60 @@
-use async_trait::async_trait;
+use sqlx_macros::usecase;
 
 use crate::{
-    domain::{MailAddress, UserFactory, UserName},
-    repository::{TransactionManager, UserRepository},
+    domain::{Clock, MailAddress, Password, PasswordHasher, UserFactory, UserName, Validator},
+    repository::{
+        MailOutboxRepository, MailVerificationRepository, TransactionManager, UserRepository,
+    },
 };
 
 use super::{UserUseCaseImpl, UserUsecaseError};
 
 // NOTE: traitとしてインターフェース化することで分業が可能
 //       また、テストも可能になる。
-#[async_trait]
-pub trait UserRegisterUsecase<Tx>
+#[usecase]
+impl<Tx, Factory, Repo, Hasher, VerificationRepo, Outbox, ApiKeyRepo, Clk> UserRegisterUsecase<Tx>
+    for UserUseCaseImpl<Tx, Factory, Repo, Hasher, VerificationRepo, Outbox, ApiKeyRepo, Clk>
 where
     Tx: TransactionManager,
+    Repo: UserRepository<Tx>,
+    Factory: UserFactory<Tx>,
+    Hasher: PasswordHasher,
+    VerificationRepo: MailVerificationRepository<Tx>,
+    Outbox: MailOutboxRepository<Tx>,
+    Clk: Clock,
 {
+    #[tracing::instrument(name = "UserRegisterUsecase::register", skip_all, err)]
     async fn register(
         &self,
         tx: &mut Tx::Transaction<'_>,
-        name: String,
-        raw_mail_address: String,
-    ) -> Result<(), UserUsecaseError>;
-}
-
-#[async_trait]
-impl<Tx, Factory, Repo> UserRegisterUsecase<Tx> for UserUseCaseImpl<Tx, Factory, Repo>
-where
-    Tx: TransactionManager + std::marker::Sync + std::marker::Send,
-    Repo: UserRepository<Tx> + std::marker::Sync,
-    Factory: UserFactory + std::marker::Sync,
-{
-    async fn register(
-        &self,
-        tx: &mut Tx::Transaction<'_>,
-        name: String,
-        raw_mail_address: String,
+        name: UserName,
+        mail_address: MailAddress,
+        password: Password,
     ) -> Result<(), UserUsecaseError> {
         // NOTE: トランザクションにより整合性が担保される
         //       →transactionを管理するものを作ってトランザクションを受け取る。
         // connection.begin_transaction();
         // MEMO: txを使用して解決
+        // NOTE: 値の形式はリクエストの受け取り時に検証済み。ユーザー名のポリシーは設定に依存するためここで確認する
+        let mut validator = Validator::new();
+        let name = validator.check("name", self.user_name_policy.check(name));
+        let (name,) = validator.finish((name,))?;
+        self.ensure_user_name_available(tx, &name, None).await?;
+        let password_hash = self.password_hasher.hash(&password).await?;
         let user = self
             .user_factory
-            .create(UserName::new(name)?, MailAddress::new(raw_mail_address)?)?;
+            .create(tx, name, mail_address, password_hash)
+            .await?;
 
         // NOTE: domain_serviceで確認を行うことで変更に強い
         if self.user_service.exists(tx, &user).await? {
             return Err(UserUsecaseError::UserAlreadyExistsError(user.name));
         }
 
-        self.user_repository.save(tx, user).await?;
+        // NOTE: ユーザーはメールアドレス未確認の状態で作成され、確認メールを送信する
+        let verification = self.prepare_mail_verification(&user, self.clock.now());
+        self.save_user(tx, user).await?;
+        self.send_mail_verification(tx, verification).await?;
 
         Ok(())
         // NOTE: トランザクションに問題がなければ永続化
@@ -61,7 +67,7 @@ where
 // pub struct MockUserRegisterUsecase<Repo, Factory>
 // where
 //     Repo: UserRepository,
-//     Factory: UserFactory,
+//     Factory: UserFactory<Tx>,
 // {
 //     user_factory: Factory,
 //     user_repository: Repo,
@@ -72,7 +78,7 @@ where
 // impl<Repo, Factory> MockUserRegisterUsecase<Repo, Factory>
 // where
 //     Repo: UserRepository,
-//     Factory: UserFactory,
+//     Factory: UserFactory<Tx>,
 // {
 //     pub fn new(
 //         user_factory: Factory,
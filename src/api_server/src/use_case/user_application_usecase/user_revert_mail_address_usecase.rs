@@ -0,0 +1,57 @@
+use sqlx_macros::usecase;
+
+use crate::{
+    domain::{Clock, MailVerificationPurpose, SecretToken},
+    repository::{
+        MailVerificationRepository, TransactionManager, UserRepository, UserStatusFilter,
+    },
+};
+
+use super::{UserUseCaseImpl, UserUsecaseError};
+
+#[usecase]
+impl<Tx, Factory, Repo, Hasher, VerificationRepo, Outbox, ApiKeyRepo, Clk>
+    UserRevertMailAddressUsecase<Tx>
+    for UserUseCaseImpl<Tx, Factory, Repo, Hasher, VerificationRepo, Outbox, ApiKeyRepo, Clk>
+where
+    Tx: TransactionManager,
+    Repo: UserRepository<Tx>,
+    VerificationRepo: MailVerificationRepository<Tx>,
+    Clk: Clock,
+{
+    #[tracing::instrument(
+        name = "UserRevertMailAddressUsecase::revert_mail_address",
+        skip_all,
+        err
+    )]
+    async fn revert_mail_address(
+        &self,
+        tx: &mut Tx::Transaction<'_>,
+        raw_token: String,
+    ) -> Result<(), UserUsecaseError> {
+        let token =
+            SecretToken::new(raw_token).map_err(|_| UserUsecaseError::InvalidVerificationToken)?;
+        let mut verification = self
+            .mail_verification_repository
+            .find_by_token_hash(tx, &token.hash())
+            .await?
+            .filter(|verification| verification.purpose == MailVerificationPurpose::Revert)
+            .ok_or(UserUsecaseError::InvalidVerificationToken)?;
+        let mut user = self
+            .user_repository
+            .find_by_user_id(tx, &verification.user_id, UserStatusFilter::NotDeleted)
+            .await?
+            .ok_or(UserUsecaseError::InvalidVerificationToken)?;
+
+        let now = self.clock.now();
+        verification.token.consume(now)?;
+        // NOTE: 変更が確定済みの場合も変更前のアドレスに戻す
+        user.revert_mail_address(verification.mail_address.clone(), now);
+
+        self.user_repository.save(tx, user).await?;
+        self.mail_verification_repository
+            .save(tx, verification)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+use chrono::{DateTime, Utc};
+use sqlx_macros::usecase;
+use uuid::Uuid;
+
+use crate::{
+    domain::{ActorId, Clock, UserAction, UserError, UserFactory, UserId, UserPolicy},
+    repository::{ApiKeyRepository, TransactionManager, UserRepository, UserStatusFilter},
+};
+
+use super::{UserUseCaseImpl, UserUsecaseError};
+
+#[usecase]
+impl<Tx, Factory, Repo, Hasher, VerificationRepo, Outbox, ApiKeyRepo, Clk> UserSuspendUsecase<Tx>
+    for UserUseCaseImpl<Tx, Factory, Repo, Hasher, VerificationRepo, Outbox, ApiKeyRepo, Clk>
+where
+    Tx: TransactionManager,
+    Repo: UserRepository<Tx>,
+    ApiKeyRepo: ApiKeyRepository<Tx>,
+    Factory: UserFactory<Tx>,
+    Clk: Clock,
+{
+    // NOTE: untilを省略した場合は無期限
+    #[tracing::instrument(name = "UserSuspendUsecase::suspend", skip(self, tx, actor_id, reason), fields(actor_id = %actor_id), err)]
+    async fn suspend(
+        &self,
+        tx: &mut Tx::Transaction<'_>,
+        actor_id: ActorId,
+        user_id: Uuid,
+        reason: String,
+        until: Option<DateTime<Utc>>,
+    ) -> Result<(), UserUsecaseError> {
+        let target_id = UserId::new(user_id)?;
+        let actor = self.find_actor(tx, &actor_id).await?;
+        UserPolicy::authorize(&actor, UserAction::Suspend, &target_id)?;
+        let mut target_user = self
+            .user_repository
+            .find_by_user_id(tx, &target_id, UserStatusFilter::NotDeleted)
+            .await?
+            .ok_or_else(|| UserUsecaseError::UserIdNotExistsError(target_id))?;
+
+        // NOTE: 発行済みのアクセストークンは期限まで有効だが、リフレッシュはできなくなる
+        target_user
+            .suspend(reason, until, self.clock.now())
+            .map_err(UserError::from)?;
+        tracing::info!(user_id = %target_user.id, ?until, "user suspended");
+        Ok(self.user_repository.save(tx, target_user).await?)
+    }
+
+    #[tracing::instrument(name = "UserSuspendUsecase::reinstate", skip(self, tx, actor_id), fields(actor_id = %actor_id), err)]
+    async fn reinstate(
+        &self,
+        tx: &mut Tx::Transaction<'_>,
+        actor_id: ActorId,
+        user_id: Uuid,
+    ) -> Result<(), UserUsecaseError> {
+        let target_id = UserId::new(user_id)?;
+        let actor = self.find_actor(tx, &actor_id).await?;
+        UserPolicy::authorize(&actor, UserAction::Suspend, &target_id)?;
+        let mut target_user = self
+            .user_repository
+            .find_by_user_id(tx, &target_id, UserStatusFilter::NotDeleted)
+            .await?
+            .ok_or_else(|| UserUsecaseError::UserIdNotExistsError(target_id))?;
+
+        target_user.reinstate().map_err(UserError::from)?;
+        Ok(self.user_repository.save(tx, target_user).await?)
+    }
+}
@@ -1,58 +1,84 @@
-use async_trait::async_trait;
+use sqlx_macros::usecase;
 use uuid::Uuid;
 
 use crate::{
-    domain::{MailAddress, UserId, UserName, UserUpdateCommand},
-    repository::{TransactionManager, UserRepository},
+    domain::{ActorId, Clock, UserAction, UserId, UserPolicy, UserUpdateCommand, Validator},
+    repository::{
+        ApiKeyRepository, MailOutboxRepository, MailVerificationRepository, TransactionManager,
+        UserRepository, UserStatusFilter,
+    },
 };
 
 use super::{UserUseCaseImpl, UserUsecaseError};
 
-#[async_trait(?Send)]
-pub trait UserUpdateUsecase<Tx>
+#[usecase]
+impl<Tx, Factory, Repo, Hasher, VerificationRepo, Outbox, ApiKeyRepo, Clk> UserUpdateUsecase<Tx>
+    for UserUseCaseImpl<Tx, Factory, Repo, Hasher, VerificationRepo, Outbox, ApiKeyRepo, Clk>
 where
     Tx: TransactionManager,
+    Repo: UserRepository<Tx>,
+    ApiKeyRepo: ApiKeyRepository<Tx>,
+    VerificationRepo: MailVerificationRepository<Tx>,
+    Outbox: MailOutboxRepository<Tx>,
+    Clk: Clock,
 {
+    #[tracing::instrument(
+        name = "UserUpdateUsecase::update",
+        skip(self, tx, actor_id, user_update_command),
+        fields(actor_id = %actor_id),
+        err
+    )]
     async fn update(
         &self,
         tx: &mut Tx::Transaction<'_>,
-        user_id: Uuid,
-        user_update_command: UserUpdateCommand,
-    ) -> Result<(), UserUsecaseError>;
-}
-
-#[async_trait(?Send)]
-impl<Tx, Factory, Repo> UserUpdateUsecase<Tx> for UserUseCaseImpl<Tx, Factory, Repo>
-where
-    Tx: TransactionManager,
-    Repo: UserRepository<Tx> + std::marker::Sync,
-{
-    async fn update(
-        &self,
-        tx: &mut Tx::Transaction<'_>,
+        actor_id: ActorId,
         user_id: Uuid,
         user_update_command: UserUpdateCommand,
     ) -> Result<(), UserUsecaseError> {
         let target_id = UserId::new(user_id)?;
+        let actor = self.find_actor(tx, &actor_id).await?;
+        // NOTE: 対象の存在有無やユーザー名の使用状況を漏らさないよう、検索・検証より先に認可する
+        UserPolicy::authorize(&actor, UserAction::Update, &target_id)?;
+        // NOTE: 値の形式はリクエストの受け取り時に検証済み。ユーザー名のポリシーは設定に依存するためここで確認する
+        let mut validator = Validator::new();
+        let name = validator.check_optional(
+            "name",
+            user_update_command
+                .name
+                .map(|name| self.user_name_policy.check(name)),
+        );
+        let (name,) = validator.finish((name,))?;
+        let mail_address = user_update_command.mail_address;
         let mut target_user = self
             .user_repository
-            .find_by_user_id(tx, &target_id)
+            .find_by_user_id(tx, &target_id, UserStatusFilter::NotDeleted)
             .await?
             .ok_or_else(|| UserUsecaseError::UserIdNotExistsError(target_id))?;
 
-        if let Some(new_user_name) = user_update_command.name {
-            // if self.user_service.exists(tx, &target_user).await? {
-            //     return Err(UserUsecaseError::UserAlreadyExistsError(target_user.name));
-            // }
-
-            target_user.change_name(UserName::new(new_user_name)?);
+        if let Some(new_user_name) = name {
+            // NOTE: 自分自身の名前の大文字・小文字の変更等は許可する
+            self.ensure_user_name_available(tx, &new_user_name, Some(&target_user.id))
+                .await?;
+            target_user.change_name(new_user_name);
         }
 
-        if let Some(new_mail_address) = user_update_command.mail_address {
-            target_user.change_mail_address(MailAddress::new(new_mail_address)?);
+        let mut verifications = Vec::new();
+        if let Some(new_mail_address) = mail_address {
+            self.ensure_mail_address_available(tx, &new_mail_address, &target_user)
+                .await?;
+            // NOTE: 新しいアドレスで確認されるまで変更は反映しない
+            if target_user.request_mail_address_change(new_mail_address) {
+                let now = self.clock.now();
+                verifications.push(self.prepare_mail_verification(&target_user, now));
+                verifications.push(self.prepare_mail_revert(&target_user, now));
+            }
         }
 
-        Ok(self.user_repository.save(tx, target_user).await?)
+        self.user_repository.save(tx, target_user).await?;
+        for verification in verifications {
+            self.send_mail_verification(tx, verification).await?;
+        }
+        Ok(())
     }
 }
 
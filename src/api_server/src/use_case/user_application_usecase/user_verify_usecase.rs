@@ -0,0 +1,285 @@
+use sqlx_macros::usecase;
+
+use crate::{
+    domain::{Clock, MailVerificationPurpose, SecretToken},
+    repository::{
+        MailVerificationRepository, TransactionManager, UserRepository, UserStatusFilter,
+    },
+};
+
+use super::{UserUseCaseImpl, UserUsecaseError};
+
+#[usecase]
+impl<Tx, Factory, Repo, Hasher, VerificationRepo, Outbox, ApiKeyRepo, Clk> UserVerifyUsecase<Tx>
+    for UserUseCaseImpl<Tx, Factory, Repo, Hasher, VerificationRepo, Outbox, ApiKeyRepo, Clk>
+where
+    Tx: TransactionManager,
+    Repo: UserRepository<Tx>,
+    VerificationRepo: MailVerificationRepository<Tx>,
+    Clk: Clock,
+{
+    #[tracing::instrument(name = "UserVerifyUsecase::verify", skip_all, err)]
+    async fn verify(
+        &self,
+        tx: &mut Tx::Transaction<'_>,
+        raw_token: String,
+    ) -> Result<(), UserUsecaseError> {
+        let token =
+            SecretToken::new(raw_token).map_err(|_| UserUsecaseError::InvalidVerificationToken)?;
+        let mut verification = self
+            .mail_verification_repository
+            .find_by_token_hash(tx, &token.hash())
+            .await?
+            .filter(|verification| verification.purpose == MailVerificationPurpose::Verify)
+            .ok_or(UserUsecaseError::InvalidVerificationToken)?;
+        let mut user = self
+            .user_repository
+            .find_by_user_id(tx, &verification.user_id, UserStatusFilter::NotDeleted)
+            .await?
+            .ok_or(UserUsecaseError::InvalidVerificationToken)?;
+
+        let now = self.clock.now();
+        verification.token.consume(now)?;
+        // NOTE: 変更の申請後に他のユーザーが同じアドレスを使用した場合は確定しない
+        self.ensure_mail_address_available(tx, &verification.mail_address, &user)
+            .await?;
+        user.verify_mail_address(&verification.mail_address, now)
+            .map_err(|_| UserUsecaseError::InvalidVerificationToken)?;
+
+        self.save_user(tx, user).await?;
+        self.mail_verification_repository
+            .save(tx, verification)
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use chrono::{Duration, Utc};
+    use rstest::rstest;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::{
+        domain::{
+            FixedClock, MailAddress, MailVerification, PasswordHash, User, UserId, UserName,
+            UserNamePolicy, UserService, UserStatus,
+        },
+        repository::{
+            MailVerificationRepositoryError, NoopTransactionManager, UserRepositoryError,
+        },
+        use_case::MailVerificationSettings,
+    };
+
+    type NoopTransaction<'a> = <NoopTransactionManager as TransactionManager>::Transaction<'a>;
+
+    fn mail(value: &str) -> MailAddress {
+        MailAddress::new(value.to_string()).unwrap()
+    }
+
+    fn user(name: &str, mail_address: &str) -> User {
+        let hash = PasswordHash::new(
+            "$argon2id$v=19$m=19456,t=2,p=1$c2FsdHNhbHRzYWx0$3nB0X2mK4d2n9JqJ0kq6mF2d3Qx3y8oVn8w0bq7fJ8Q".to_string(),
+        )
+        .unwrap();
+        let mut user = User::new(
+            UserId::new(Uuid::new_v4()).unwrap(),
+            UserName::new(name.to_string()).unwrap(),
+            mail(mail_address),
+            hash,
+        );
+        user.mail_verified_at = Some(Utc::now());
+        user.status = UserStatus::Active;
+        user
+    }
+
+    // NOTE: Userは複製できないため、保存時の値から読み込みのたびに組み立て直す
+    struct StoredUser {
+        id: UserId,
+        name: UserName,
+        mail_address: MailAddress,
+        pending_mail_address: Option<MailAddress>,
+    }
+
+    impl StoredUser {
+        fn restore(&self) -> User {
+            let mut restored = user(self.name.get(), self.mail_address.get());
+            restored.id = self.id;
+            restored.pending_mail_address = self.pending_mail_address.clone();
+            restored
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct InMemoryUserRepository {
+        users: Arc<Mutex<Vec<StoredUser>>>,
+    }
+
+    impl InMemoryUserRepository {
+        fn insert(&self, user: User) {
+            let mut users = self.users.lock().unwrap();
+            users.retain(|stored| stored.id != user.id);
+            users.push(StoredUser {
+                id: user.id,
+                name: user.name,
+                mail_address: user.mail_address,
+                pending_mail_address: user.pending_mail_address,
+            });
+        }
+
+        fn find(&self, predicate: impl Fn(&StoredUser) -> bool) -> Option<User> {
+            let users = self.users.lock().unwrap();
+            users
+                .iter()
+                .find(|stored| predicate(stored))
+                .map(StoredUser::restore)
+        }
+    }
+
+    #[async_trait]
+    impl UserRepository<NoopTransactionManager> for InMemoryUserRepository {
+        async fn find_by_user_id(
+            &self,
+            _tx: &mut NoopTransaction<'_>,
+            user_id: &UserId,
+            _filter: UserStatusFilter,
+        ) -> Result<Option<User>, UserRepositoryError> {
+            Ok(self.find(|stored| stored.id == *user_id))
+        }
+        async fn find_by_user_id_for_update(
+            &self,
+            tx: &mut NoopTransaction<'_>,
+            user_id: &UserId,
+            filter: UserStatusFilter,
+        ) -> Result<Option<User>, UserRepositoryError> {
+            self.find_by_user_id(tx, user_id, filter).await
+        }
+        async fn find_by_user_name(
+            &self,
+            _tx: &mut NoopTransaction<'_>,
+            user_name: &UserName,
+            _filter: UserStatusFilter,
+        ) -> Result<Option<User>, UserRepositoryError> {
+            Ok(self.find(|stored| stored.name == *user_name))
+        }
+        async fn find_by_mail_address(
+            &self,
+            _tx: &mut NoopTransaction<'_>,
+            mail_address: &MailAddress,
+            _filter: UserStatusFilter,
+        ) -> Result<Option<User>, UserRepositoryError> {
+            Ok(self.find(|stored| stored.mail_address == *mail_address))
+        }
+        async fn exists_by_user_name_skeleton(
+            &self,
+            _tx: &mut NoopTransaction<'_>,
+            _skeleton: &str,
+            _except: Option<&UserId>,
+        ) -> Result<bool, UserRepositoryError> {
+            unreachable!()
+        }
+        async fn save(
+            &self,
+            _tx: &mut NoopTransaction<'_>,
+            user: User,
+        ) -> Result<(), UserRepositoryError> {
+            self.insert(user);
+            Ok(())
+        }
+        async fn delete(
+            &self,
+            _tx: &mut NoopTransaction<'_>,
+            _user: User,
+        ) -> Result<(), UserRepositoryError> {
+            unreachable!()
+        }
+    }
+
+    #[derive(Default)]
+    struct InMemoryMailVerificationRepository {
+        verifications: Mutex<Vec<MailVerification>>,
+    }
+
+    #[async_trait]
+    impl MailVerificationRepository<NoopTransactionManager> for InMemoryMailVerificationRepository {
+        async fn find_by_token_hash(
+            &self,
+            _tx: &mut NoopTransaction<'_>,
+            _token_hash: &crate::domain::SecretTokenHash,
+        ) -> Result<Option<MailVerification>, MailVerificationRepositoryError> {
+            Ok(self.verifications.lock().unwrap().pop())
+        }
+        async fn save(
+            &self,
+            _tx: &mut NoopTransaction<'_>,
+            verification: MailVerification,
+        ) -> Result<(), MailVerificationRepositoryError> {
+            self.verifications.lock().unwrap().push(verification);
+            Ok(())
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn confirmation_is_rejected_when_address_was_taken_after_request() {
+        let now = Utc::now();
+        let user_repository = InMemoryUserRepository::default();
+        let verification_repository = InMemoryMailVerificationRepository::default();
+
+        let mut requester = user("hoge", "old@example.com");
+        requester.request_mail_address_change(mail("new@example.com"));
+        let (verification, token) = MailVerification::issue(
+            requester.id,
+            MailVerificationPurpose::Verify,
+            mail("new@example.com"),
+            now,
+            Duration::hours(1),
+        );
+        let requester_id = requester.id;
+        user_repository.insert(requester);
+        verification_repository
+            .verifications
+            .lock()
+            .unwrap()
+            .push(verification);
+        // NOTE: 変更の申請後に、他のユーザーが同じアドレスで登録した
+        user_repository.insert(user("fuga", "new@example.com"));
+
+        let usecase = UserUseCaseImpl::new(
+            (),
+            user_repository.clone(),
+            UserService::new(user_repository.clone()),
+            UserNamePolicy::default(),
+            (),
+            verification_repository,
+            (),
+            (),
+            FixedClock(now),
+            MailVerificationSettings {
+                ttl: Duration::hours(1),
+                url_template: "{token}".to_string(),
+                revert_ttl: Duration::hours(1),
+                revert_url_template: "{token}".to_string(),
+            },
+        );
+        let result = UserVerifyUsecase::<NoopTransactionManager>::verify(
+            &usecase,
+            &mut (),
+            token.get().to_string(),
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(UserUsecaseError::UserAlreadyExistsError(name)) if name.get() == "hoge"
+        ));
+        let requester = user_repository
+            .find(|stored| stored.id == requester_id)
+            .unwrap();
+        assert_eq!(requester.mail_address, mail("old@example.com"));
+    }
+}
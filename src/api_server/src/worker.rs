@@ -0,0 +1,169 @@
+use std::{future::Future, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use tokio::{sync::Mutex, task::JoinSet};
+use tokio_util::sync::CancellationToken;
+
+use crate::repository::{database_error::DatabaseError, TransactionManager};
+
+// NOTE: バックグラウンドで動くタスクを管理し、シャットダウン時にまとめて停止する
+//       各タスクはCancellationTokenがキャンセルされたら速やかに終了すること
+#[derive(Default)]
+pub struct BackgroundWorkers {
+    token: CancellationToken,
+    tasks: JoinSet<()>,
+}
+
+impl BackgroundWorkers {
+    pub fn spawn<F, Fut>(&mut self, name: &'static str, worker: F)
+    where
+        F: FnOnce(CancellationToken) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let task = worker(self.token.child_token());
+        self.tasks.spawn(async move {
+            task.await;
+            tracing::debug!(worker = name, "background worker stopped");
+        });
+    }
+
+    pub fn len(&self) -> usize {
+        self.tasks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+
+    // NOTE: タイムアウトまでに終了しなかったタスクは中断し、その数を返す
+    pub async fn shutdown(mut self, timeout: Duration) -> usize {
+        self.token.cancel();
+        let drained = tokio::time::timeout(timeout, async {
+            while self.tasks.join_next().await.is_some() {}
+        })
+        .await;
+        if drained.is_ok() {
+            return 0;
+        }
+        let remaining = self.tasks.len();
+        self.tasks.shutdown().await;
+        remaining
+    }
+}
+
+// NOTE: 1つのトランザクションで最大limit件を処理し、処理した件数を返すジョブ
+#[async_trait]
+pub trait BatchJob<TM>: Send + Sync
+where
+    TM: TransactionManager,
+{
+    type Error: std::error::Error + Send;
+
+    async fn run_batch(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        limit: u32,
+    ) -> Result<usize, Self::Error>;
+}
+
+#[async_trait]
+impl<TM, J> BatchJob<TM> for Arc<J>
+where
+    TM: TransactionManager,
+    J: BatchJob<TM> + ?Sized,
+{
+    type Error = J::Error;
+
+    async fn run_batch(
+        &self,
+        tx: &mut TM::Transaction<'_>,
+        limit: u32,
+    ) -> Result<usize, Self::Error> {
+        (**self).run_batch(tx, limit).await
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum BatchJobError<E> {
+    #[error(transparent)]
+    DatabaseError(DatabaseError),
+    #[error(transparent)]
+    JobError(E),
+}
+
+// NOTE: ジョブが失敗した場合はトランザクションをロールバックする
+async fn run_batch<TM, J>(
+    tm: &Mutex<TM>,
+    job: &J,
+    limit: u32,
+) -> Result<usize, BatchJobError<J::Error>>
+where
+    TM: TransactionManager + Send,
+    J: BatchJob<TM>,
+{
+    let mut tx = TM::begin(tm).await.map_err(BatchJobError::DatabaseError)?;
+    match job.run_batch(&mut tx, limit).await {
+        Ok(count) => {
+            TM::commit(tx).await.map_err(BatchJobError::DatabaseError)?;
+            Ok(count)
+        }
+        Err(e) => {
+            TM::rollback(tx)
+                .await
+                .map_err(BatchJobError::DatabaseError)?;
+            Err(BatchJobError::JobError(e))
+        }
+    }
+}
+
+// NOTE: interval毎にジョブを実行する。処理件数がbatch_sizeに達した場合は残りがあるとみなし、待たずに次を処理する
+pub async fn run_batch_job<TM, J>(
+    token: CancellationToken,
+    tm: Arc<Mutex<TM>>,
+    job: J,
+    interval: Duration,
+    batch_size: u32,
+) where
+    TM: TransactionManager + Send,
+    J: BatchJob<TM>,
+{
+    loop {
+        let full_batch = match run_batch(&tm, &job, batch_size).await {
+            Ok(count) => count >= batch_size as usize,
+            Err(e) => {
+                tracing::warn!(error = %e, "batch job failed");
+                false
+            }
+        };
+        if token.is_cancelled() {
+            break;
+        }
+        if full_batch {
+            continue;
+        }
+        tokio::select! {
+            _ = token.cancelled() => break,
+            _ = tokio::time::sleep(interval) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[tokio::test]
+    async fn shutdown_stops_workers() {
+        let mut workers = BackgroundWorkers::default();
+        workers.spawn(
+            "cooperative",
+            |token| async move { token.cancelled().await },
+        );
+        workers.spawn("stuck", |_| std::future::pending());
+        assert_eq!(workers.len(), 2);
+
+        assert_eq!(workers.shutdown(Duration::from_millis(50)).await, 1);
+    }
+}
@@ -0,0 +1,99 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{spanned::Spanned, Data, DeriveInput, Fields, Member, Meta, Type};
+
+// NOTE: DBの値として扱うフィールド
+//       タプル構造体・名前付き構造体のどちらも、フィールドが1つならそのフィールドを使う
+//       複数ある場合は#[sqlx_inner]を付けたフィールドを使い、残りはDefault::default()で初期化する
+pub struct InnerField<'a> {
+    pub member: Member,
+    pub ty: &'a Type,
+    others: Vec<Member>,
+}
+
+impl<'a> InnerField<'a> {
+    pub fn find(ast: &'a DeriveInput, derive: &str) -> syn::Result<Self> {
+        let data = match &ast.data {
+            Data::Struct(data) => data,
+            Data::Enum(data) => {
+                return Err(syn::Error::new(
+                    data.enum_token.span,
+                    format!("{derive} can only be derived for structs"),
+                ))
+            }
+            Data::Union(data) => {
+                return Err(syn::Error::new(
+                    data.union_token.span,
+                    format!("{derive} can only be derived for structs"),
+                ))
+            }
+        };
+        if matches!(data.fields, Fields::Unit) || data.fields.is_empty() {
+            return Err(syn::Error::new_spanned(
+                &ast.ident,
+                format!("{derive} requires a struct with a field that holds the value"),
+            ));
+        }
+
+        let members: Vec<Member> = data.fields.members().collect();
+        let mut marked: Option<usize> = None;
+        for (index, field) in data.fields.iter().enumerate() {
+            for attr in field
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("sqlx_inner"))
+            {
+                if !matches!(attr.meta, Meta::Path(_)) {
+                    return Err(syn::Error::new_spanned(
+                        &attr.meta,
+                        "#[sqlx_inner] does not take arguments",
+                    ));
+                }
+                if marked.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        attr,
+                        "only one field can be marked with #[sqlx_inner]",
+                    ));
+                }
+                marked = Some(index);
+            }
+        }
+        let index = match marked {
+            Some(index) => index,
+            None if members.len() == 1 => 0,
+            None => {
+                return Err(syn::Error::new(
+                    data.fields.span(),
+                    format!(
+                        "{derive} cannot infer the field that holds the value; mark it with #[sqlx_inner]"
+                    ),
+                ))
+            }
+        };
+
+        let field = data.fields.iter().nth(index).expect("index is in range");
+        let others = members
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != index)
+            .map(|(_, member)| member.clone())
+            .collect();
+        Ok(Self {
+            member: members[index].clone(),
+            ty: &field.ty,
+            others,
+        })
+    }
+
+    // NOTE: 内部の値から構造体を生成する式
+    pub fn construct(&self, value: TokenStream) -> TokenStream {
+        let member = &self.member;
+        let others = &self.others;
+        quote! {
+            Self {
+                #member: #value,
+                #(#others: ::core::default::Default::default(),)*
+            }
+        }
+    }
+}
@@ -1,75 +1,78 @@
 extern crate proc_macro;
 
+mod inner_field;
+mod persistence_model;
+mod sqlx_type;
+mod usecase;
+mod value_object;
+
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse_macro_input, Data, DeriveInput, Fields};
+use syn::{parse_macro_input, parse_quote, DeriveInput, ItemImpl, Visibility};
 
-#[proc_macro_derive(SqlxType)]
-pub fn sqlx_type_derive(input: TokenStream) -> TokenStream {
+// NOTE: 検証付きのnew・エラー型(codeで識別子を返す)・アクセサ・Display・TryFrom/From・sqlxの変換をまとめて生成する
+//       検証ルールは#[vo(trim, non_empty, min_len = 3, max_len = 20, regex = "...")]で指定する
+//       sqlxの変換はdb = "postgres"のように対象のDBを限定できる
+//       serdeを指定すると、デシリアライズ時にも検証するSerialize/Deserializeを生成する
+//       trust_storedを指定すると、検証しないfrom_storedを生成し、DBからの復元(Decode)ではそちらを使う
+#[proc_macro_derive(ValueObject, attributes(vo))]
+pub fn value_object_derive(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
-    impl_sqlx_type(&input)
+    value_object::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
-fn impl_sqlx_type(ast: &syn::DeriveInput) -> TokenStream {
-    let name = &ast.ident;
-    let inner_type = extract_inner_type(ast);
-    let gen = quote! {
-        impl sqlx::Type<sqlx::Postgres> for #name {
-            fn type_info() -> sqlx::postgres::PgTypeInfo {
-                <#inner_type as sqlx::Type<sqlx::Postgres>>::type_info()
-            }
-        }
-    };
-    gen.into()
+// NOTE: 以下の3つはNewtypeを内部の型としてDBに読み書きする
+//       フィールドが複数ある場合は、値を持つフィールドに#[sqlx_inner]を付ける
+//       既定では内部の型が対応する全てのDatabaseに実装し、#[sqlx(db = "postgres, sqlite")]で対象を限定できる
+#[proc_macro_derive(SqlxType, attributes(sqlx_inner, sqlx))]
+pub fn sqlx_type_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    sqlx_type::expand_type(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
-#[proc_macro_derive(SqlxEncode)]
+#[proc_macro_derive(SqlxEncode, attributes(sqlx_inner, sqlx))]
 pub fn sqlx_encode_derive(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
-    impl_sqlx_encode(&input)
+    sqlx_type::expand_encode(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
-fn impl_sqlx_encode(ast: &syn::DeriveInput) -> TokenStream {
-    let name = &ast.ident;
-    let inner_type = extract_inner_type(ast);
-    let gen = quote! {
-        impl<'q> sqlx::Encode<'q, sqlx::Postgres> for #name {
-            fn encode_by_ref(&self, buf: &mut sqlx::postgres::PgArgumentBuffer) -> sqlx::encode::IsNull {
-                <#inner_type as sqlx::Encode<sqlx::Postgres>>::encode(self.0.clone(), buf)
-            }
-        }
-    };
-    gen.into()
-}
-
-#[proc_macro_derive(SqlxDecode)]
+#[proc_macro_derive(SqlxDecode, attributes(sqlx_inner, sqlx))]
 pub fn sqlx_decode_derive(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
-    impl_sqlx_decode(&input)
+    sqlx_type::expand_decode(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
-fn impl_sqlx_decode(ast: &syn::DeriveInput) -> TokenStream {
-    let name = &ast.ident;
-    let inner_type = extract_inner_type(ast);
-    let gen = quote! {
-        impl<'r> sqlx::Decode<'r, sqlx::Postgres> for #name {
-            fn decode(value: sqlx::postgres::PgValueRef<'r>) -> Result<Self, Box<dyn std::error::Error + 'static + Send + Sync>> {
-                <#inner_type as sqlx::Decode<sqlx::Postgres>>::decode(value).map(Self)
-            }
-        }
-    };
-    gen.into()
+// NOTE: DTOとドメインモデルの相互変換(TryFrom)と変換エラーの列挙型を生成する
+//       #[model(domain = User, field(user_name = "name: UserName"))]のように、DTOのフィールドと値オブジェクトの対応を指定する
+//       値オブジェクトはTryFrom<カラムの型>とFrom<値オブジェクト> for カラムの型を実装し、エラー型は{Type}Errorとする
+//       storedを指定したフィールドは、TryFromではなくfrom_stored(trust_storedの値オブジェクト)で復元する
+#[proc_macro_derive(PersistenceModel, attributes(model))]
+pub fn persistence_model_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    persistence_model::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
-fn extract_inner_type(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
-    if let Data::Struct(ref data_struct) = ast.data {
-        if let Fields::Unnamed(ref fields_unnamed) = data_struct.fields {
-            if fields_unnamed.unnamed.len() == 1 {
-                let field = &fields_unnamed.unnamed[0];
-                let ty = &field.ty;
-                return quote! { #ty };
-            }
-        }
-    }
-    panic!("Expected a tuple struct with a single field");
+// NOTE: ユースケースのimplブロックから、トレイトの定義とSend + Syncの境界を生成する
+//       implに書いたasync fnのシグネチャがトレイトのメソッドになり、Futureは常にSendとなる
+//       トレイトの可視性は既定でpubとし、#[usecase(pub(crate))]のように変更できる
+#[proc_macro_attribute]
+pub fn usecase(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let vis = if attr.is_empty() {
+        parse_quote!(pub)
+    } else {
+        parse_macro_input!(attr as Visibility)
+    };
+    let item = parse_macro_input!(item as ItemImpl);
+    usecase::expand(vis, item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
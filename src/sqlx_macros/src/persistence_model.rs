@@ -0,0 +1,443 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Data, DeriveInput, Fields, GenericArgument, Ident, LitStr, Path, PathArguments, Token, Type,
+};
+
+// NOTE: field(dto_field = "domain_field: Type")の右辺
+struct FieldTarget {
+    domain_field: Ident,
+    ty: Type,
+}
+
+impl Parse for FieldTarget {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let domain_field = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let ty = input.parse()?;
+        Ok(Self { domain_field, ty })
+    }
+}
+
+// NOTE: ドメインの1フィールドと、それに対応するDTOのフィールド(複数の場合はタプルで変換する)
+struct FieldMapping {
+    columns: Vec<Ident>,
+    target: FieldTarget,
+    error: Option<Path>,
+    // NOTE: 値オブジェクトをTryFromではなく、検証しないfrom_storedで復元する(trust_storedの値オブジェクト用)
+    stored: bool,
+}
+
+struct ModelAttributes {
+    domain: Path,
+    error: Option<Ident>,
+    fields: Vec<FieldMapping>,
+}
+
+impl ModelAttributes {
+    fn parse(ast: &DeriveInput) -> syn::Result<Self> {
+        let mut domain = None;
+        let mut error = None;
+        let mut fields = Vec::new();
+        for attr in ast
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("model"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("domain") {
+                    domain = Some(meta.value()?.parse::<Path>()?);
+                } else if meta.path.is_ident("error") {
+                    error = Some(meta.value()?.parse::<Ident>()?);
+                } else if meta.path.is_ident("field") {
+                    fields.push(Self::parse_field(&meta)?);
+                } else {
+                    return Err(meta.error(
+                        "unsupported model attribute, expected one of: domain, error, field",
+                    ));
+                }
+                Ok(())
+            })?;
+        }
+        let domain = domain.ok_or_else(|| {
+            syn::Error::new_spanned(
+                &ast.ident,
+                "PersistenceModel requires #[model(domain = Type)]",
+            )
+        })?;
+        Ok(Self {
+            domain,
+            error,
+            fields,
+        })
+    }
+
+    // NOTE: field(column = "domain_field: Type", with(other_column, ...), error = "ErrorType", stored)
+    fn parse_field(meta: &syn::meta::ParseNestedMeta) -> syn::Result<FieldMapping> {
+        let mut columns = Vec::new();
+        let mut target = None;
+        let mut error = None;
+        let mut stored = false;
+        meta.parse_nested_meta(|meta| {
+            if meta.path.is_ident("with") {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                let others = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+                columns.extend(others);
+            } else if meta.path.is_ident("error") {
+                error = Some(meta.value()?.parse::<LitStr>()?.parse::<Path>()?);
+            } else if meta.path.is_ident("stored") {
+                stored = true;
+            } else if let Some(column) = meta.path.get_ident() {
+                if target.is_some() {
+                    return Err(meta.error("field maps only one column, use with(...) to add more"));
+                }
+                columns.insert(0, column.clone());
+                target = Some(meta.value()?.parse::<LitStr>()?.parse::<FieldTarget>()?);
+            } else {
+                return Err(meta.error("expected `column = \"domain_field: Type\"`"));
+            }
+            Ok(())
+        })?;
+        let target =
+            target.ok_or_else(|| meta.error("field requires `column = \"domain_field: Type\"`"))?;
+        if stored && columns.len() > 1 {
+            return Err(meta.error("stored supports only a single column"));
+        }
+        Ok(FieldMapping {
+            columns,
+            target,
+            error,
+            stored,
+        })
+    }
+}
+
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// NOTE: 値オブジェクトのエラー型は{Type}Errorとする(UserName -> UserNameError)
+fn value_object_error(ty: &Type) -> syn::Result<Path> {
+    let ty = option_inner(ty).unwrap_or(ty);
+    match ty {
+        Type::Path(path) if path.qself.is_none() => {
+            let mut error = path.path.clone();
+            let last = error.segments.last_mut().expect("path has a segment");
+            last.ident = format_ident!("{}Error", last.ident);
+            last.arguments = PathArguments::None;
+            Ok(error)
+        }
+        _ => Err(syn::Error::new_spanned(
+            ty,
+            "cannot infer the error type, specify it with error = \"...\"",
+        )),
+    }
+}
+
+fn to_pascal_case(ident: &Ident) -> String {
+    ident
+        .to_string()
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+// NOTE: 変換エラーのバリアント。同じエラー型を持つフィールドは1つのバリアントにまとめる
+struct ErrorVariant {
+    name: Ident,
+    label: String,
+    key: String,
+    ty: Path,
+}
+
+pub fn expand(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let attrs = ModelAttributes::parse(ast)?;
+    if !ast.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &ast.generics,
+            "PersistenceModel does not support generic parameters",
+        ));
+    }
+    let dto_fields = match &ast.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &ast.ident,
+                    "PersistenceModel can only be derived for a struct with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &ast.ident,
+                "PersistenceModel can only be derived for a struct with named fields",
+            ))
+        }
+    };
+    let dto_type = |column: &Ident| -> syn::Result<&Type> {
+        dto_fields
+            .iter()
+            .find(|field| field.ident.as_ref() == Some(column))
+            .map(|field| &field.ty)
+            .ok_or_else(|| {
+                syn::Error::new_spanned(column, format!("no field `{column}` in {}", ast.ident))
+            })
+    };
+
+    let mut mapped_columns: Vec<&Ident> = Vec::new();
+    for mapping in &attrs.fields {
+        for column in &mapping.columns {
+            dto_type(column)?;
+            if mapped_columns.contains(&column) {
+                return Err(syn::Error::new_spanned(
+                    column,
+                    format!("`{column}` is mapped more than once"),
+                ));
+            }
+            mapped_columns.push(column);
+        }
+    }
+
+    let name = &ast.ident;
+    let vis = &ast.vis;
+    let domain = &attrs.domain;
+    let domain_name = &domain.segments.last().expect("path has a segment").ident;
+    let error = attrs
+        .error
+        .clone()
+        .unwrap_or_else(|| format_ident!("{}DomainToDtoConversionError", domain_name));
+
+    let mut variants: Vec<ErrorVariant> = Vec::new();
+    let mut to_domain = Vec::new();
+    let mut to_dto = Vec::new();
+    let mut to_dto_bindings = Vec::new();
+
+    for mapping in &attrs.fields {
+        let domain_field = &mapping.target.domain_field;
+        let ty = &mapping.target.ty;
+        let error_ty = match &mapping.error {
+            Some(error_ty) => error_ty.clone(),
+            None => value_object_error(ty)?,
+        };
+        let variant_source = match &mapping.error {
+            Some(_) => to_pascal_case(domain_field),
+            None => {
+                let ty = option_inner(ty).unwrap_or(ty);
+                quote!(#ty).to_string().replace(' ', "")
+            }
+        };
+        let error_key = quote!(#error_ty).to_string();
+        if !variants.iter().any(|variant| variant.key == error_key) {
+            let label = variant_source
+                .rsplit("::")
+                .next()
+                .unwrap_or(&variant_source)
+                .to_string();
+            // NOTE: Vec<Foo>のようなジェネリックな型等からは列挙子の名前を作れないため、エラー型の指定を求める
+            let name = syn::parse_str::<Ident>(&format!("Invalid{label}")).map_err(|_| {
+                syn::Error::new_spanned(ty, "specify error = \"...\" for this field")
+            })?;
+            variants.push(ErrorVariant {
+                name,
+                label,
+                key: error_key,
+                ty: error_ty,
+            });
+        }
+
+        // NOTE: 明示的にエラー型を指定したフィールドは、DTOへの変換も失敗しうるものとしてTryFromを使う
+        let fallible_to_dto = mapping.error.is_some();
+        if let [column] = mapping.columns.as_slice() {
+            let column_ty = dto_type(column)?;
+            match (option_inner(ty), option_inner(column_ty)) {
+                (Some(inner), Some(column_inner)) => {
+                    to_domain.push(if mapping.stored {
+                        quote!(#domain_field: value.#column.map(<#inner>::from_stored))
+                    } else {
+                        quote! {
+                            #domain_field: value.#column
+                                .map(<#inner as ::core::convert::TryFrom<#column_inner>>::try_from)
+                                .transpose()?
+                        }
+                    });
+                    let convert = if fallible_to_dto {
+                        quote! {
+                            value.#domain_field
+                                .map(<#column_inner as ::core::convert::TryFrom<#inner>>::try_from)
+                                .transpose()?
+                        }
+                    } else {
+                        quote! {
+                            value.#domain_field.map(<#column_inner as ::core::convert::From<#inner>>::from)
+                        }
+                    };
+                    to_dto.push(quote!(#column: #convert));
+                }
+                (None, None) => {
+                    to_domain.push(if mapping.stored {
+                        quote!(#domain_field: <#ty>::from_stored(value.#column))
+                    } else {
+                        quote! {
+                            #domain_field: <#ty as ::core::convert::TryFrom<#column_ty>>::try_from(value.#column)?
+                        }
+                    });
+                    let convert = if fallible_to_dto {
+                        quote!(<#column_ty as ::core::convert::TryFrom<#ty>>::try_from(value.#domain_field)?)
+                    } else {
+                        quote!(<#column_ty as ::core::convert::From<#ty>>::from(value.#domain_field))
+                    };
+                    to_dto.push(quote!(#column: #convert));
+                }
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        ty,
+                        format!(
+                        "the column `{column}` and the domain field `{domain_field}` must both be Option or both be non-Option"
+                    ),
+                    ))
+                }
+            }
+        } else {
+            // NOTE: 複数のカラムはタプルにまとめて変換する
+            let columns = &mapping.columns;
+            let column_types = columns
+                .iter()
+                .map(&dto_type)
+                .collect::<syn::Result<Vec<_>>>()?;
+            to_domain.push(quote! {
+                #domain_field: <#ty as ::core::convert::TryFrom<(#(#column_types,)*)>>::try_from(
+                    (#(value.#columns,)*)
+                )?
+            });
+            let convert = if fallible_to_dto {
+                quote!(<(#(#column_types,)*) as ::core::convert::TryFrom<#ty>>::try_from(value.#domain_field)?)
+            } else {
+                quote!(<(#(#column_types,)*) as ::core::convert::From<#ty>>::from(value.#domain_field))
+            };
+            to_dto_bindings.push(quote!(let (#(#columns,)*) = #convert;));
+            to_dto.extend(columns.iter().map(|column| quote!(#column)));
+        }
+    }
+
+    // NOTE: 対応を指定していないフィールドは、ドメインの同名のフィールドをそのまま使う
+    for field in dto_fields {
+        let column = field.ident.as_ref().expect("named field");
+        if mapped_columns.contains(&column) {
+            continue;
+        }
+        to_domain.push(quote!(#column: value.#column));
+        to_dto.push(quote!(#column: value.#column));
+    }
+
+    let error_doc = format!(" {domain_name}と{name}の変換エラー");
+    let variant_defs = variants.iter().map(|variant| {
+        let variant_name = &variant.name;
+        let ty = &variant.ty;
+        quote!(#variant_name(#ty))
+    });
+    let messages = variants.iter().map(|variant| {
+        let variant_name = &variant.name;
+        let message = format!("Invalid {}: {{}}", variant.label);
+        quote!(Self::#variant_name(e) => write!(f, #message, e))
+    });
+    let sources = variants.iter().map(|variant| {
+        let variant_name = &variant.name;
+        quote!(Self::#variant_name(e) => Some(e))
+    });
+    let froms = variants.iter().map(|variant| {
+        let variant_name = &variant.name;
+        let ty = &variant.ty;
+        quote! {
+            impl ::core::convert::From<#ty> for #error {
+                fn from(value: #ty) -> Self {
+                    Self::#variant_name(value)
+                }
+            }
+        }
+    });
+    let display_body = if variants.is_empty() {
+        quote!(match *self {})
+    } else {
+        quote! {
+            match self {
+                #(#messages,)*
+            }
+        }
+    };
+    let source_body = if variants.is_empty() {
+        quote!(match *self {})
+    } else {
+        quote! {
+            match self {
+                #(#sources,)*
+            }
+        }
+    };
+
+    Ok(quote! {
+        impl ::core::convert::TryFrom<#domain> for #name {
+            type Error = #error;
+
+            fn try_from(value: #domain) -> Result<Self, Self::Error> {
+                #(#to_dto_bindings)*
+                Ok(Self {
+                    #(#to_dto,)*
+                })
+            }
+        }
+
+        impl ::core::convert::TryFrom<#name> for #domain {
+            type Error = #error;
+
+            fn try_from(value: #name) -> Result<Self, Self::Error> {
+                Ok(Self {
+                    #(#to_domain,)*
+                })
+            }
+        }
+
+        #[doc = #error_doc]
+        #[derive(Debug)]
+        #vis enum #error {
+            #(#variant_defs,)*
+        }
+
+        impl ::std::fmt::Display for #error {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                #display_body
+            }
+        }
+
+        impl ::std::error::Error for #error {
+            fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> {
+                #source_body
+            }
+        }
+
+        #(#froms)*
+    })
+}
@@ -0,0 +1,248 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, DeriveInput, Generics, LitStr, WhereClause, WherePredicate};
+
+use crate::inner_field::InnerField;
+
+// NOTE: #[sqlx(db = "...")]で指定できるバックエンド
+#[derive(Clone, Copy, PartialEq)]
+pub enum Backend {
+    Postgres,
+    Sqlite,
+    MySql,
+}
+
+impl Backend {
+    // NOTE: "postgres, sqlite"のようにカンマ区切りで指定する
+    pub fn parse_list(lit: &LitStr) -> syn::Result<Vec<Self>> {
+        let mut backends = Vec::new();
+        for name in lit.value().split(',').map(str::trim) {
+            let backend = match name {
+                "postgres" => Self::Postgres,
+                "sqlite" => Self::Sqlite,
+                "mysql" => Self::MySql,
+                _ => {
+                    return Err(syn::Error::new(
+                        lit.span(),
+                        format!(
+                            "unknown database `{name}`, expected one of: postgres, sqlite, mysql"
+                        ),
+                    ))
+                }
+            };
+            if backends.contains(&backend) {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    format!("database `{name}` is specified more than once"),
+                ));
+            }
+            backends.push(backend);
+        }
+        Ok(backends)
+    }
+
+    fn path(self) -> TokenStream {
+        match self {
+            Self::Postgres => quote!(sqlx::Postgres),
+            Self::Sqlite => quote!(sqlx::Sqlite),
+            Self::MySql => quote!(sqlx::MySql),
+        }
+    }
+}
+
+// NOTE: バックエンドを指定しない場合は、内部の型が対応する全てのDatabaseに対して実装する
+fn parse_backends(ast: &DeriveInput) -> syn::Result<Option<Vec<Backend>>> {
+    let mut backends = None;
+    for attr in ast.attrs.iter().filter(|attr| attr.path().is_ident("sqlx")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("db") {
+                if backends.is_some() {
+                    return Err(meta.error("db is specified more than once"));
+                }
+                backends = Some(Backend::parse_list(&meta.value()?.parse()?)?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported sqlx attribute, expected `db`"))
+            }
+        })?;
+    }
+    Ok(backends)
+}
+
+pub struct SqlxImpls<'a> {
+    ast: &'a DeriveInput,
+    inner: InnerField<'a>,
+    backends: Option<Vec<Backend>>,
+}
+
+impl<'a> SqlxImpls<'a> {
+    pub fn new(ast: &'a DeriveInput, derive: &str) -> syn::Result<Self> {
+        let inner = InnerField::find(ast, derive)?;
+        let backends = parse_backends(ast)?;
+        Ok(Self {
+            ast,
+            inner,
+            backends,
+        })
+    }
+
+    pub fn with_backends(
+        ast: &'a DeriveInput,
+        inner: InnerField<'a>,
+        backends: Option<Vec<Backend>>,
+    ) -> Self {
+        Self {
+            ast,
+            inner,
+            backends,
+        }
+    }
+
+    // NOTE: 実装ごとに(impl側のジェネリクス, where句, Databaseの型)を生成する
+    fn targets(
+        &self,
+        lifetime: Option<&syn::Lifetime>,
+        bound: impl Fn(&TokenStream) -> WherePredicate,
+    ) -> Vec<(Generics, Option<WhereClause>, TokenStream)> {
+        let mut generics = self.ast.generics.clone();
+        if let Some(lifetime) = lifetime {
+            generics.params.insert(0, parse_quote!(#lifetime));
+        }
+        match &self.backends {
+            None => {
+                let db = quote!(__DB);
+                let mut generics = generics;
+                generics.params.push(parse_quote!(#db: sqlx::Database));
+                let where_clause = where_clause(&self.ast.generics, true, bound(&db));
+                vec![(generics, where_clause, db)]
+            }
+            Some(backends) => backends
+                .iter()
+                .map(|backend| {
+                    let db = backend.path();
+                    let where_clause = where_clause(&self.ast.generics, false, bound(&db));
+                    (generics.clone(), where_clause, db)
+                })
+                .collect(),
+        }
+    }
+
+    pub fn type_impl(&self) -> TokenStream {
+        let name = &self.ast.ident;
+        let inner_type = self.inner.ty;
+        let (_, ty_generics, _) = self.ast.generics.split_for_impl();
+        let impls = self
+            .targets(None, |db| parse_quote!(#inner_type: sqlx::Type<#db>))
+            .into_iter()
+            .map(|(generics, where_clause, db)| {
+                let (impl_generics, _, _) = generics.split_for_impl();
+                quote! {
+                    impl #impl_generics sqlx::Type<#db> for #name #ty_generics #where_clause {
+                        fn type_info() -> <#db as sqlx::Database>::TypeInfo {
+                            <#inner_type as sqlx::Type<#db>>::type_info()
+                        }
+
+                        fn compatible(ty: &<#db as sqlx::Database>::TypeInfo) -> bool {
+                            <#inner_type as sqlx::Type<#db>>::compatible(ty)
+                        }
+                    }
+                }
+            });
+        quote!(#(#impls)*)
+    }
+
+    // NOTE: 内部の値を参照のままエンコードし、エンコードのたびに複製しない
+    pub fn encode_impl(&self) -> TokenStream {
+        let name = &self.ast.ident;
+        let inner_type = self.inner.ty;
+        let member = &self.inner.member;
+        let lifetime: syn::Lifetime = parse_quote!('__q);
+        let (_, ty_generics, _) = self.ast.generics.split_for_impl();
+        let impls = self
+            .targets(Some(&lifetime), |db| {
+                parse_quote!(#inner_type: sqlx::Encode<#lifetime, #db>)
+            })
+            .into_iter()
+            .map(|(generics, where_clause, db)| {
+                let (impl_generics, _, _) = generics.split_for_impl();
+                quote! {
+                    impl #impl_generics sqlx::Encode<#lifetime, #db> for #name #ty_generics #where_clause {
+                        fn encode_by_ref(
+                            &self,
+                            buf: &mut <#db as sqlx::Database>::ArgumentBuffer<#lifetime>,
+                        ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+                            <#inner_type as sqlx::Encode<#lifetime, #db>>::encode_by_ref(&self.#member, buf)
+                        }
+
+                        fn produces(&self) -> Option<<#db as sqlx::Database>::TypeInfo> {
+                            <#inner_type as sqlx::Encode<#lifetime, #db>>::produces(&self.#member)
+                        }
+
+                        fn size_hint(&self) -> usize {
+                            <#inner_type as sqlx::Encode<#lifetime, #db>>::size_hint(&self.#member)
+                        }
+                    }
+                }
+            });
+        quote!(#(#impls)*)
+    }
+
+    // NOTE: convertには内部の型でデコードした値(value)から構造体を生成する式を渡す
+    pub fn decode_impl(&self, convert: impl Fn(&InnerField) -> TokenStream) -> TokenStream {
+        let name = &self.ast.ident;
+        let inner_type = self.inner.ty;
+        let lifetime: syn::Lifetime = parse_quote!('__r);
+        let (_, ty_generics, _) = self.ast.generics.split_for_impl();
+        let convert = convert(&self.inner);
+        let impls = self
+            .targets(Some(&lifetime), |db| {
+                parse_quote!(#inner_type: sqlx::Decode<#lifetime, #db>)
+            })
+            .into_iter()
+            .map(|(generics, where_clause, db)| {
+                let (impl_generics, _, _) = generics.split_for_impl();
+                quote! {
+                    impl #impl_generics sqlx::Decode<#lifetime, #db> for #name #ty_generics #where_clause {
+                        fn decode(
+                            value: <#db as sqlx::Database>::ValueRef<#lifetime>,
+                        ) -> Result<Self, sqlx::error::BoxDynError> {
+                            let value = <#inner_type as sqlx::Decode<#lifetime, #db>>::decode(value)?;
+                            #convert
+                        }
+                    }
+                }
+            });
+        quote!(#(#impls)*)
+    }
+}
+
+// NOTE: 構造体かDatabaseがジェネリクスの場合のみ、内部の型に必要なトレイト境界を追加する
+fn where_clause(
+    generics: &Generics,
+    generic_db: bool,
+    bound: WherePredicate,
+) -> Option<WhereClause> {
+    let mut where_clause = generics.where_clause.clone();
+    if generic_db || !generics.params.is_empty() {
+        where_clause
+            .get_or_insert_with(|| parse_quote!(where))
+            .predicates
+            .push(bound);
+    }
+    where_clause
+}
+
+pub fn expand_type(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    Ok(SqlxImpls::new(ast, "SqlxType")?.type_impl())
+}
+
+pub fn expand_encode(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    Ok(SqlxImpls::new(ast, "SqlxEncode")?.encode_impl())
+}
+
+pub fn expand_decode(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    Ok(SqlxImpls::new(ast, "SqlxDecode")?.decode_impl(|inner| {
+        let construct = inner.construct(quote!(value));
+        quote!(Ok(#construct))
+    }))
+}
@@ -0,0 +1,149 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    parse_quote, GenericArgument, GenericParam, ImplItem, ItemImpl, PathArguments, TraitItemFn,
+    Type, Visibility, WherePredicate,
+};
+
+// NOTE: 実装するトレイトのパス(UserDeleteUsecase<Tx>)から、トレイト名とジェネリクスの識別子を取り出す
+fn trait_params(item: &ItemImpl) -> syn::Result<(&syn::Ident, Vec<&syn::Ident>)> {
+    let Some((None, path, _)) = &item.trait_ else {
+        return Err(syn::Error::new_spanned(
+            &item.self_ty,
+            "#[usecase] must be applied to `impl UsecaseTrait<..> for UseCaseImpl<..>`",
+        ));
+    };
+    let segment = path.segments.last().expect("path has a segment");
+    if path.segments.len() != 1 {
+        return Err(syn::Error::new_spanned(
+            path,
+            "#[usecase] generates the trait, so it must be a plain name, not a path",
+        ));
+    }
+    let mut params = Vec::new();
+    if let PathArguments::AngleBracketed(args) = &segment.arguments {
+        for arg in &args.args {
+            match arg {
+                GenericArgument::Type(Type::Path(ty))
+                    if ty.qself.is_none() && ty.path.get_ident().is_some() =>
+                {
+                    params.push(ty.path.get_ident().expect("checked above"));
+                }
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        arg,
+                        "trait arguments must be type parameters of the impl",
+                    ))
+                }
+            }
+        }
+    }
+    Ok((&segment.ident, params))
+}
+
+fn bounded_ident(predicate: &WherePredicate) -> Option<&syn::Ident> {
+    match predicate {
+        WherePredicate::Type(predicate) => match &predicate.bounded_ty {
+            Type::Path(ty) if ty.qself.is_none() => ty.path.get_ident(),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+pub fn expand(vis: Visibility, mut item: ItemImpl) -> syn::Result<TokenStream> {
+    let (trait_name, params) = trait_params(&item)?;
+    let trait_name = trait_name.clone();
+    let params: Vec<syn::Ident> = params.into_iter().cloned().collect();
+
+    let mut methods = Vec::new();
+    for impl_item in &item.items {
+        let ImplItem::Fn(method) = impl_item else {
+            return Err(syn::Error::new_spanned(
+                impl_item,
+                "#[usecase] impl can only contain async fns",
+            ));
+        };
+        if method.sig.asyncness.is_none() {
+            return Err(syn::Error::new_spanned(
+                method.sig.fn_token,
+                "usecase methods must be async fns",
+            ));
+        }
+        if method.sig.receiver().is_none() {
+            return Err(syn::Error::new_spanned(
+                &method.sig,
+                "usecase methods must take &self",
+            ));
+        }
+        // NOTE: tracing::instrument等の実装向けの属性はトレイトには付けない
+        let docs = method
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("doc"))
+            .cloned()
+            .collect();
+        methods.push(TraitItemFn {
+            attrs: docs,
+            sig: method.sig.clone(),
+            default: None,
+            semi_token: Some(Default::default()),
+        });
+    }
+
+    // NOTE: トレイトのジェネリクスには、implで書いた境界をそのまま引き継ぐ
+    let mut trait_generics: syn::Generics = parse_quote!(<#(#params),*>);
+    let mut trait_predicates: Vec<WherePredicate> = Vec::new();
+    for param in &item.generics.params {
+        if let GenericParam::Type(param) = param {
+            if params.contains(&param.ident) && !param.bounds.is_empty() {
+                let ident = &param.ident;
+                let bounds = &param.bounds;
+                trait_predicates.push(parse_quote!(#ident: #bounds));
+            }
+        }
+    }
+    if let Some(where_clause) = &item.generics.where_clause {
+        trait_predicates.extend(
+            where_clause
+                .predicates
+                .iter()
+                .filter(|predicate| {
+                    bounded_ident(predicate).is_some_and(|ident| params.contains(ident))
+                })
+                .cloned(),
+        );
+    }
+    if !trait_predicates.is_empty() {
+        trait_generics
+            .make_where_clause()
+            .predicates
+            .extend(trait_predicates);
+    }
+    let (_, _, trait_where_clause) = trait_generics.split_for_impl();
+
+    // NOTE: &selfを保持したままawaitするため、Futureを常にSendにするには全ての型パラメータがSend + Syncである必要がある
+    let type_params: Vec<syn::Ident> = item
+        .generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect();
+    let where_clause = item.generics.make_where_clause();
+    for ident in type_params {
+        where_clause
+            .predicates
+            .push(parse_quote!(#ident: ::core::marker::Send + ::core::marker::Sync));
+    }
+    item.attrs.push(parse_quote!(#[::async_trait::async_trait]));
+
+    Ok(quote! {
+        #[::async_trait::async_trait]
+        #vis trait #trait_name <#(#params),*>: ::core::marker::Send + ::core::marker::Sync
+        #trait_where_clause
+        {
+            #(#methods)*
+        }
+
+        #item
+    })
+}
@@ -0,0 +1,333 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, Ident, LitInt, LitStr, Type};
+
+use crate::inner_field::InnerField;
+use crate::sqlx_type::{Backend, SqlxImpls};
+
+// NOTE: #[vo(...)]で指定された検証ルール
+#[derive(Default)]
+struct ValueObjectAttributes {
+    label: Option<String>,
+    error: Option<Ident>,
+    trim: bool,
+    non_empty: bool,
+    min_len: Option<usize>,
+    max_len: Option<usize>,
+    regex: Option<LitStr>,
+    db: Option<Vec<Backend>>,
+    serde: bool,
+    trust_stored: bool,
+}
+
+impl ValueObjectAttributes {
+    fn parse(ast: &DeriveInput) -> syn::Result<Self> {
+        let mut attrs = Self::default();
+        for attr in ast.attrs.iter().filter(|attr| attr.path().is_ident("vo")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("label") {
+                    attrs.label = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("error") {
+                    let lit = meta.value()?.parse::<LitStr>()?;
+                    attrs.error = Some(lit.parse()?);
+                } else if meta.path.is_ident("trim") {
+                    attrs.trim = true;
+                } else if meta.path.is_ident("non_empty") {
+                    attrs.non_empty = true;
+                } else if meta.path.is_ident("min_len") {
+                    attrs.min_len = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+                } else if meta.path.is_ident("max_len") {
+                    attrs.max_len = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+                } else if meta.path.is_ident("regex") {
+                    let lit = meta.value()?.parse::<LitStr>()?;
+                    if let Err(e) = regex::Regex::new(&lit.value()) {
+                        return Err(syn::Error::new(lit.span(), format!("invalid regex: {e}")));
+                    }
+                    attrs.regex = Some(lit);
+                } else if meta.path.is_ident("db") {
+                    attrs.db = Some(Backend::parse_list(&meta.value()?.parse()?)?);
+                } else if meta.path.is_ident("serde") {
+                    attrs.serde = true;
+                } else if meta.path.is_ident("trust_stored") {
+                    attrs.trust_stored = true;
+                } else {
+                    return Err(meta.error(
+                        "unsupported vo attribute, expected one of: label, error, trim, non_empty, min_len, max_len, regex, db, serde, trust_stored",
+                    ));
+                }
+                Ok(())
+            })?;
+        }
+        if let (Some(min_len), Some(max_len)) = (attrs.min_len, attrs.max_len) {
+            if min_len > max_len {
+                return Err(syn::Error::new_spanned(
+                    &ast.ident,
+                    "min_len must not be greater than max_len",
+                ));
+            }
+        }
+        Ok(attrs)
+    }
+
+    fn has_string_rules(&self) -> bool {
+        self.trim
+            || self.non_empty
+            || self.min_len.is_some()
+            || self.max_len.is_some()
+            || self.regex.is_some()
+    }
+
+    // NOTE: 最小長を指定した場合も、空文字列は専用のエラーとする
+    fn checks_empty(&self) -> bool {
+        self.non_empty || self.min_len.is_some_and(|min_len| min_len > 0)
+    }
+}
+
+fn inner_type(ast: &DeriveInput) -> syn::Result<&Type> {
+    match &ast.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => Ok(&fields.unnamed[0].ty),
+            _ => Err(syn::Error::new_spanned(
+                &ast.ident,
+                "ValueObject can only be derived for a tuple struct with a single field",
+            )),
+        },
+        _ => Err(syn::Error::new_spanned(
+            &ast.ident,
+            "ValueObject can only be derived for a tuple struct with a single field",
+        )),
+    }
+}
+
+fn is_string(ty: &Type) -> bool {
+    matches!(ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident("String"))
+}
+
+pub fn expand(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let attrs = ValueObjectAttributes::parse(ast)?;
+    let inner_type = inner_type(ast)?;
+    let string_inner = is_string(inner_type);
+    if attrs.has_string_rules() && !string_inner {
+        return Err(syn::Error::new_spanned(
+            inner_type,
+            "trim, non_empty, min_len, max_len and regex require a String field",
+        ));
+    }
+    if !ast.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &ast.generics,
+            "ValueObject does not support generic parameters",
+        ));
+    }
+
+    let vis = &ast.vis;
+    let name = &ast.ident;
+    let error = attrs
+        .error
+        .clone()
+        .unwrap_or_else(|| format_ident!("{}Error", name));
+    let label = attrs.label.clone().unwrap_or_else(|| name.to_string());
+
+    let mut consts = Vec::new();
+    let mut checks = Vec::new();
+    let mut variants = Vec::new();
+    let mut messages = Vec::new();
+    let mut codes = Vec::new();
+
+    if attrs.trim {
+        checks.push(quote! { let value = value.trim().to_string(); });
+    }
+    if attrs.checks_empty() {
+        checks.push(quote! {
+            if value.is_empty() {
+                return Err(#error::Empty);
+            }
+        });
+        variants.push(quote! { Empty });
+        let message = format!("{label}が空白です。");
+        messages.push(quote! { Self::Empty => write!(f, #message) });
+        codes.push(quote! { Self::Empty => "empty" });
+    }
+    if let Some(min_len) = attrs.min_len {
+        consts.push(quote! { pub const MIN_LENGTH: usize = #min_len; });
+        checks.push(quote! {
+            if value.chars().count() < Self::MIN_LENGTH {
+                return Err(#error::TooShort { min_length: Self::MIN_LENGTH });
+            }
+        });
+        variants.push(quote! { TooShort { min_length: usize } });
+        let message = format!("{label}は{{}}文字以上で入力してください。");
+        messages.push(quote! { Self::TooShort { min_length } => write!(f, #message, min_length) });
+        codes.push(quote! { Self::TooShort { .. } => "too_short" });
+    }
+    if let Some(max_len) = attrs.max_len {
+        consts.push(quote! { pub const MAX_LENGTH: usize = #max_len; });
+        checks.push(quote! {
+            if value.chars().count() > Self::MAX_LENGTH {
+                return Err(#error::TooLong { max_length: Self::MAX_LENGTH });
+            }
+        });
+        variants.push(quote! { TooLong { max_length: usize } });
+        let message = format!("{label}は{{}}文字以下で入力してください。");
+        messages.push(quote! { Self::TooLong { max_length } => write!(f, #message, max_length) });
+        codes.push(quote! { Self::TooLong { .. } => "too_long" });
+    }
+    if let Some(regex) = &attrs.regex {
+        checks.push(quote! {
+            static PATTERN: ::std::sync::LazyLock<::regex::Regex> =
+                ::std::sync::LazyLock::new(|| ::regex::Regex::new(#regex).expect("validated at compile time"));
+            if !PATTERN.is_match(&value) {
+                return Err(#error::InvalidFormat);
+            }
+        });
+        variants.push(quote! { InvalidFormat });
+        let message = format!("{label}の形式が不正です。");
+        messages.push(quote! { Self::InvalidFormat => write!(f, #message) });
+        codes.push(quote! { Self::InvalidFormat => "invalid_format" });
+    }
+
+    let getter = if string_inner {
+        quote! {
+            pub fn get(&self) -> &str {
+                &self.0
+            }
+        }
+    } else {
+        quote! {
+            pub fn get(&self) -> &#inner_type {
+                &self.0
+            }
+        }
+    };
+    let (display_body, code_body) = if messages.is_empty() {
+        (quote! { match *self {} }, quote! { match *self {} })
+    } else {
+        (
+            quote! {
+                match self {
+                    #(#messages,)*
+                }
+            },
+            quote! {
+                match self {
+                    #(#codes,)*
+                }
+            },
+        )
+    };
+    let error_doc = format!(" {name}の検証エラー");
+    // NOTE: DBの値も検証し、不正な値からドメインオブジェクトを生成しない
+    //       trust_storedを指定した場合は、検証ルールの追加前に保存された値も読み込めるよう、
+    //       検証しないfrom_storedを生成してDecodeで使う。TryFromはnewと同じく常に検証する
+    let (from_stored, decode) = if attrs.trust_stored {
+        (
+            Some(quote! {
+                /// DBに保存済みの値から検証せずに復元する。入力値には使わないこと
+                pub fn from_stored(value: #inner_type) -> Self {
+                    Self(value)
+                }
+            }),
+            quote!(Ok(Self::from_stored(value))),
+        )
+    } else {
+        (
+            None,
+            quote!(Ok(<Self as ::std::convert::TryFrom<#inner_type>>::try_from(value)?)),
+        )
+    };
+    let sqlx_impls =
+        SqlxImpls::with_backends(ast, InnerField::find(ast, "ValueObject")?, attrs.db.clone());
+    let sqlx_impls = [
+        sqlx_impls.type_impl(),
+        sqlx_impls.encode_impl(),
+        sqlx_impls.decode_impl(|_| decode.clone()),
+    ];
+    // NOTE: デシリアライズ時もnewで検証し、言語に依存しない検証エラーのコードをserdeのエラーとして返す
+    //       表示用のメッセージは呼び出し側で検証エラーの型から言語を選んで生成する
+    let serde_impls = attrs.serde.then(|| {
+        quote! {
+            impl ::serde::Serialize for #name {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: ::serde::Serializer,
+                {
+                    <#inner_type as ::serde::Serialize>::serialize(&self.0, serializer)
+                }
+            }
+
+            impl<'de> ::serde::Deserialize<'de> for #name {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: ::serde::Deserializer<'de>,
+                {
+                    let value = <#inner_type as ::serde::Deserialize<'de>>::deserialize(deserializer)?;
+                    Self::new(value).map_err(|error| ::serde::de::Error::custom(error.code()))
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #name {
+            #(#consts)*
+
+            pub fn new(value: #inner_type) -> Result<Self, #error> {
+                #(#checks)*
+                Ok(Self(value))
+            }
+
+            #from_stored
+
+            #getter
+
+            pub fn into_inner(self) -> #inner_type {
+                self.0
+            }
+        }
+
+        impl ::std::fmt::Display for #name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                ::std::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl ::std::convert::TryFrom<#inner_type> for #name {
+            type Error = #error;
+
+            fn try_from(value: #inner_type) -> Result<Self, Self::Error> {
+                Self::new(value)
+            }
+        }
+
+        impl ::std::convert::From<#name> for #inner_type {
+            fn from(value: #name) -> Self {
+                value.0
+            }
+        }
+
+        #[doc = #error_doc]
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #vis enum #error {
+            #(#variants,)*
+        }
+
+        impl #error {
+            // NOTE: クライアントが判定に使う、メッセージの文言に依存しない識別子
+            pub fn code(&self) -> &'static str {
+                #code_body
+            }
+        }
+
+        impl ::std::fmt::Display for #error {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                #display_body
+            }
+        }
+
+        impl ::std::error::Error for #error {}
+
+        #(#sqlx_impls)*
+
+        #serde_impls
+    })
+}
@@ -0,0 +1,8 @@
+// NOTE: derive_macroの展開結果とエラーメッセージを検証する
+//       エラーメッセージを変更した場合は、TRYBUILD=overwrite cargo test -p sqlx_macrosで.stderrを更新する
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/pass/*.rs");
+    t.compile_fail("tests/ui/fail/*.rs");
+}
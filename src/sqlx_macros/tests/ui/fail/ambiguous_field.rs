@@ -0,0 +1,9 @@
+use sqlx_macros::SqlxEncode;
+
+#[derive(Clone, SqlxEncode)]
+struct Pair {
+    first: String,
+    second: String,
+}
+
+fn main() {}
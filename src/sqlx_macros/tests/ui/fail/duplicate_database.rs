@@ -0,0 +1,7 @@
+use sqlx_macros::ValueObject;
+
+#[derive(ValueObject)]
+#[vo(db = "sqlite, sqlite")]
+struct Code(String);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use sqlx_macros::SqlxType;
+
+#[derive(SqlxType)]
+struct Pair(#[sqlx_inner] String, #[sqlx_inner] String);
+
+fn main() {}
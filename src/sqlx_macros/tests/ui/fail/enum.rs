@@ -0,0 +1,9 @@
+use sqlx_macros::SqlxType;
+
+#[derive(SqlxType)]
+enum Status {
+    Active,
+    Suspended,
+}
+
+fn main() {}
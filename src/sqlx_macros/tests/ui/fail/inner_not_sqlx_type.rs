@@ -0,0 +1,12 @@
+use sqlx_macros::SqlxType;
+
+struct NotSqlx;
+
+#[derive(SqlxType)]
+struct Wrapper(NotSqlx);
+
+fn assert_postgres<T: sqlx::Type<sqlx::Postgres>>() {}
+
+fn main() {
+    assert_postgres::<Wrapper>();
+}
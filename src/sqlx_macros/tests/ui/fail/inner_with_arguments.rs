@@ -0,0 +1,9 @@
+use sqlx_macros::SqlxType;
+
+#[derive(SqlxType)]
+struct Name {
+    #[sqlx_inner(rename = "name")]
+    value: String,
+}
+
+fn main() {}
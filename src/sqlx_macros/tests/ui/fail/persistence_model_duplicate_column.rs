@@ -0,0 +1,19 @@
+use sqlx_macros::PersistenceModel;
+
+pub struct User {
+    pub status: String,
+    pub reason: String,
+}
+
+#[derive(PersistenceModel)]
+#[model(
+    domain = User,
+    field(status = "status: String", with(reason)),
+    field(reason = "reason: String")
+)]
+pub struct UserDto {
+    pub status: String,
+    pub reason: String,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use sqlx_macros::PersistenceModel;
+
+pub struct Tag(String);
+
+pub struct User {
+    pub tags: Vec<Tag>,
+}
+
+#[derive(PersistenceModel)]
+#[model(domain = User, field(tags = "tags: Vec<Tag>"))]
+pub struct UserDto {
+    pub tags: Vec<String>,
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+use sqlx_macros::PersistenceModel;
+
+pub struct UserName(String);
+
+#[derive(Debug)]
+pub struct UserNameError;
+
+impl std::fmt::Display for UserNameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid")
+    }
+}
+
+impl std::error::Error for UserNameError {}
+
+pub struct User {
+    pub name: UserName,
+}
+
+#[derive(PersistenceModel)]
+#[model(domain = User, field(user_name = "name: UserName"))]
+pub struct UserDto {
+    pub user_name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use sqlx_macros::PersistenceModel;
+
+#[derive(PersistenceModel)]
+#[model(field(user_name = "name: String"))]
+pub struct UserDto {
+    pub user_name: String,
+}
+
+fn main() {}
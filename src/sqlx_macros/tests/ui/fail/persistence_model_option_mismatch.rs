@@ -0,0 +1,13 @@
+use sqlx_macros::PersistenceModel;
+
+pub struct User {
+    pub nickname: Option<String>,
+}
+
+#[derive(PersistenceModel)]
+#[model(domain = User, field(nickname = "nickname: Option<String>"))]
+pub struct UserDto {
+    pub nickname: String,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use sqlx_macros::PersistenceModel;
+
+pub struct User {
+    pub name: String,
+}
+
+#[derive(PersistenceModel)]
+#[model(domain = User, field(name = "name: String"))]
+pub struct UserDto {
+    pub user_name: String,
+}
+
+fn main() {}
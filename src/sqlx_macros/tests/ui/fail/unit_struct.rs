@@ -0,0 +1,6 @@
+use sqlx_macros::SqlxDecode;
+
+#[derive(SqlxDecode)]
+struct Empty;
+
+fn main() {}
@@ -0,0 +1,7 @@
+use sqlx_macros::SqlxType;
+
+#[derive(SqlxType)]
+#[sqlx(db = "postgres, oracle")]
+struct UserName(String);
+
+fn main() {}
@@ -0,0 +1,11 @@
+use sqlx_macros::SqlxType;
+
+#[derive(SqlxType)]
+#[sqlx(db = "sqlite")]
+struct UserName(String);
+
+fn assert_postgres<T: sqlx::Type<sqlx::Postgres>>() {}
+
+fn main() {
+    assert_postgres::<UserName>();
+}
@@ -0,0 +1,12 @@
+use sqlx_macros::usecase;
+
+pub struct UseCaseImpl;
+
+#[usecase]
+impl UseCaseImpl {
+    async fn find(&self) -> Option<String> {
+        None
+    }
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use std::rc::Rc;
+
+use sqlx_macros::usecase;
+
+pub struct UseCaseImpl;
+
+#[usecase]
+impl CountUsecase for UseCaseImpl {
+    async fn count(&self) -> usize {
+        let counter = Rc::new(1);
+        std::future::ready(()).await;
+        *counter
+    }
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use sqlx_macros::ValueObject;
+
+#[derive(ValueObject)]
+#[vo(regex = "[a-z")]
+struct Code(String);
+
+fn main() {}
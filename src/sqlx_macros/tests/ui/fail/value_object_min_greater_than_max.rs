@@ -0,0 +1,7 @@
+use sqlx_macros::ValueObject;
+
+#[derive(ValueObject)]
+#[vo(min_len = 10, max_len = 3)]
+struct Code(String);
+
+fn main() {}
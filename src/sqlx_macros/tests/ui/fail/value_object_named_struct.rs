@@ -0,0 +1,8 @@
+use sqlx_macros::ValueObject;
+
+#[derive(ValueObject)]
+struct Code {
+    value: String,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use sqlx_macros::ValueObject;
+
+#[derive(ValueObject)]
+#[vo(max_len = 3)]
+struct Age(i32);
+
+fn main() {}
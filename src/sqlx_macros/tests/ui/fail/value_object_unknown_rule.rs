@@ -0,0 +1,7 @@
+use sqlx_macros::ValueObject;
+
+#[derive(ValueObject)]
+#[vo(trim, lowercase)]
+struct Code(String);
+
+fn main() {}
@@ -0,0 +1,39 @@
+use sqlx::{Database, Postgres, Sqlite};
+use sqlx_macros::{SqlxDecode, SqlxEncode, SqlxType, ValueObject};
+
+#[derive(SqlxType, SqlxEncode, SqlxDecode)]
+struct AnyDatabase(String);
+
+#[derive(SqlxType, SqlxEncode, SqlxDecode)]
+#[sqlx(db = "postgres, sqlite")]
+struct Selected(i64);
+
+#[derive(SqlxType, SqlxEncode, SqlxDecode)]
+#[sqlx(db = "sqlite")]
+struct SqliteOnly<T>(T);
+
+#[derive(Debug, Clone, PartialEq, ValueObject)]
+#[vo(trim, non_empty, db = "sqlite")]
+struct Code(String);
+
+#[derive(Debug, Clone, PartialEq, ValueObject)]
+#[vo(max_len = 10)]
+struct Label(String);
+
+fn assert_sqlx<DB, T>()
+where
+    DB: Database,
+    T: sqlx::Type<DB> + for<'q> sqlx::Encode<'q, DB> + for<'r> sqlx::Decode<'r, DB>,
+{
+}
+
+fn main() {
+    assert_sqlx::<Postgres, AnyDatabase>();
+    assert_sqlx::<Sqlite, AnyDatabase>();
+    assert_sqlx::<Postgres, Selected>();
+    assert_sqlx::<Sqlite, Selected>();
+    assert_sqlx::<Sqlite, SqliteOnly<i32>>();
+    assert_sqlx::<Sqlite, Code>();
+    assert_sqlx::<Postgres, Label>();
+    assert_sqlx::<Sqlite, Label>();
+}
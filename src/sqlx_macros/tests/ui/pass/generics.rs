@@ -0,0 +1,38 @@
+use std::borrow::Cow;
+
+use sqlx::Postgres;
+use sqlx_macros::{SqlxDecode, SqlxEncode, SqlxType};
+
+#[derive(Clone, SqlxType, SqlxEncode, SqlxDecode)]
+struct Wrapper<T>(T);
+
+#[derive(Clone, SqlxType, SqlxEncode, SqlxDecode)]
+struct Label<'a>(Cow<'a, str>);
+
+#[derive(Clone, SqlxType, SqlxEncode, SqlxDecode)]
+struct Bounded<T>
+where
+    T: Clone,
+{
+    value: T,
+}
+
+fn assert_type<T: sqlx::Type<Postgres>>() {}
+
+fn assert_encode<'q, T: sqlx::Encode<'q, Postgres>>() {}
+
+fn assert_decode<'r, T: sqlx::Decode<'r, Postgres>>() {}
+
+fn main() {
+    assert_type::<Wrapper<i32>>();
+    assert_encode::<Wrapper<String>>();
+    assert_decode::<Wrapper<sqlx::types::Uuid>>();
+
+    assert_type::<Label<'static>>();
+    assert_encode::<Label<'static>>();
+    assert_decode::<Label<'static>>();
+
+    assert_type::<Bounded<i64>>();
+    assert_encode::<Bounded<i64>>();
+    assert_decode::<Bounded<i64>>();
+}
@@ -0,0 +1,33 @@
+use std::marker::PhantomData;
+
+use sqlx::Postgres;
+use sqlx_macros::{SqlxDecode, SqlxEncode, SqlxType};
+
+#[derive(Clone, SqlxType, SqlxEncode, SqlxDecode)]
+struct MailAddress {
+    value: String,
+}
+
+#[derive(Clone, SqlxType, SqlxEncode, SqlxDecode)]
+struct Counter {
+    #[sqlx_inner]
+    count: i64,
+    cached: Option<String>,
+}
+
+struct User;
+
+#[derive(Clone, SqlxType, SqlxEncode, SqlxDecode)]
+struct Id<T>(#[sqlx_inner] i64, PhantomData<T>);
+
+fn assert_sqlx<T>()
+where
+    T: sqlx::Type<Postgres> + for<'q> sqlx::Encode<'q, Postgres> + for<'r> sqlx::Decode<'r, Postgres>,
+{
+}
+
+fn main() {
+    assert_sqlx::<MailAddress>();
+    assert_sqlx::<Counter>();
+    assert_sqlx::<Id<User>>();
+}
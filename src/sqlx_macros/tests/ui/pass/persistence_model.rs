@@ -0,0 +1,140 @@
+use sqlx_macros::PersistenceModel;
+
+#[derive(Debug, PartialEq)]
+pub struct UserName(String);
+
+impl TryFrom<String> for UserName {
+    type Error = UserNameError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.is_empty() {
+            return Err(UserNameError);
+        }
+        Ok(Self(value))
+    }
+}
+
+impl From<UserName> for String {
+    fn from(value: UserName) -> Self {
+        value.0
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct UserNameError;
+
+impl std::fmt::Display for UserNameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "empty")
+    }
+}
+
+impl std::error::Error for UserNameError {}
+
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    Active,
+    Suspended { reason: String },
+}
+
+impl TryFrom<(String, Option<String>)> for Status {
+    type Error = StatusError;
+
+    fn try_from((status, reason): (String, Option<String>)) -> Result<Self, Self::Error> {
+        match (status.as_str(), reason) {
+            ("active", _) => Ok(Self::Active),
+            ("suspended", Some(reason)) => Ok(Self::Suspended { reason }),
+            _ => Err(StatusError),
+        }
+    }
+}
+
+impl From<Status> for (String, Option<String>) {
+    fn from(value: Status) -> Self {
+        match value {
+            Status::Active => ("active".to_string(), None),
+            Status::Suspended { reason } => ("suspended".to_string(), Some(reason)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct StatusError;
+
+impl std::fmt::Display for StatusError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown status")
+    }
+}
+
+impl std::error::Error for StatusError {}
+
+#[derive(Debug, PartialEq)]
+pub struct User {
+    pub name: UserName,
+    pub nickname: Option<UserName>,
+    pub status: Status,
+    pub login_count: u32,
+    pub note: String,
+}
+
+#[derive(Debug, PartialEq, PersistenceModel)]
+#[model(
+    domain = User,
+    field(user_name = "name: UserName"),
+    field(nickname = "nickname: Option<UserName>"),
+    field(status = "status: Status", with(suspension_reason)),
+    field(login_count = "login_count: u32", error = "std::num::TryFromIntError")
+)]
+pub struct UserDto {
+    pub user_name: String,
+    pub nickname: Option<String>,
+    pub status: String,
+    pub suspension_reason: Option<String>,
+    pub login_count: i32,
+    pub note: String,
+}
+
+fn dto() -> UserDto {
+    UserDto {
+        user_name: "alice".to_string(),
+        nickname: None,
+        status: "suspended".to_string(),
+        suspension_reason: Some("spam".to_string()),
+        login_count: 3,
+        note: "memo".to_string(),
+    }
+}
+
+fn main() {
+    let user = User::try_from(dto()).unwrap();
+    assert_eq!(user.name, UserName("alice".to_string()));
+    assert_eq!(user.status, Status::Suspended { reason: "spam".to_string() });
+    assert_eq!(UserDto::try_from(user).unwrap(), dto());
+
+    let invalid = UserDto {
+        nickname: Some(String::new()),
+        ..dto()
+    };
+    let error = User::try_from(invalid).unwrap_err();
+    assert!(matches!(error, UserDomainToDtoConversionError::InvalidUserName(UserNameError)));
+    assert_eq!(error.to_string(), "Invalid UserName: empty");
+
+    let negative = UserDto {
+        login_count: -1,
+        ..dto()
+    };
+    assert!(matches!(
+        User::try_from(negative),
+        Err(UserDomainToDtoConversionError::InvalidLoginCount(_))
+    ));
+
+    let unknown = UserDto {
+        status: "unknown".to_string(),
+        ..dto()
+    };
+    assert!(matches!(
+        User::try_from(unknown),
+        Err(UserDomainToDtoConversionError::InvalidStatus(StatusError))
+    ));
+}
@@ -0,0 +1,36 @@
+use sqlx_macros::{PersistenceModel, ValueObject};
+
+#[derive(Debug, Clone, PartialEq, ValueObject)]
+#[vo(label = "メールアドレス", regex = "^[^@]+@[^@]+\\.[^@]+$", trust_stored)]
+pub struct MailAddress(String);
+
+#[derive(Debug, PartialEq)]
+pub struct User {
+    pub mail_address: MailAddress,
+    pub pending_mail_address: Option<MailAddress>,
+}
+
+#[derive(Debug, PartialEq, PersistenceModel)]
+#[model(
+    domain = User,
+    field(mail_address = "mail_address: MailAddress", stored),
+    field(pending_mail_address = "pending_mail_address: Option<MailAddress>", stored)
+)]
+pub struct UserDto {
+    pub mail_address: String,
+    pub pending_mail_address: Option<String>,
+}
+
+fn main() {
+    // NOTE: 検証ルールの追加前に保存された値も復元できる
+    let user = User::try_from(UserDto {
+        mail_address: "hoge@localhost".to_string(),
+        pending_mail_address: Some("fuga@localhost".to_string()),
+    })
+    .unwrap();
+    assert_eq!(user.mail_address.get(), "hoge@localhost");
+    assert_eq!(
+        user.pending_mail_address.as_ref().map(MailAddress::get),
+        Some("fuga@localhost")
+    );
+}
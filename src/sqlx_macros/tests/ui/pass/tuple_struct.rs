@@ -0,0 +1,16 @@
+use sqlx::Postgres;
+use sqlx_macros::{SqlxDecode, SqlxEncode, SqlxType};
+
+// NOTE: エンコードは参照で行うため、Cloneは不要
+#[derive(SqlxType, SqlxEncode, SqlxDecode)]
+struct UserName(String);
+
+fn assert_sqlx<T>()
+where
+    T: sqlx::Type<Postgres> + for<'q> sqlx::Encode<'q, Postgres> + for<'r> sqlx::Decode<'r, Postgres>,
+{
+}
+
+fn main() {
+    assert_sqlx::<UserName>();
+}
@@ -0,0 +1,63 @@
+use sqlx_macros::usecase;
+
+pub trait TransactionManager {
+    type Transaction<'a>: Send + 'a;
+}
+
+pub trait UserRepository<Tx: TransactionManager> {
+    fn find(&self, tx: &mut Tx::Transaction<'_>, id: u32) -> Option<String>;
+}
+
+pub struct UserUseCaseImpl<Repo> {
+    repository: Repo,
+}
+
+#[usecase]
+impl<Tx, Repo> UserGetUsecase<Tx> for UserUseCaseImpl<Repo>
+where
+    Tx: TransactionManager,
+    Repo: UserRepository<Tx>,
+{
+    /// ユーザー名を取得する
+    async fn get(&self, tx: &mut Tx::Transaction<'_>, id: u32) -> Option<String> {
+        let name = self.repository.find(tx, id);
+        std::future::ready(()).await;
+        name
+    }
+
+    async fn exists(&self, tx: &mut Tx::Transaction<'_>, id: u32) -> bool {
+        self.get(tx, id).await.is_some()
+    }
+}
+
+#[usecase(pub(crate))]
+impl<Repo> HealthCheckUsecase for UserUseCaseImpl<Repo> {
+    async fn check(&self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+fn assert_send<T: Send>(_: &T) {}
+
+fn check<Tx, U>(usecase: &U, tx: &mut Tx::Transaction<'_>)
+where
+    Tx: TransactionManager,
+    U: UserGetUsecase<Tx> + HealthCheckUsecase,
+{
+    assert_send(&usecase.get(tx, 1));
+    assert_send(&usecase.check());
+}
+
+fn main() {
+    let _ = check::<(), UserUseCaseImpl<()>>;
+}
+
+impl TransactionManager for () {
+    type Transaction<'a> = ();
+}
+
+impl UserRepository<()> for () {
+    fn find(&self, _: &mut (), _: u32) -> Option<String> {
+        None
+    }
+}
@@ -0,0 +1,22 @@
+use sqlx_macros::ValueObject;
+
+#[derive(Debug, Clone, PartialEq, ValueObject)]
+#[vo(label = "ユーザー名", trim, min_len = 3, max_len = 20, regex = "^[a-z_]+$")]
+pub struct UserName(String);
+
+#[derive(Debug, Clone, PartialEq, ValueObject)]
+pub struct Age(i32);
+
+fn main() {
+    assert_eq!(UserName::new(" alice ".to_string()).unwrap().get(), "alice");
+    assert_eq!(
+        UserName::new("al".to_string()),
+        Err(UserNameError::TooShort { min_length: 3 })
+    );
+    assert_eq!(
+        UserName::new("Alice".to_string()),
+        Err(UserNameError::InvalidFormat)
+    );
+    assert_eq!(UserNameError::InvalidFormat.code(), "invalid_format");
+    assert_eq!(*Age::new(20).unwrap().get(), 20);
+}
@@ -0,0 +1,22 @@
+use sqlx_macros::ValueObject;
+
+#[derive(Debug, Clone, PartialEq, ValueObject)]
+#[vo(label = "ユーザー名", trim, min_len = 3, serde)]
+pub struct UserName(String);
+
+#[derive(Debug, Clone, PartialEq, ValueObject)]
+#[vo(serde)]
+pub struct Age(i32);
+
+fn main() {
+    let name: UserName = serde_json::from_str(r#"" alice ""#).unwrap();
+    assert_eq!(name.get(), "alice");
+    assert_eq!(serde_json::to_string(&name).unwrap(), r#""alice""#);
+
+    let error = serde_json::from_str::<UserName>(r#""al""#).unwrap_err();
+    assert_eq!(error.to_string(), "too_short");
+
+    let age: Age = serde_json::from_str("20").unwrap();
+    assert_eq!(*age.get(), 20);
+    assert!(serde_json::from_str::<Age>(r#""20""#).is_err());
+}
@@ -0,0 +1,20 @@
+use sqlx_macros::ValueObject;
+
+#[derive(Debug, Clone, PartialEq, ValueObject)]
+#[vo(label = "メールアドレス", regex = "^[^@]+@[^@]+\\.[^@]+$", trust_stored)]
+pub struct MailAddress(String);
+
+fn main() {
+    assert_eq!(
+        MailAddress::new("hoge@localhost".to_string()),
+        Err(MailAddressError::InvalidFormat)
+    );
+    // NOTE: TryFromはnewと同じく検証する
+    assert_eq!(
+        MailAddress::try_from("hoge@localhost".to_string()),
+        Err(MailAddressError::InvalidFormat)
+    );
+    // NOTE: DBからの復元では検証しない
+    let stored = MailAddress::from_stored("hoge@localhost".to_string());
+    assert_eq!(stored.get(), "hoge@localhost");
+}